@@ -0,0 +1,227 @@
+//! # Credit Score RPC
+//!
+//! Extensão RPC do nó que expõe a [`CreditScoreApi`] do runtime como métodos
+//! JSON-RPC `credchain_*`, para que o backend consulte scores sem decodificar
+//! o storage bruto.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_credit_score::{
+    ConsentPurpose, CreditScoreData, EffectiveScore, FactorRecord, ScoreBand, ScoreChange,
+    ScoreExplanation, ScoreFactorType, ScoreReason,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, Get};
+
+pub use pallet_credit_score_runtime_api::CreditScoreApi as CreditScoreRuntimeApi;
+
+/// Página do histórico de mudanças e sequência da próxima página
+pub type ScoreHistoryPage<AccountId, BlockNumber, MaxFactors> =
+    (Vec<ScoreChange<AccountId, BlockNumber, MaxFactors>>, Option<u64>);
+
+/// Métodos RPC do Credit Score. Os limites são explícitos porque `MaxFactors`
+/// apenas dimensiona os `BoundedVec` retornados e não é serializável.
+#[rpc(
+    server,
+    server_bounds(
+        BlockHash: DeserializeOwned + Send + Sync + 'static,
+        AccountId: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+        BlockNumber: Clone + Serialize + Send + Sync + 'static,
+        Hash: Clone + Serialize + Send + Sync + 'static,
+        MaxFactors: Get<u32> + Clone + Send + Sync + 'static,
+    )
+)]
+pub trait CreditScoreApi<BlockHash, AccountId, BlockNumber, Hash, MaxFactors: Get<u32>> {
+    /// Score armazenado de uma conta
+    #[method(name = "credchain_getCreditScore")]
+    fn credit_score(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors>>>;
+
+    /// Score com staleness e decaimento aplicados
+    #[method(name = "credchain_getEffectiveScore")]
+    fn effective_score(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<EffectiveScore>>;
+
+    /// Fatores brutos registrados para a conta
+    #[method(name = "credchain_getScoreFactors")]
+    fn score_factors(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(ScoreFactorType, FactorRecord<AccountId, BlockNumber>)>>;
+
+    /// Página do histórico de mudanças de score
+    #[method(name = "credchain_getScoreHistory")]
+    fn score_history(
+        &self,
+        who: AccountId,
+        start: Option<u64>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ScoreHistoryPage<AccountId, BlockNumber, MaxFactors>>;
+
+    /// Contribuição de cada fator para o score atual
+    #[method(name = "credchain_explainScore")]
+    fn explain_score(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<ScoreExplanation>>;
+
+    /// Motivos que reduziram o score, do maior para o menor impacto
+    #[method(name = "credchain_getScoreReasons")]
+    fn score_reasons(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<ScoreReason>>;
+
+    /// Indica se o titular concedeu ao credor acesso válido para a finalidade
+    #[method(name = "credchain_hasValidConsent")]
+    fn has_valid_consent(
+        &self,
+        owner: AccountId,
+        lender: AccountId,
+        purpose: ConsentPurpose,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Indica se o score está congelado
+    #[method(name = "credchain_isScoreFrozen")]
+    fn is_frozen(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Faixa do score atual da conta
+    #[method(name = "credchain_getScoreBand")]
+    fn score_band(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<ScoreBand>>;
+}
+
+/// Implementação da extensão RPC sobre um cliente com a API de runtime
+pub struct CreditScore<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> CreditScore<C, Block> {
+    /// Cria a extensão a partir do cliente do nó
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Código de erro para falhas na chamada à API de runtime
+const RUNTIME_ERROR: i32 = 1;
+
+/// Converte um erro da API de runtime em erro RPC
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Runtime error", Some(format!("{:?}", err)))
+}
+
+impl<C, Block, AccountId, BlockNumber, Hash, MaxFactors>
+    CreditScoreApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, Hash, MaxFactors>
+    for CreditScore<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CreditScoreRuntimeApi<Block, AccountId, BlockNumber, Hash, MaxFactors>,
+    AccountId: Codec + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Codec + Clone + Serialize + Send + Sync + 'static,
+    Hash: Codec + Clone + Serialize + Send + Sync + 'static,
+    MaxFactors: Get<u32> + Clone + Send + Sync + 'static,
+{
+    fn credit_score(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.credit_score(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn effective_score(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<EffectiveScore>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.effective_score(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn score_factors(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<(ScoreFactorType, FactorRecord<AccountId, BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.score_factors(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn score_history(
+        &self,
+        who: AccountId,
+        start: Option<u64>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ScoreHistoryPage<AccountId, BlockNumber, MaxFactors>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.score_history(at, who, start, limit).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn explain_score(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ScoreExplanation>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.explain_score(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn score_reasons(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ScoreReason>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.score_reasons(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn has_valid_consent(
+        &self,
+        owner: AccountId,
+        lender: AccountId,
+        purpose: ConsentPurpose,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.has_valid_consent(at, owner, lender, purpose).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn is_frozen(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.is_frozen(at, who).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn score_band(&self, who: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<ScoreBand>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.score_band(at, who).map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Credit Score Runtime API
+//!
+//! API de runtime para consultar scores de crédito sem decodificar o storage
+//! do pallet diretamente. Implementada pelo runtime e exposta pela extensão
+//! RPC do nó (`credchain_*`).
+
+use codec::Codec;
+use pallet_credit_score::{
+    ConsentPurpose, CreditScoreData, EffectiveScore, FactorRecord, ScoreChange, ScoreExplanation, ScoreBand,
+    ScoreFactorType, ScoreReason,
+};
+use sp_runtime::traits::Get;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait CreditScoreApi<AccountId, BlockNumber, Hash, MaxFactors>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+        MaxFactors: Get<u32>,
+    {
+        /// Score armazenado de uma conta
+        fn credit_score(who: AccountId) -> Option<CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors>>;
+
+        /// Score com staleness e decaimento aplicados
+        fn effective_score(who: AccountId) -> Option<EffectiveScore>;
+
+        /// Fatores brutos registrados para a conta, com suas fontes
+        fn score_factors(who: AccountId) -> Vec<(ScoreFactorType, FactorRecord<AccountId, BlockNumber>)>;
+
+        /// Página do histórico de mudanças e sequência da próxima página
+        fn score_history(
+            who: AccountId,
+            start: Option<u64>,
+            limit: u32,
+        ) -> (Vec<ScoreChange<AccountId, BlockNumber, MaxFactors>>, Option<u64>);
+
+        /// Contribuição de cada fator para o score atual
+        fn explain_score(who: AccountId) -> Option<ScoreExplanation>;
+
+        /// Motivos que reduziram o score, do maior para o menor impacto
+        fn score_reasons(who: AccountId) -> Vec<ScoreReason>;
+
+        /// Indica se o titular concedeu ao credor acesso válido para a finalidade
+        fn has_valid_consent(owner: AccountId, lender: AccountId, purpose: ConsentPurpose) -> bool;
+
+        /// Indica se o score está congelado (sem suspensão temporária em vigor)
+        fn is_frozen(who: AccountId) -> bool;
+
+        /// Faixa do score atual da conta
+        fn score_band(who: AccountId) -> Option<ScoreBand>;
+    }
+}
@@ -0,0 +1,463 @@
+//! Benchmarks do pallet de credit score
+//!
+//! Cada extrinsic é medida no pior caso: histórico cheio (com poda), todas as
+//! faixas de score configuradas e registro de consultas no limite.
+
+use super::*;
+use crate::Pallet as CreditScore;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::pallet_prelude::*;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, Zero};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// Scores verificados pelo verificador descredenciado no benchmark de `remove_verifier`
+const MAX_VERIFIED_SCORES: u32 = 1_000;
+
+const FACTOR_TYPES: [ScoreFactorType; 8] = [
+    ScoreFactorType::PaymentHistory,
+    ScoreFactorType::CreditUtilization,
+    ScoreFactorType::CreditAge,
+    ScoreFactorType::CreditMix,
+    ScoreFactorType::NewCredit,
+    ScoreFactorType::IncomeStability,
+    ScoreFactorType::EmploymentHistory,
+    ScoreFactorType::DebtToIncome,
+];
+
+/// `n` fatores válidos, percorrendo os tipos de fator em ordem
+fn score_factors(n: u32) -> Vec<ScoreFactor> {
+    (0..n as usize)
+        .map(|i| ScoreFactor {
+            factor_type: FACTOR_TYPES[i % FACTOR_TYPES.len()],
+            value: 80,
+            weight: 10,
+        })
+        .collect()
+}
+
+/// `n` faixas de score igualmente espaçadas a partir de `MinScore`
+fn score_bands<T: Config>(n: u32) -> Vec<ScoreBand> {
+    let span = T::MaxScore::get().saturating_sub(T::MinScore::get()) / n.max(1);
+    (0..n)
+        .map(|i| ScoreBand {
+            label: BoundedVec::truncate_from(vec![b'A'; 32]),
+            min_score: T::MinScore::get() + i * span.max(1),
+        })
+        .collect()
+}
+
+/// Configura o máximo de faixas, tornando a classificação do score a mais cara
+fn fill_score_bands<T: Config>() {
+    ScoreBands::<T>::put(BoundedVec::truncate_from(score_bands::<T>(T::MaxScoreBands::get())));
+}
+
+/// Registra um score para `who` e enche seu histórico, forçando a poda da entrada mais antiga
+fn store_score_with_full_history<T: Config>(who: &T::AccountId) {
+    for _ in 0..T::MaxHistoryEntries::get().max(1) {
+        CreditScore::<T>::calculate_score(
+            RawOrigin::Signed(who.clone()).into(),
+            score_factors(T::MaxScoreFactors::get().min(FACTOR_TYPES.len() as u32)),
+        )
+        .expect("fatores válidos");
+    }
+}
+
+/// Credencia `who` como verificador
+fn register_verifier<T: Config>(who: &T::AccountId) {
+    Verifiers::<T>::insert(who, VerifierInfo {
+        metadata: BoundedVec::truncate_from(vec![0u8; T::MaxVerifierMetadataLength::get() as usize]),
+        registered_at: Zero::zero(),
+        total_verifications: 0,
+        last_verification_at: None,
+    });
+}
+
+/// Faz `v` verificadores distintos verificarem o score atual de `who`
+fn verify_score_times<T: Config>(who: &T::AccountId, v: u32) {
+    for i in 0..v {
+        let verifier: T::AccountId = account("verifier", i, SEED);
+        register_verifier::<T>(&verifier);
+        CreditScore::<T>::verify_score(RawOrigin::Signed(verifier).into(), who.clone())
+            .expect("verificador credenciado");
+    }
+}
+
+/// Abre uma contestação de `owner` e a atribui a `reviewer`
+fn open_reviewed_dispute<T: Config>(owner: &T::AccountId, reviewer: &T::AccountId) -> u64 {
+    store_score_with_full_history::<T>(owner);
+    register_verifier::<T>(reviewer);
+
+    let dispute_id = NextDisputeId::<T>::get();
+    CreditScore::<T>::open_score_dispute(
+        RawOrigin::Signed(owner.clone()).into(),
+        T::Hashing::hash(b"evidencias"),
+    )
+    .expect("titular com score");
+
+    ScoreDisputes::<T>::mutate(dispute_id, |dispute| {
+        if let Some(dispute) = dispute {
+            dispute.reviewer = Some(reviewer.clone());
+            dispute.status = ScoreDisputeStatus::UnderReview;
+        }
+    });
+
+    dispute_id
+}
+
+/// Congela o score de `who` e suspende o congelamento temporariamente
+fn freeze_and_lift<T: Config>(who: &T::AccountId) {
+    CreditScore::<T>::freeze_score(RawOrigin::Signed(who.clone()).into()).expect("não congelado");
+    CreditScore::<T>::lift_freeze_temporarily(
+        RawOrigin::Signed(who.clone()).into(),
+        T::MaxFreezeLiftDuration::get(),
+    )
+    .expect("congelado pelo titular");
+}
+
+benchmarks! {
+    calculate_score {
+        let f in 1 .. T::MaxScoreFactors::get();
+        let v in 0 .. T::MaxScoreVerifications::get();
+        let caller: T::AccountId = whitelisted_caller();
+        fill_score_bands::<T>();
+        store_score_with_full_history::<T>(&caller);
+        verify_score_times::<T>(&caller, v);
+    }: _(RawOrigin::Signed(caller.clone()), score_factors(f))
+    verify {
+        assert!(CreditScores::<T>::contains_key(&caller));
+        assert_eq!(ScoreVerifications::<T>::iter_prefix(&caller).count(), 0);
+    }
+
+    update_score {
+        let f in 1 .. T::MaxScoreFactors::get();
+        let v in 0 .. T::MaxScoreVerifications::get();
+        let caller: T::AccountId = whitelisted_caller();
+        fill_score_bands::<T>();
+        store_score_with_full_history::<T>(&caller);
+        verify_score_times::<T>(&caller, v);
+    }: _(RawOrigin::Signed(caller.clone()), score_factors(f))
+    verify {
+        assert_eq!(CreditScores::<T>::get(&caller).map(|data| data.factors.len() as u32), Some(f));
+    }
+
+    verify_score {
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        let user: T::AccountId = account("user", 0, SEED);
+        register_verifier::<T>(&verifier);
+        store_score_with_full_history::<T>(&user);
+    }: _(RawOrigin::Signed(verifier), user.clone())
+    verify {
+        assert!(CreditScores::<T>::get(&user).is_some_and(|data| data.is_verified));
+    }
+
+    add_score_factor {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()), ScoreFactorType::CreditMix, 80, 10)
+    verify {
+        assert!(ScoreFactors::<T>::contains_key(&caller, ScoreFactorType::CreditMix));
+    }
+
+    add_verifier {
+        let origin = T::VerifierOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        let metadata = vec![0u8; T::MaxVerifierMetadataLength::get() as usize];
+    }: _<T::RuntimeOrigin>(origin, verifier.clone(), metadata)
+    verify {
+        assert!(Verifiers::<T>::contains_key(&verifier));
+    }
+
+    remove_verifier {
+        let v in 0 .. MAX_VERIFIED_SCORES;
+        let origin = T::VerifierOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        register_verifier::<T>(&verifier);
+        for i in 0..v {
+            let user: T::AccountId = account("user", i, SEED);
+            CreditScore::<T>::calculate_score(
+                RawOrigin::Signed(user.clone()).into(),
+                score_factors(T::MaxScoreFactors::get().min(FACTOR_TYPES.len() as u32)),
+            )?;
+            CreditScore::<T>::verify_score(RawOrigin::Signed(verifier.clone()).into(), user)?;
+        }
+    }: _<T::RuntimeOrigin>(origin, verifier.clone(), v)
+    verify {
+        assert!(!Verifiers::<T>::contains_key(&verifier));
+        assert_eq!(VerifierScores::<T>::iter_prefix(&verifier).count(), 0);
+    }
+
+    submit_attested_factors {
+        let f in 1 .. T::MaxScoreFactors::get();
+        let v in 0 .. T::MaxScoreVerifications::get();
+        let bureau: T::AccountId = account("bureau", 0, SEED);
+        let user: T::AccountId = account("user", 0, SEED);
+        Bureaus::<T>::insert(&bureau, BureauInfo {
+            name: BoundedVec::truncate_from(vec![0u8; T::MaxBureauNameLength::get() as usize]),
+            registered_at: Zero::zero(),
+        });
+        fill_score_bands::<T>();
+        store_score_with_full_history::<T>(&user);
+        verify_score_times::<T>(&user, v);
+    }: _(RawOrigin::Signed(bureau.clone()), user.clone(), score_factors(f))
+    verify {
+        assert_eq!(
+            CreditScores::<T>::get(&user).map(|data| data.source),
+            Some(FactorSource::Attester(bureau))
+        );
+    }
+
+    register_bureau {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bureau: T::AccountId = account("bureau", 0, SEED);
+        let name = vec![0u8; T::MaxBureauNameLength::get() as usize];
+    }: _<T::RuntimeOrigin>(origin, bureau.clone(), name)
+    verify {
+        assert!(Bureaus::<T>::contains_key(&bureau));
+    }
+
+    remove_bureau {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bureau: T::AccountId = account("bureau", 0, SEED);
+        Bureaus::<T>::insert(&bureau, BureauInfo {
+            name: BoundedVec::truncate_from(vec![0u8; T::MaxBureauNameLength::get() as usize]),
+            registered_at: Zero::zero(),
+        });
+    }: _<T::RuntimeOrigin>(origin, bureau.clone())
+    verify {
+        assert!(!Bureaus::<T>::contains_key(&bureau));
+    }
+
+    set_attestation_mode {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, FactorAttestationMode::AttestedOnly)
+    verify {
+        assert_eq!(AttestationMode::<T>::get(), FactorAttestationMode::AttestedOnly);
+    }
+
+    propose_scorecard {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let entries: Vec<ScorecardEntry> = FACTOR_TYPES
+            .iter()
+            .take(T::MaxScoreFactors::get() as usize)
+            .map(|factor_type| ScorecardEntry {
+                factor_type: *factor_type,
+                weight: 10,
+                min_value: 0,
+                max_value: 100,
+                curve: NormalizationCurve::Linear,
+            })
+            .collect();
+        let activate_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        // Uma proposta pendente é substituída
+        CreditScore::<T>::propose_scorecard(origin.clone(), entries.clone(), activate_at)
+            .expect("proposta válida");
+    }: _<T::RuntimeOrigin>(origin, entries, activate_at)
+    verify {
+        assert_eq!(PendingScorecard::<T>::get().map(|(_, scorecard)| scorecard.version), Some(2));
+    }
+
+    cancel_scorecard_proposal {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let entries: Vec<ScorecardEntry> = DERIVED_FACTOR_TYPES
+            .iter()
+            .map(|factor_type| ScorecardEntry {
+                factor_type: *factor_type,
+                weight: 10,
+                min_value: 0,
+                max_value: 100,
+                curve: NormalizationCurve::Linear,
+            })
+            .collect();
+        let activate_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        CreditScore::<T>::propose_scorecard(origin.clone(), entries, activate_at)
+            .expect("proposta válida");
+    }: _<T::RuntimeOrigin>(origin)
+    verify {
+        assert!(PendingScorecard::<T>::get().is_none());
+    }
+
+    grant_consent {
+        let owner: T::AccountId = whitelisted_caller();
+        let lender: T::AccountId = account("lender", 0, SEED);
+    }: _(RawOrigin::Signed(owner.clone()), lender.clone(), ConsentPurpose::CreditApplication, T::MaxConsentDuration::get())
+    verify {
+        assert!(Consents::<T>::contains_key(&owner, &lender));
+    }
+
+    revoke_consent {
+        let owner: T::AccountId = whitelisted_caller();
+        let lender: T::AccountId = account("lender", 0, SEED);
+        CreditScore::<T>::grant_consent(
+            RawOrigin::Signed(owner.clone()).into(),
+            lender.clone(),
+            ConsentPurpose::CreditApplication,
+            T::MaxConsentDuration::get(),
+        )?;
+    }: _(RawOrigin::Signed(owner.clone()), lender.clone())
+    verify {
+        assert!(!Consents::<T>::contains_key(&owner, &lender));
+    }
+
+    pull_score {
+        let v in 0 .. T::MaxScoreVerifications::get();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let lender: T::AccountId = whitelisted_caller();
+        fill_score_bands::<T>();
+        store_score_with_full_history::<T>(&owner);
+        verify_score_times::<T>(&owner, v);
+        CreditScore::<T>::grant_consent(
+            RawOrigin::Signed(owner.clone()).into(),
+            lender.clone(),
+            ConsentPurpose::CreditApplication,
+            T::MaxConsentDuration::get(),
+        )?;
+
+        // Registro cheio de consultas de outros credores: a mais antiga é descartada,
+        // a nova conta como hard inquiry e o score é recalculado
+        let inquiries: Vec<_> = (0..T::MaxInquiries::get())
+            .map(|i| Inquiry {
+                lender: account("other", i, SEED),
+                purpose: ConsentPurpose::CreditApplication,
+                product: ProductType::PersonalLoan,
+                inquired_at: frame_system::Pallet::<T>::block_number(),
+                is_hard: true,
+                is_duplicate: false,
+            })
+            .collect();
+        Inquiries::<T>::insert(&owner, BoundedVec::truncate_from(inquiries));
+    }: _(RawOrigin::Signed(lender.clone()), owner.clone(), ConsentPurpose::CreditApplication, ProductType::PersonalLoan)
+    verify {
+        assert!(Inquiries::<T>::get(&owner).iter().any(|inquiry| inquiry.lender == lender));
+    }
+
+    open_score_dispute {
+        let owner: T::AccountId = whitelisted_caller();
+        store_score_with_full_history::<T>(&owner);
+    }: _(RawOrigin::Signed(owner.clone()), T::Hashing::hash(b"evidencias"))
+    verify {
+        assert!(OpenDisputeOf::<T>::contains_key(&owner));
+    }
+
+    assign_dispute_reviewer {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let reviewer: T::AccountId = account("reviewer", 0, SEED);
+        store_score_with_full_history::<T>(&owner);
+        register_verifier::<T>(&reviewer);
+        CreditScore::<T>::open_score_dispute(
+            RawOrigin::Signed(owner).into(),
+            T::Hashing::hash(b"evidencias"),
+        )?;
+    }: _<T::RuntimeOrigin>(origin, 0, reviewer.clone())
+    verify {
+        assert_eq!(ScoreDisputes::<T>::get(0).and_then(|dispute| dispute.reviewer), Some(reviewer));
+    }
+
+    accept_score_dispute {
+        let f in 1 .. T::MaxScoreFactors::get();
+        let v in 0 .. T::MaxScoreVerifications::get();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let reviewer: T::AccountId = account("reviewer", 0, SEED);
+        fill_score_bands::<T>();
+        let dispute_id = open_reviewed_dispute::<T>(&owner, &reviewer);
+        verify_score_times::<T>(&owner, v);
+    }: _(RawOrigin::Signed(reviewer), dispute_id, score_factors(f))
+    verify {
+        assert!(!OpenDisputeOf::<T>::contains_key(&owner));
+    }
+
+    reject_score_dispute {
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let reviewer: T::AccountId = account("reviewer", 0, SEED);
+        let dispute_id = open_reviewed_dispute::<T>(&owner, &reviewer);
+    }: _(RawOrigin::Signed(reviewer), dispute_id)
+    verify {
+        assert!(!OpenDisputeOf::<T>::contains_key(&owner));
+    }
+
+    freeze_score {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(ScoreFreezes::<T>::contains_key(&caller));
+    }
+
+    unfreeze_score {
+        let caller: T::AccountId = whitelisted_caller();
+        freeze_and_lift::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!ScoreFreezes::<T>::contains_key(&caller));
+    }
+
+    lift_freeze_temporarily {
+        let caller: T::AccountId = whitelisted_caller();
+        // Uma suspensão anterior é substituída
+        freeze_and_lift::<T>(&caller);
+    }: _(RawOrigin::Signed(caller.clone()), T::MaxFreezeLiftDuration::get())
+    verify {
+        assert!(ScoreFreezes::<T>::get(&caller).is_some_and(|freeze| freeze.lifted_until.is_some()));
+    }
+
+    force_freeze_score {
+        let origin = T::FraudResponseOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let who: T::AccountId = account("victim", 0, SEED);
+        // Assume o congelamento do titular, encerrando a suspensão
+        freeze_and_lift::<T>(&who);
+    }: _<T::RuntimeOrigin>(origin, who.clone())
+    verify {
+        assert_eq!(
+            ScoreFreezes::<T>::get(&who).map(|freeze| freeze.authority),
+            Some(FreezeAuthority::FraudResponse)
+        );
+    }
+
+    force_unfreeze_score {
+        let origin = T::FraudResponseOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let who: T::AccountId = account("victim", 0, SEED);
+        freeze_and_lift::<T>(&who);
+    }: _<T::RuntimeOrigin>(origin, who.clone())
+    verify {
+        assert!(!ScoreFreezes::<T>::contains_key(&who));
+    }
+
+    set_score_bands {
+        let b in 1 .. T::MaxScoreBands::get();
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let bands = score_bands::<T>(b);
+    }: _<T::RuntimeOrigin>(origin, bands)
+    verify {
+        assert_eq!(ScoreBands::<T>::get().len() as u32, b);
+    }
+
+    prune_expired_consent {
+        let caller: T::AccountId = whitelisted_caller();
+        let owner: T::AccountId = account("owner", 0, SEED);
+        let lender: T::AccountId = account("lender", 0, SEED);
+        let duration = T::MaxConsentDuration::get();
+        CreditScore::<T>::grant_consent(
+            RawOrigin::Signed(owner.clone()).into(),
+            lender.clone(),
+            ConsentPurpose::CreditApplication,
+            duration,
+        )?;
+        frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + duration);
+    }: _(RawOrigin::Signed(caller), owner.clone(), lender.clone())
+    verify {
+        assert!(!Consents::<T>::contains_key(&owner, &lender));
+    }
+}
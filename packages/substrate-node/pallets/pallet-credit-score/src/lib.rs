@@ -7,17 +7,52 @@
 //! transparente e verificável na blockchain.
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+pub use scoring::{
+    NormalizationCurve, Scorecard, ScorecardEntry, ScoringModel, ScoringModelInfo,
+    WeightedAverageModel,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod scoring;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
+    use crate::scoring::{Scorecard, ScorecardEntry, ScoringModel, ScoringModelInfo};
+    use crate::weights::WeightInfo;
+    use credchain_primitives::{process_schedule, OnPaymentStateChange, PaymentHistoryProvider};
     use frame_support::{
         pallet_prelude::*,
-        traits::{Get, Randomness},
+        traits::{Contains, Get, Randomness, StorageVersion},
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
+    use sp_runtime::{
+        traits::{Hash, One, SaturatedConversion, Saturating, Zero},
+        Permill,
+    };
     use sp_std::vec::Vec;
 
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+
+    /// Versão atual do storage; migrações em `crate::migrations`
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+    /// Pontos subtraídos do fator NewCredit por hard inquiry recente
+    pub const NEW_CREDIT_PENALTY_PER_INQUIRY: u32 = 15;
+
+    /// Tipos de fator derivados de dados on-chain, sempre na escala 1-100
+    pub const DERIVED_FACTOR_TYPES: [ScoreFactorType; 2] =
+        [ScoreFactorType::PaymentHistory, ScoreFactorType::NewCredit];
+
     /// Configuração do pallet
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -40,12 +75,149 @@ pub mod pallet {
         type MaxScoreFactors: Get<u32>;
         
         /// Randomness source para geração de IDs únicos
-        type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// Origem de governança que credencia e descredencia verificadores
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Tamanho máximo dos metadados de um verificador
+        #[pallet::constant]
+        type MaxVerifierMetadataLength: Get<u32>;
+
+        /// Número máximo de verificações de um mesmo score
+        #[pallet::constant]
+        type MaxScoreVerifications: Get<u32>;
+
+        /// Origem de governança para birôs de crédito e modo de atestação
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Contas de oráculo autorizadas a atestar fatores (pallet-oracle-integration)
+        type OracleAttesters: Contains<Self::AccountId>;
+
+        /// Tamanho máximo do nome de um birô de crédito
+        #[pallet::constant]
+        type MaxBureauNameLength: Get<u32>;
+
+        /// Histórico de pagamentos on-chain (pallet-payment-registry)
+        type PaymentHistory: PaymentHistoryProvider<Self::AccountId>;
+
+        /// Peso (1-100) do fator PaymentHistory derivado dos pagamentos on-chain
+        #[pallet::constant]
+        type PaymentHistoryWeight: Get<u32>;
+
+        /// Modelo usado para calcular o score a partir dos fatores
+        type ScoringModel: ScoringModel;
+
+        /// Blocos após o cálculo a partir dos quais um score é considerado desatualizado
+        #[pallet::constant]
+        type StalenessPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Intervalo, em blocos, entre etapas de decaimento de um score desatualizado
+        #[pallet::constant]
+        type DecayPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Fração da distância ao score neutro perdida a cada etapa (zero desativa)
+        #[pallet::constant]
+        type DecayRate: Get<Permill>;
+
+        /// Score neutro para o qual scores desatualizados convergem
+        #[pallet::constant]
+        type NeutralScore: Get<u32>;
+
+        /// Número máximo de mudanças de score mantidas no histórico de cada conta
+        #[pallet::constant]
+        type MaxHistoryEntries: Get<u32>;
+
+        /// Número máximo de códigos de motivo informados para um score
+        #[pallet::constant]
+        type MaxReasonCodes: Get<u32>;
+
+        /// Duração máxima, em blocos, de um consentimento de acesso ao score
+        #[pallet::constant]
+        type MaxConsentDuration: Get<BlockNumberFor<Self>>;
+
+        /// Número máximo de consultas mantidas no registro de cada conta
+        #[pallet::constant]
+        type MaxInquiries: Get<u32>;
+
+        /// Janela móvel, em blocos, de consultas consideradas no fator NewCredit
+        #[pallet::constant]
+        type InquiryWindow: Get<BlockNumberFor<Self>>;
+
+        /// Período em que novas consultas do mesmo credor são tratadas como duplicadas
+        #[pallet::constant]
+        type InquiryDedupPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Peso (1-100) do fator NewCredit derivado das consultas on-chain
+        #[pallet::constant]
+        type NewCreditWeight: Get<u32>;
+
+        /// Prazo, em blocos, para que uma contestação de score seja analisada.
+        /// Valores menores que 1 são tratados como 1.
+        #[pallet::constant]
+        type DisputeReviewPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Origem da equipe de resposta a fraudes, que pode congelar scores de terceiros
+        type FraudResponseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Duração máxima, em blocos, de uma suspensão temporária do congelamento
+        #[pallet::constant]
+        type MaxFreezeLiftDuration: Get<BlockNumberFor<Self>>;
+
+        /// Número máximo de faixas de score configuráveis pela governança
+        #[pallet::constant]
+        type MaxScoreBands: Get<u32>;
+
+        /// Número máximo de scores marcados como desatualizados em um bloco.
+        /// O excedente fica para os blocos seguintes.
+        #[pallet::constant]
+        type MaxStaleMarksPerBlock: Get<u32>;
+
+        /// Número máximo de contestações expiradas em um bloco.
+        /// O excedente fica para os blocos seguintes.
+        #[pallet::constant]
+        type MaxDisputeExpiriesPerBlock: Get<u32>;
+
+        /// Número máximo de congelamentos restabelecidos em um bloco.
+        /// O excedente fica para os blocos seguintes.
+        #[pallet::constant]
+        type MaxFreezeReinstatesPerBlock: Get<u32>;
+
+        /// Número máximo de scores recalculados em um bloco porque uma consulta
+        /// saiu de `InquiryWindow`. O excedente fica para os blocos seguintes.
+        #[pallet::constant]
+        type MaxInquiryExpiriesPerBlock: Get<u32>;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Dados de score com os tipos do runtime
+    pub type CreditScoreDataOf<T> = CreditScoreData<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+        <T as Config>::MaxScoreFactors,
+    >;
+
+    /// Mudança de score com os tipos do runtime
+    pub type ScoreChangeOf<T> = ScoreChange<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as Config>::MaxScoreFactors,
+    >;
+
+    /// Registro de fator com os tipos do runtime
+    pub type FactorRecordOf<T> = FactorRecord<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// Contestação de score com os tipos do runtime
+    pub type ScoreDisputeOf<T> = ScoreDispute<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+    >;
+
     /// Armazena scores de crédito por usuário
     #[pallet::storage]
     #[pallet::getter(fn credit_score)]
@@ -53,23 +225,34 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,
-        CreditScoreData<T::AccountId, T::BlockNumber>,
+        CreditScoreDataOf<T>,
         OptionQuery,
     >;
 
-    /// Histórico de mudanças de score
+    /// Histórico de mudanças de score, indexado por número de sequência
     #[pallet::storage]
     #[pallet::getter(fn score_history)]
     pub type ScoreHistory<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        Blake2_128Concat,
-        T::BlockNumber,
-        ScoreChange<T::AccountId, T::BlockNumber>,
+        Twox64Concat,
+        u64,
+        ScoreChangeOf<T>,
         OptionQuery,
     >;
 
+    /// Cursor do buffer circular de histórico de cada conta
+    #[pallet::storage]
+    #[pallet::getter(fn history_index)]
+    pub type HistoryIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        HistoryCursor,
+        ValueQuery,
+    >;
+
     /// Fatores que influenciam o score
     #[pallet::storage]
     #[pallet::getter(fn score_factors)]
@@ -79,8 +262,8 @@ pub mod pallet {
         T::AccountId,
         Blake2_128Concat,
         ScoreFactorType,
-        u32,
-        ValueQuery,
+        FactorRecord<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
     >;
 
     /// Contador global de scores calculados
@@ -98,6 +281,206 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Verificadores credenciados e suas estatísticas
+    #[pallet::storage]
+    #[pallet::getter(fn verifiers)]
+    pub type Verifiers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VerifierInfo<BlockNumberFor<T>, T::MaxVerifierMetadataLength>,
+        OptionQuery,
+    >;
+
+    /// Hash do score verificado por cada verificador (usuário, verificador).
+    /// Apenas o score atual de cada usuário mantém verificações.
+    #[pallet::storage]
+    #[pallet::getter(fn score_verifications)]
+    pub type ScoreVerifications<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    /// Índice reverso de `ScoreVerifications` (verificador, usuário)
+    #[pallet::storage]
+    pub type VerifierScores<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Birôs de crédito registrados como fontes atestadoras
+    #[pallet::storage]
+    #[pallet::getter(fn bureaus)]
+    pub type Bureaus<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BureauInfo<BlockNumberFor<T>, T::MaxBureauNameLength>,
+        OptionQuery,
+    >;
+
+    /// Política aplicada a fatores enviados pelo próprio usuário
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_mode)]
+    pub type AttestationMode<T: Config> = StorageValue<_, FactorAttestationMode, ValueQuery>;
+
+    /// Scorecard ativo; sem scorecard, os pesos informados nos fatores são usados
+    #[pallet::storage]
+    #[pallet::getter(fn active_scorecard)]
+    pub type ActiveScorecard<T: Config> = StorageValue<_, Scorecard<T::MaxScoreFactors>, OptionQuery>;
+
+    /// Scorecard proposto e o bloco a partir do qual será ativado
+    #[pallet::storage]
+    #[pallet::getter(fn pending_scorecard)]
+    pub type PendingScorecard<T: Config> = StorageValue<
+        _,
+        (BlockNumberFor<T>, Scorecard<T::MaxScoreFactors>),
+        OptionQuery,
+    >;
+
+    /// Última versão de scorecard proposta
+    #[pallet::storage]
+    pub type LastScorecardVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Agenda de scores que se tornam desatualizados em cada bloco
+    #[pallet::storage]
+    pub type StalenessSchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Bloco mais antigo de `StalenessSchedule` que ainda pode ter scores agendados
+    #[pallet::storage]
+    #[pallet::getter(fn staleness_cursor)]
+    pub type StalenessCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Consentimentos concedidos pelo titular do score a cada credor
+    #[pallet::storage]
+    #[pallet::getter(fn consent)]
+    pub type Consents<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Consent<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Consultas ao score de cada conta, da mais antiga para a mais recente
+    #[pallet::storage]
+    #[pallet::getter(fn inquiries)]
+    pub type Inquiries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<Inquiry<T::AccountId, BlockNumberFor<T>>, T::MaxInquiries>,
+        ValueQuery,
+    >;
+
+    /// Agenda de contas cujas consultas saem de `InquiryWindow` em cada bloco
+    #[pallet::storage]
+    pub type InquiryExpirySchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Bloco mais antigo de `InquiryExpirySchedule` que ainda pode ter contas agendadas
+    #[pallet::storage]
+    #[pallet::getter(fn inquiry_expiry_cursor)]
+    pub type InquiryExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Contestações de score por identificador
+    #[pallet::storage]
+    #[pallet::getter(fn score_dispute)]
+    pub type ScoreDisputes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        ScoreDispute<T::AccountId, BlockNumberFor<T>, T::Hash>,
+        OptionQuery,
+    >;
+
+    /// Próximo identificador de contestação
+    #[pallet::storage]
+    #[pallet::getter(fn next_dispute_id)]
+    pub type NextDisputeId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Contestação em aberto de cada conta (no máximo uma por vez)
+    #[pallet::storage]
+    #[pallet::getter(fn open_dispute_of)]
+    pub type OpenDisputeOf<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
+    /// Agenda de prazos de análise de contestações (bloco do prazo, contestação)
+    #[pallet::storage]
+    pub type DisputeDeadlines<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        u64,
+        (),
+        OptionQuery,
+    >;
+
+    /// Bloco mais antigo de `DisputeDeadlines` que ainda pode ter contestações agendadas
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_deadline_cursor)]
+    pub type DisputeDeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Congelamentos de score por conta (proteção contra fraude de identidade)
+    #[pallet::storage]
+    #[pallet::getter(fn score_freeze)]
+    pub type ScoreFreezes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ScoreFreeze<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Agenda de fim das suspensões temporárias de congelamento
+    #[pallet::storage]
+    pub type FreezeLiftExpiries<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Bloco mais antigo de `FreezeLiftExpiries` que ainda pode ter suspensões agendadas
+    #[pallet::storage]
+    #[pallet::getter(fn freeze_lift_cursor)]
+    pub type FreezeLiftCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Faixas de score em ordem crescente de score mínimo
+    #[pallet::storage]
+    #[pallet::getter(fn score_bands)]
+    pub type ScoreBands<T: Config> = StorageValue<_, BoundedVec<ScoreBand, T::MaxScoreBands>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -106,7 +489,7 @@ pub mod pallet {
             user: T::AccountId,
             score: u32,
             factors: Vec<ScoreFactor>,
-            block_number: T::BlockNumber,
+            block_number: BlockNumberFor<T>,
         },
         /// Score atualizado
         ScoreUpdated {
@@ -129,6 +512,148 @@ pub mod pallet {
             score: u32,
             verification_hash: T::Hash,
         },
+        /// Verificador credenciado pela governança
+        VerifierAdded {
+            verifier: T::AccountId,
+            metadata: Vec<u8>,
+        },
+        /// Verificador descredenciado pela governança
+        VerifierRemoved {
+            verifier: T::AccountId,
+        },
+        /// Fatores atestados por oráculo ou birô de crédito
+        FactorsAttested {
+            user: T::AccountId,
+            attester: T::AccountId,
+            factor_count: u32,
+        },
+        /// Birô de crédito registrado
+        BureauRegistered {
+            bureau: T::AccountId,
+            name: Vec<u8>,
+        },
+        /// Birô de crédito removido
+        BureauRemoved {
+            bureau: T::AccountId,
+        },
+        /// Modo de atestação alterado pela governança
+        AttestationModeChanged {
+            mode: FactorAttestationMode,
+        },
+        /// Nova versão de scorecard proposta pela governança
+        ScorecardProposed {
+            version: u32,
+            activate_at: BlockNumberFor<T>,
+            entries: Vec<ScorecardEntry>,
+        },
+        /// Scorecard ativado; novos cálculos passam a usá-lo
+        ScorecardActivated {
+            version: u32,
+        },
+        /// Proposta de scorecard cancelada antes da ativação
+        ScorecardProposalCancelled {
+            version: u32,
+        },
+        /// Principais motivos que reduziram o score, do maior para o menor impacto
+        ScoreReasonsGenerated {
+            user: T::AccountId,
+            reasons: Vec<ScoreReason>,
+        },
+        /// Score passou do período de validade sem ser recalculado
+        ScoreBecameStale {
+            user: T::AccountId,
+            calculated_at: BlockNumberFor<T>,
+        },
+        /// Titular concedeu acesso ao score a um credor
+        ConsentGranted {
+            owner: T::AccountId,
+            lender: T::AccountId,
+            purpose: ConsentPurpose,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// Titular revogou o acesso de um credor
+        ConsentRevoked {
+            owner: T::AccountId,
+            lender: T::AccountId,
+        },
+        /// Consentimento vencido removido do storage
+        ConsentExpired {
+            owner: T::AccountId,
+            lender: T::AccountId,
+        },
+        /// Credor consultou o score com consentimento do titular
+        ScorePulled {
+            owner: T::AccountId,
+            lender: T::AccountId,
+            purpose: ConsentPurpose,
+            product: ProductType,
+            score: u32,
+        },
+        /// Consulta registrada no histórico de inquiries do titular
+        InquiryRecorded {
+            owner: T::AccountId,
+            lender: T::AccountId,
+            product: ProductType,
+            is_hard: bool,
+            is_duplicate: bool,
+        },
+        /// Titular contestou seu score
+        ScoreDisputeOpened {
+            dispute_id: u64,
+            owner: T::AccountId,
+            evidence_hash: T::Hash,
+            deadline: BlockNumberFor<T>,
+        },
+        /// Revisor designado para analisar a contestação
+        ScoreDisputeReviewerAssigned {
+            dispute_id: u64,
+            reviewer: T::AccountId,
+        },
+        /// Contestação aceita e score corrigido
+        ScoreDisputeAccepted {
+            dispute_id: u64,
+            reviewer: T::AccountId,
+            old_score: u32,
+            new_score: u32,
+        },
+        /// Contestação rejeitada; o score é mantido
+        ScoreDisputeRejected {
+            dispute_id: u64,
+            reviewer: T::AccountId,
+        },
+        /// Prazo de análise encerrado sem decisão
+        ScoreDisputeExpired {
+            dispute_id: u64,
+        },
+        /// Score congelado: novas consultas e consentimentos bloqueados
+        ScoreFrozen {
+            who: T::AccountId,
+            authority: FreezeAuthority,
+        },
+        /// Congelamento removido
+        ScoreUnfrozen {
+            who: T::AccountId,
+        },
+        /// Congelamento suspenso temporariamente até o bloco indicado
+        ScoreFreezeTemporarilyLifted {
+            who: T::AccountId,
+            until: BlockNumberFor<T>,
+        },
+        /// Suspensão temporária encerrada; o congelamento voltou a valer
+        ScoreFreezeReinstated {
+            who: T::AccountId,
+        },
+        /// Governança redefiniu as faixas de score
+        ScoreBandsUpdated {
+            bands: Vec<ScoreBand>,
+        },
+        /// Score da conta passou para outra faixa; ambas as faixas seguem a
+        /// configuração vigente no recálculo
+        ScoreBandChanged {
+            user: T::AccountId,
+            old_band: Option<BandLabel>,
+            new_band: Option<BandLabel>,
+        },
     }
 
     #[pallet::error]
@@ -153,13 +678,86 @@ pub mod pallet {
         ScoreCalculationFailed,
         /// Verificação de score falhou
         ScoreVerificationFailed,
+        /// Conta não é um verificador credenciado
+        NotAVerifier,
+        /// Verificador já credenciado
+        VerifierAlreadyExists,
+        /// Verificador não encontrado
+        VerifierNotFound,
+        /// Metadados do verificador excedem o tamanho máximo
+        VerifierMetadataTooLong,
+        /// Este score já foi verificado por este verificador
+        AlreadyVerified,
+        /// Score atingiu o número máximo de verificações
+        TooManyVerifications,
+        /// Verificador tem mais scores verificados que o informado
+        VerifiedScoresWitnessTooLow,
+        /// Fatores autodeclarados não são aceitos no modo atual
+        SelfReportedFactorsNotAllowed,
+        /// Conta não é um oráculo ou birô de crédito autorizado
+        NotAnAttester,
+        /// Birô de crédito já registrado
+        BureauAlreadyExists,
+        /// Birô de crédito não encontrado
+        BureauNotFound,
+        /// Nome do birô excede o tamanho máximo
+        BureauNameTooLong,
+        /// Bloco de ativação deve estar no futuro
+        ActivationBlockInPast,
+        /// Scorecard vazio, com regra inválida ou fator duplicado
+        InvalidScorecard,
+        /// Tipo de fator não previsto no scorecard ativo
+        FactorNotInScorecard,
+        /// Scorecard sem regra para um fator derivado ou com faixa que não cobre 1-100
+        IncompatibleDerivedFactorRule,
+        /// Valor do fator fora da faixa definida no scorecard
+        FactorValueOutOfRange,
+        /// Nenhum scorecard pendente de ativação
+        NoPendingScorecard,
+        /// Duração do consentimento é zero ou excede o máximo permitido
+        InvalidConsentDuration,
+        /// Titular não pode conceder consentimento a si mesmo
+        CannotConsentToSelf,
+        /// Consentimento não encontrado
+        ConsentNotFound,
+        /// Credor já possui consentimento válido; revogue-o antes de conceder outro
+        ConsentAlreadyExists,
+        /// Consentimento ainda válido não pode ser removido como vencido
+        ConsentNotExpired,
+        /// Não há consentimento válido para o credor e a finalidade
+        NoValidConsent,
+        /// Conta já possui contestação de score em aberto
+        ScoreDisputeAlreadyOpen,
+        /// Contestação não encontrada
+        ScoreDisputeNotFound,
+        /// Contestação já foi decidida ou expirou
+        ScoreDisputeNotOpen,
+        /// Apenas o revisor designado pode decidir a contestação
+        NotDisputeReviewer,
+        /// Score congelado: consultas e consentimentos estão bloqueados
+        ScoreFrozen,
+        /// Score já está congelado
+        ScoreAlreadyFrozen,
+        /// Score não está congelado
+        ScoreNotFrozen,
+        /// Congelamento feito pela equipe de fraude só pode ser alterado por ela
+        FreezeHeldByFraudResponse,
+        /// Duração da suspensão é zero ou excede o máximo permitido
+        InvalidFreezeLiftDuration,
+        /// Faixas vazias, fora de ordem, sem rótulo ou sem cobrir o score mínimo
+        InvalidScoreBands,
+        /// Número de faixas excede o máximo permitido
+        TooManyScoreBands,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-        fn on_initialize(_n: T::BlockNumber) -> Weight {
-            // Lógica de inicialização se necessário
-            Weight::zero()
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::activate_pending_scorecard(n)
+                .saturating_add(Self::mark_stale_scores(n))
+                .saturating_add(Self::expire_score_disputes(n))
+                .saturating_add(Self::reinstate_score_freezes(n))
+                .saturating_add(Self::refresh_expired_inquiries(n))
         }
     }
 
@@ -167,234 +765,1538 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Calcula e registra um novo score de crédito
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::calculate_score())]
+        #[pallet::weight(T::WeightInfo::calculate_score(
+            factors.len() as u32,
+            T::MaxScoreVerifications::get(),
+        ))]
         pub fn calculate_score(
             origin: OriginFor<T>,
             factors: Vec<ScoreFactor>,
         ) -> DispatchResult {
             let user = ensure_signed(origin)?;
-            
-            // Verifica se não há muitos fatores
-            ensure!(
-                factors.len() <= T::MaxScoreFactors::get() as usize,
-                Error::<T>::TooManyScoreFactors
-            );
 
-            // Calcula o score baseado nos fatores
-            let score = Self::calculate_score_from_factors(&factors)?;
-            
-            // Verifica se o score está no range permitido
-            ensure!(
-                score >= T::MinScore::get() && score <= T::MaxScore::get(),
-                Error::<T>::ScoreOutOfRange
-            );
+            // Fatores autodeclarados dependem do modo de atestação
+            Self::ensure_self_reporting_allowed()?;
 
-            // Gera hash único para este cálculo
-            let score_hash = Self::generate_score_hash(&user, &factors, &score);
-            
-            // Armazena o score
-            let score_data = CreditScoreData {
-                user: user.clone(),
-                score,
-                factors: factors.clone(),
-                calculated_at: frame_system::Pallet::<T>::block_number(),
-                score_hash,
-                is_verified: false,
-                verification_count: 0,
-            };
+            let (_, score) = Self::do_store_score(
+                &user,
+                factors.clone(),
+                FactorSource::SelfReported,
+                ScoreUpdateReason::InitialCalculation,
+            )?;
 
-            CreditScores::<T>::insert(&user, score_data);
-            
-            // Armazena histórico
-            let score_change = ScoreChange {
+            Self::deposit_event(Event::ScoreCalculated {
                 user: user.clone(),
-                old_score: None,
-                new_score: score,
-                factors: factors.clone(),
+                score,
+                factors,
                 block_number: frame_system::Pallet::<T>::block_number(),
-                reason: ScoreUpdateReason::InitialCalculation,
-            };
-
-            ScoreHistory::<T>::insert(&user, frame_system::Pallet::<T>::block_number(), score_change);
-            
-            // Atualiza fatores de score
-            for factor in &factors {
-                ScoreFactors::<T>::insert(&user, factor.factor_type, factor.value);
-            }
-
-            // Atualiza contador global
-            TotalScoresCalculated::<T>::mutate(|count| *count += 1);
-            
-            // Armazena hash do último score
-            LastScoreHash::<T>::insert(&user, score_hash);
-
-            Self::deposit_event(Event::ScoreCalculated {
-                user,
-                score,
-                factors,
-                block_number: frame_system::Pallet::<T>::block_number(),
-            });
+            });
+            Self::deposit_score_reasons(&user);
 
             Ok(())
         }
 
         /// Atualiza um score existente
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::update_score())]
+        #[pallet::weight(T::WeightInfo::update_score(
+            new_factors.len() as u32,
+            T::MaxScoreVerifications::get(),
+        ))]
         pub fn update_score(
             origin: OriginFor<T>,
             new_factors: Vec<ScoreFactor>,
         ) -> DispatchResult {
             let user = ensure_signed(origin)?;
+
+            // Fatores autodeclarados dependem do modo de atestação
+            Self::ensure_self_reporting_allowed()?;
             
             // Verifica se o usuário tem score existente
-            let old_score_data = CreditScores::<T>::get(&user)
+            ensure!(CreditScores::<T>::contains_key(&user), Error::<T>::ScoreNotFound);
+
+            let (old_score, new_score) = Self::do_store_score(
+                &user,
+                new_factors,
+                FactorSource::SelfReported,
+                ScoreUpdateReason::UserUpdate,
+            )?;
+
+            Self::deposit_event(Event::ScoreUpdated {
+                user: user.clone(),
+                old_score: old_score.unwrap_or_default(),
+                new_score,
+                reason: ScoreUpdateReason::UserUpdate,
+            });
+            Self::deposit_score_reasons(&user);
+
+            Ok(())
+        }
+
+        /// Verifica um score (apenas para verificadores credenciados)
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::verify_score())]
+        pub fn verify_score(
+            origin: OriginFor<T>,
+            target_user: T::AccountId,
+        ) -> DispatchResult {
+            let verifier = ensure_signed(origin)?;
+
+            // Apenas verificadores credenciados podem verificar scores
+            let mut verifier_info = Verifiers::<T>::get(&verifier)
+                .ok_or(Error::<T>::NotAVerifier)?;
+            
+            // Verifica se o usuário tem score
+            let mut score_data = CreditScores::<T>::get(&target_user)
                 .ok_or(Error::<T>::ScoreNotFound)?;
 
-            // Calcula novo score
-            let new_score = Self::calculate_score_from_factors(&new_factors)?;
+            // Um verificador não pode verificar o mesmo score duas vezes
+            ensure!(
+                ScoreVerifications::<T>::get(&target_user, &verifier) != Some(score_data.score_hash),
+                Error::<T>::AlreadyVerified
+            );
+            ensure!(
+                score_data.verification_count < T::MaxScoreVerifications::get(),
+                Error::<T>::TooManyVerifications
+            );
+
+            // Gera hash de verificação
+            let verification_hash = Self::generate_verification_hash(&target_user, &score_data.score, &verifier);
+            let score = score_data.score;
+            
+            // Marca como verificado
+            score_data.verification_count = score_data.verification_count.saturating_add(1);
+            score_data.is_verified = true;
+            
+            ScoreVerifications::<T>::insert(&target_user, &verifier, score_data.score_hash);
+            VerifierScores::<T>::insert(&verifier, &target_user, ());
+            CreditScores::<T>::insert(&target_user, score_data);
+
+            // Atualiza estatísticas do verificador
+            verifier_info.total_verifications = verifier_info.total_verifications.saturating_add(1);
+            verifier_info.last_verification_at = Some(frame_system::Pallet::<T>::block_number());
+            Verifiers::<T>::insert(&verifier, verifier_info);
+
+            Self::deposit_event(Event::ScoreVerified {
+                user: target_user,
+                verifier,
+                score,
+                verification_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Adiciona um fator de score específico
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::add_score_factor())]
+        pub fn add_score_factor(
+            origin: OriginFor<T>,
+            factor_type: ScoreFactorType,
+            value: u32,
+            weight: u32,
+        ) -> DispatchResult {
+            let user = ensure_signed(origin)?;
+
+            // Fatores autodeclarados dependem do modo de atestação
+            Self::ensure_self_reporting_allowed()?;
+            
+            // Verifica se o valor do fator é válido
+            ensure!(value > 0, Error::<T>::InvalidScoreFactor);
+            ensure!(weight > 0 && weight <= 100, Error::<T>::InvalidFactorWeight);
+
+            // Armazena o fator, marcado como autodeclarado
+            ScoreFactors::<T>::insert(&user, factor_type, FactorRecord {
+                value,
+                source: FactorSource::SelfReported,
+                recorded_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::ScoreFactorAdded {
+                user,
+                factor_type,
+                value,
+                weight,
+            });
+
+            Ok(())
+        }
+
+        /// Credencia um novo verificador de scores (apenas governança)
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::add_verifier())]
+        pub fn add_verifier(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Verifiers::<T>::contains_key(&verifier),
+                Error::<T>::VerifierAlreadyExists
+            );
+
+            let bounded_metadata: BoundedVec<u8, T::MaxVerifierMetadataLength> = metadata
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::VerifierMetadataTooLong)?;
+
+            let verifier_info = VerifierInfo {
+                metadata: bounded_metadata,
+                registered_at: frame_system::Pallet::<T>::block_number(),
+                total_verifications: 0,
+                last_verification_at: None,
+            };
+
+            Verifiers::<T>::insert(&verifier, verifier_info);
+
+            Self::deposit_event(Event::VerifierAdded {
+                verifier,
+                metadata,
+            });
+
+            Ok(())
+        }
+
+        /// Descredencia um verificador de scores (apenas governança) e desfaz as
+        /// verificações feitas por ele. `verified_scores` é o número de scores
+        /// atualmente verificados pelo verificador e limita o peso cobrado.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_verifier(*verified_scores))]
+        pub fn remove_verifier(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+            verified_scores: u32,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Verifiers::<T>::contains_key(&verifier),
+                Error::<T>::VerifierNotFound
+            );
+            ensure!(
+                VerifierScores::<T>::iter_key_prefix(&verifier)
+                    .take(verified_scores.saturating_add(1) as usize)
+                    .count() as u32 <= verified_scores,
+                Error::<T>::VerifiedScoresWitnessTooLow
+            );
+
+            Verifiers::<T>::remove(&verifier);
+
+            for (user, ()) in VerifierScores::<T>::drain_prefix(&verifier) {
+                ScoreVerifications::<T>::remove(&user, &verifier);
+                CreditScores::<T>::mutate_extant(&user, |score_data| {
+                    score_data.verification_count = score_data.verification_count.saturating_sub(1);
+                    score_data.is_verified = score_data.verification_count > 0;
+                });
+            }
+
+            Self::deposit_event(Event::VerifierRemoved { verifier });
+
+            Ok(())
+        }
+
+        /// Submete fatores atestados por um oráculo ou birô de crédito
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::submit_attested_factors(
+            factors.len() as u32,
+            T::MaxScoreVerifications::get(),
+        ))]
+        pub fn submit_attested_factors(
+            origin: OriginFor<T>,
+            target_user: T::AccountId,
+            factors: Vec<ScoreFactor>,
+        ) -> DispatchResult {
+            let attester = ensure_signed(origin)?;
+
+            ensure!(Self::is_attester(&attester), Error::<T>::NotAnAttester);
+
+            let factor_count = factors.len() as u32;
+            let (old_score, new_score) = Self::do_store_score(
+                &target_user,
+                factors.clone(),
+                FactorSource::Attester(attester.clone()),
+                ScoreUpdateReason::AttestationUpdate,
+            )?;
+
+            match old_score {
+                Some(old_score) => Self::deposit_event(Event::ScoreUpdated {
+                    user: target_user.clone(),
+                    old_score,
+                    new_score,
+                    reason: ScoreUpdateReason::AttestationUpdate,
+                }),
+                None => Self::deposit_event(Event::ScoreCalculated {
+                    user: target_user.clone(),
+                    score: new_score,
+                    factors,
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                }),
+            }
+            Self::deposit_score_reasons(&target_user);
+
+            Self::deposit_event(Event::FactorsAttested {
+                user: target_user,
+                attester,
+                factor_count,
+            });
+
+            Ok(())
+        }
+
+        /// Registra um birô de crédito como fonte atestadora (apenas governança)
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::register_bureau())]
+        pub fn register_bureau(
+            origin: OriginFor<T>,
+            bureau: T::AccountId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !Bureaus::<T>::contains_key(&bureau),
+                Error::<T>::BureauAlreadyExists
+            );
+
+            let bounded_name: BoundedVec<u8, T::MaxBureauNameLength> = name
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::BureauNameTooLong)?;
+
+            Bureaus::<T>::insert(&bureau, BureauInfo {
+                name: bounded_name,
+                registered_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::BureauRegistered { bureau, name });
+
+            Ok(())
+        }
+
+        /// Remove um birô de crédito (apenas governança)
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_bureau())]
+        pub fn remove_bureau(
+            origin: OriginFor<T>,
+            bureau: T::AccountId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                Bureaus::<T>::contains_key(&bureau),
+                Error::<T>::BureauNotFound
+            );
+
+            Bureaus::<T>::remove(&bureau);
+
+            Self::deposit_event(Event::BureauRemoved { bureau });
+
+            Ok(())
+        }
+
+        /// Define se fatores autodeclarados são rejeitados ou apenas sinalizados
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_attestation_mode())]
+        pub fn set_attestation_mode(
+            origin: OriginFor<T>,
+            mode: FactorAttestationMode,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            AttestationMode::<T>::put(mode.clone());
+
+            Self::deposit_event(Event::AttestationModeChanged { mode });
+
+            Ok(())
+        }
+
+        /// Propõe uma nova versão de scorecard, ativada no bloco informado
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::propose_scorecard())]
+        pub fn propose_scorecard(
+            origin: OriginFor<T>,
+            entries: Vec<ScorecardEntry>,
+            activate_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                activate_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ActivationBlockInPast
+            );
+
+            // Cada regra deve ser válida e cada tipo de fator aparecer uma vez
+            ensure!(!entries.is_empty(), Error::<T>::InvalidScorecard);
+            for (index, entry) in entries.iter().enumerate() {
+                ensure!(entry.is_valid(), Error::<T>::InvalidScorecard);
+                ensure!(
+                    !entries[..index].iter().any(|other| other.factor_type == entry.factor_type),
+                    Error::<T>::InvalidScorecard
+                );
+            }
+
+            // Fatores derivados entram em todo cálculo e precisam de regra que aceite 1-100
+            for factor_type in DERIVED_FACTOR_TYPES {
+                ensure!(
+                    entries.iter().any(|entry| entry.factor_type == factor_type && entry.accepts(1, 100)),
+                    Error::<T>::IncompatibleDerivedFactorRule
+                );
+            }
+
+            let bounded_entries: BoundedVec<ScorecardEntry, T::MaxScoreFactors> = entries
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyScoreFactors)?;
+
+            let version = LastScorecardVersion::<T>::get().saturating_add(1);
+            LastScorecardVersion::<T>::put(version);
+
+            // Uma nova proposta substitui a pendente
+            if let Some((_, replaced)) = PendingScorecard::<T>::take() {
+                Self::deposit_event(Event::ScorecardProposalCancelled { version: replaced.version });
+            }
+
+            PendingScorecard::<T>::put((activate_at, Scorecard {
+                version,
+                entries: bounded_entries,
+            }));
+
+            Self::deposit_event(Event::ScorecardProposed {
+                version,
+                activate_at,
+                entries,
+            });
+
+            Ok(())
+        }
+
+        /// Cancela a proposta de scorecard pendente
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::cancel_scorecard_proposal())]
+        pub fn cancel_scorecard_proposal(origin: OriginFor<T>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let (_, scorecard) = PendingScorecard::<T>::take()
+                .ok_or(Error::<T>::NoPendingScorecard)?;
+
+            Self::deposit_event(Event::ScorecardProposalCancelled { version: scorecard.version });
+
+            Ok(())
+        }
+
+        /// Concede a um credor acesso ao score para uma finalidade, por `duration` blocos.
+        /// Cada credor tem um único consentimento: um vencido é substituído, enquanto um
+        /// ainda válido precisa ser revogado antes.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::grant_consent())]
+        pub fn grant_consent(
+            origin: OriginFor<T>,
+            lender: T::AccountId,
+            purpose: ConsentPurpose,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::ensure_not_frozen(&owner)?;
+            ensure!(owner != lender, Error::<T>::CannotConsentToSelf);
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxConsentDuration::get(),
+                Error::<T>::InvalidConsentDuration
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                Consents::<T>::get(&owner, &lender).is_none_or(|consent| consent.expires_at <= now),
+                Error::<T>::ConsentAlreadyExists
+            );
+            let expires_at = now.saturating_add(duration);
+
+            Consents::<T>::insert(&owner, &lender, Consent {
+                purpose,
+                granted_at: now,
+                expires_at,
+            });
+
+            Self::deposit_event(Event::ConsentGranted {
+                owner,
+                lender,
+                purpose,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Revoga o acesso de um credor ao score
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::revoke_consent())]
+        pub fn revoke_consent(origin: OriginFor<T>, lender: T::AccountId) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Consents::<T>::take(&owner, &lender).ok_or(Error::<T>::ConsentNotFound)?;
+
+            Self::deposit_event(Event::ConsentRevoked { owner, lender });
+
+            Ok(())
+        }
+
+        /// Registra a consulta de um credor ao score de um titular.
+        /// Exige consentimento válido para a finalidade e fica registrada como inquiry.
+        /// Consultas para pedido de crédito são hard inquiries e afetam o fator NewCredit.
+        /// Consentimentos vencidos são removidos por `prune_expired_consent`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::pull_score(T::MaxScoreVerifications::get()))]
+        pub fn pull_score(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            purpose: ConsentPurpose,
+            product: ProductType,
+        ) -> DispatchResult {
+            let lender = ensure_signed(origin)?;
+
+            Self::ensure_not_frozen(&owner)?;
+
+            ensure!(
+                Self::has_valid_consent(&owner, &lender, purpose),
+                Error::<T>::NoValidConsent
+            );
+            let score_data = CreditScores::<T>::get(&owner).ok_or(Error::<T>::ScoreNotFound)?;
+
+            Self::deposit_event(Event::ScorePulled {
+                owner: owner.clone(),
+                lender: lender.clone(),
+                purpose,
+                product,
+                score: score_data.score,
+            });
+
+            // O score consultado é o anterior à consulta; a nova inquiry afeta os próximos
+            if Self::record_inquiry(&owner, &lender, purpose, product) {
+                if let Some(factor) = Self::derived_new_credit_factor(&owner) {
+                    Self::recalculate_with_derived_factor(&owner, factor);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Contesta o próprio score, apresentando o hash das evidências
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::open_score_dispute())]
+        pub fn open_score_dispute(origin: OriginFor<T>, evidence_hash: T::Hash) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            let score_data = CreditScores::<T>::get(&owner).ok_or(Error::<T>::ScoreNotFound)?;
+            ensure!(!OpenDisputeOf::<T>::contains_key(&owner), Error::<T>::ScoreDisputeAlreadyOpen);
+
+            let dispute_id = NextDisputeId::<T>::get();
+            let now = frame_system::Pallet::<T>::block_number();
+            // Prazo mínimo de um bloco: contestações vencem em blocos futuros
+            let deadline = now.saturating_add(T::DisputeReviewPeriod::get().max(One::one()));
+
+            ScoreDisputes::<T>::insert(dispute_id, ScoreDispute {
+                owner: owner.clone(),
+                evidence_hash,
+                disputed_score: score_data.score,
+                opened_at: now,
+                deadline,
+                reviewer: None,
+                status: ScoreDisputeStatus::Open,
+            });
+            OpenDisputeOf::<T>::insert(&owner, dispute_id);
+            DisputeDeadlines::<T>::insert(deadline, dispute_id, ());
+            NextDisputeId::<T>::put(dispute_id.saturating_add(1));
+
+            Self::deposit_event(Event::ScoreDisputeOpened {
+                dispute_id,
+                owner,
+                evidence_hash,
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Designa um verificador credenciado para analisar a contestação (apenas governança)
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::assign_dispute_reviewer())]
+        pub fn assign_dispute_reviewer(
+            origin: OriginFor<T>,
+            dispute_id: u64,
+            reviewer: T::AccountId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(Verifiers::<T>::contains_key(&reviewer), Error::<T>::NotAVerifier);
+
+            ScoreDisputes::<T>::try_mutate(dispute_id, |maybe_dispute| -> DispatchResult {
+                let dispute = maybe_dispute.as_mut().ok_or(Error::<T>::ScoreDisputeNotFound)?;
+                ensure!(dispute.is_open(), Error::<T>::ScoreDisputeNotOpen);
+
+                // O titular não pode analisar a própria contestação
+                ensure!(dispute.owner != reviewer, Error::<T>::NotDisputeReviewer);
+
+                dispute.reviewer = Some(reviewer.clone());
+                dispute.status = ScoreDisputeStatus::UnderReview;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ScoreDisputeReviewerAssigned { dispute_id, reviewer });
+
+            Ok(())
+        }
+
+        /// Aceita a contestação e recalcula o score com os fatores corrigidos
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::accept_score_dispute(
+            corrected_factors.len() as u32,
+            T::MaxScoreVerifications::get(),
+        ))]
+        pub fn accept_score_dispute(
+            origin: OriginFor<T>,
+            dispute_id: u64,
+            corrected_factors: Vec<ScoreFactor>,
+        ) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+
+            let dispute = Self::ensure_dispute_reviewer(dispute_id, &reviewer)?;
+            ensure!(CreditScores::<T>::contains_key(&dispute.owner), Error::<T>::ScoreNotFound);
+
+            // Os fatores corrigidos passam a ser atribuídos ao revisor
+            let (old_score, new_score) = Self::do_store_score(
+                &dispute.owner,
+                corrected_factors,
+                FactorSource::DisputeReviewer(reviewer.clone()),
+                ScoreUpdateReason::ComplianceUpdate,
+            )?;
+            let old_score = old_score.unwrap_or_default();
+
+            Self::close_dispute(dispute_id, &dispute, ScoreDisputeStatus::Accepted);
+
+            Self::deposit_event(Event::ScoreUpdated {
+                user: dispute.owner.clone(),
+                old_score,
+                new_score,
+                reason: ScoreUpdateReason::ComplianceUpdate,
+            });
+            Self::deposit_score_reasons(&dispute.owner);
+            Self::deposit_event(Event::ScoreDisputeAccepted {
+                dispute_id,
+                reviewer,
+                old_score,
+                new_score,
+            });
+
+            Ok(())
+        }
+
+        /// Rejeita a contestação, mantendo o score atual
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::reject_score_dispute())]
+        pub fn reject_score_dispute(origin: OriginFor<T>, dispute_id: u64) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+
+            let dispute = Self::ensure_dispute_reviewer(dispute_id, &reviewer)?;
+            Self::close_dispute(dispute_id, &dispute, ScoreDisputeStatus::Rejected);
+
+            Self::deposit_event(Event::ScoreDisputeRejected { dispute_id, reviewer });
+
+            Ok(())
+        }
+
+        /// Congela o próprio score, bloqueando consultas e novos consentimentos
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::freeze_score())]
+        pub fn freeze_score(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!ScoreFreezes::<T>::contains_key(&who), Error::<T>::ScoreAlreadyFrozen);
+
+            Self::do_freeze(&who, FreezeAuthority::Owner);
+
+            Ok(())
+        }
+
+        /// Remove o congelamento definido pelo próprio titular
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::unfreeze_score())]
+        pub fn unfreeze_score(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let freeze = ScoreFreezes::<T>::get(&who).ok_or(Error::<T>::ScoreNotFrozen)?;
+            ensure!(freeze.authority == FreezeAuthority::Owner, Error::<T>::FreezeHeldByFraudResponse);
+
+            Self::do_unfreeze(&who, &freeze);
+
+            Ok(())
+        }
+
+        /// Suspende o congelamento por `duration` blocos (ex.: para um pedido de crédito)
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::lift_freeze_temporarily())]
+        pub fn lift_freeze_temporarily(origin: OriginFor<T>, duration: BlockNumberFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxFreezeLiftDuration::get(),
+                Error::<T>::InvalidFreezeLiftDuration
+            );
+
+            let mut freeze = ScoreFreezes::<T>::get(&who).ok_or(Error::<T>::ScoreNotFrozen)?;
+            ensure!(freeze.authority == FreezeAuthority::Owner, Error::<T>::FreezeHeldByFraudResponse);
+
+            // Uma nova suspensão substitui a anterior
+            if let Some(previous) = freeze.lifted_until {
+                FreezeLiftExpiries::<T>::remove(previous, &who);
+            }
+
+            let until = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+            freeze.lifted_until = Some(until);
+            ScoreFreezes::<T>::insert(&who, freeze);
+            FreezeLiftExpiries::<T>::insert(until, &who, ());
+
+            Self::deposit_event(Event::ScoreFreezeTemporarilyLifted { who, until });
+
+            Ok(())
+        }
+
+        /// Congela o score de uma conta vítima de fraude (apenas equipe de fraude)
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::force_freeze_score())]
+        pub fn force_freeze_score(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::FraudResponseOrigin::ensure_origin(origin)?;
+
+            if let Some(freeze) = ScoreFreezes::<T>::get(&who) {
+                ensure!(freeze.authority == FreezeAuthority::Owner, Error::<T>::ScoreAlreadyFrozen);
+
+                // Assume o congelamento do titular, encerrando qualquer suspensão
+                if let Some(until) = freeze.lifted_until {
+                    FreezeLiftExpiries::<T>::remove(until, &who);
+                }
+            }
+
+            Self::do_freeze(&who, FreezeAuthority::FraudResponse);
+
+            Ok(())
+        }
+
+        /// Remove qualquer congelamento de uma conta (apenas equipe de fraude)
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::force_unfreeze_score())]
+        pub fn force_unfreeze_score(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::FraudResponseOrigin::ensure_origin(origin)?;
+
+            let freeze = ScoreFreezes::<T>::get(&who).ok_or(Error::<T>::ScoreNotFrozen)?;
+            Self::do_unfreeze(&who, &freeze);
+
+            Ok(())
+        }
+
+        /// Define as faixas de score (apenas governança). A primeira faixa deve
+        /// começar em `MinScore` e as seguintes em scores estritamente crescentes.
+        /// Scores existentes são reclassificados no próximo recálculo.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_score_bands(bands.len() as u32))]
+        pub fn set_score_bands(origin: OriginFor<T>, bands: Vec<ScoreBand>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                bands.first().is_some_and(|band| band.min_score == T::MinScore::get()),
+                Error::<T>::InvalidScoreBands
+            );
+            for (index, band) in bands.iter().enumerate() {
+                ensure!(!band.label.is_empty(), Error::<T>::InvalidScoreBands);
+                ensure!(
+                    index == 0 || bands[index - 1].min_score < band.min_score,
+                    Error::<T>::InvalidScoreBands
+                );
+            }
+
+            let bounded_bands: BoundedVec<ScoreBand, T::MaxScoreBands> = bands
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyScoreBands)?;
+
+            ScoreBands::<T>::put(bounded_bands);
+
+            Self::deposit_event(Event::ScoreBandsUpdated { bands });
+
+            Ok(())
+        }
+
+        /// Remove um consentimento vencido. Qualquer conta pode chamar, já que o
+        /// consentimento não concede mais acesso.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::prune_expired_consent())]
+        pub fn prune_expired_consent(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            lender: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let consent = Consents::<T>::get(&owner, &lender).ok_or(Error::<T>::ConsentNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(consent.expires_at <= now, Error::<T>::ConsentNotExpired);
+
+            Consents::<T>::remove(&owner, &lender);
+
+            Self::deposit_event(Event::ConsentExpired { owner, lender });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Verifica se a conta é um oráculo ativo ou birô de crédito registrado
+        pub fn is_attester(who: &T::AccountId) -> bool {
+            T::OracleAttesters::contains(who) || Bureaus::<T>::contains_key(who)
+        }
+
+        /// Indica se o titular concedeu ao credor acesso ainda válido para a finalidade
+        pub fn has_valid_consent(
+            owner: &T::AccountId,
+            lender: &T::AccountId,
+            purpose: ConsentPurpose,
+        ) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            Consents::<T>::get(owner, lender)
+                .is_some_and(|consent| consent.purpose == purpose && now < consent.expires_at)
+        }
+
+        /// Registra uma consulta, descartando a mais antiga quando o limite é atingido.
+        /// Hard inquiries do mesmo credor dentro de `InquiryDedupPeriod` são marcadas
+        /// como duplicadas. Retorna se a consulta conta para o fator NewCredit.
+        fn record_inquiry(
+            owner: &T::AccountId,
+            lender: &T::AccountId,
+            purpose: ConsentPurpose,
+            product: ProductType,
+        ) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            let is_hard = purpose == ConsentPurpose::CreditApplication;
+
+            Inquiries::<T>::mutate(owner, |inquiries| {
+                let is_duplicate = is_hard
+                    && inquiries.iter().any(|inquiry| {
+                        inquiry.counts_toward_new_credit()
+                            && &inquiry.lender == lender
+                            && now < inquiry.inquired_at.saturating_add(T::InquiryDedupPeriod::get())
+                    });
+
+                if inquiries.is_full() && !inquiries.is_empty() {
+                    inquiries.remove(0);
+                }
+                // Não falha: há espaço após a remoção (ou o limite é zero)
+                let _ = inquiries.try_push(Inquiry {
+                    lender: lender.clone(),
+                    purpose,
+                    product,
+                    inquired_at: now,
+                    is_hard,
+                    is_duplicate,
+                });
+
+                Self::deposit_event(Event::InquiryRecorded {
+                    owner: owner.clone(),
+                    lender: lender.clone(),
+                    product,
+                    is_hard,
+                    is_duplicate,
+                });
+
+                let counts = is_hard && !is_duplicate;
+                if counts {
+                    // O fator NewCredit é recalculado quando a consulta sai da janela
+                    let expires_at = now.saturating_add(T::InquiryWindow::get().max(One::one()));
+                    InquiryExpirySchedule::<T>::insert(expires_at, owner, ());
+                }
+                counts
+            })
+        }
+
+        /// Número de hard inquiries não duplicadas dentro de `InquiryWindow`
+        pub fn recent_hard_inquiries(who: &T::AccountId) -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            Inquiries::<T>::get(who)
+                .iter()
+                .filter(|inquiry| {
+                    inquiry.counts_toward_new_credit()
+                        && now < inquiry.inquired_at.saturating_add(T::InquiryWindow::get())
+                })
+                .count() as u32
+        }
+
+        /// Retorna a contestação em análise, exigindo que `reviewer` seja o revisor designado
+        fn ensure_dispute_reviewer(
+            dispute_id: u64,
+            reviewer: &T::AccountId,
+        ) -> Result<ScoreDisputeOf<T>, DispatchError> {
+            let dispute = ScoreDisputes::<T>::get(dispute_id).ok_or(Error::<T>::ScoreDisputeNotFound)?;
+            ensure!(dispute.is_open(), Error::<T>::ScoreDisputeNotOpen);
+            ensure!(dispute.reviewer.as_ref() == Some(reviewer), Error::<T>::NotDisputeReviewer);
+            Ok(dispute)
+        }
+
+        /// Encerra uma contestação com o status final, liberando a conta para nova contestação
+        fn close_dispute(
+            dispute_id: u64,
+            dispute: &ScoreDisputeOf<T>,
+            status: ScoreDisputeStatus,
+        ) {
+            ScoreDisputes::<T>::mutate(dispute_id, |maybe_dispute| {
+                if let Some(dispute) = maybe_dispute {
+                    dispute.status = status;
+                }
+            });
+            OpenDisputeOf::<T>::remove(&dispute.owner);
+            DisputeDeadlines::<T>::remove(dispute.deadline, dispute_id);
+        }
+
+        /// Expira as contestações cujo prazo de análise terminou, no máximo
+        /// `MaxDisputeExpiriesPerBlock` por bloco
+        fn expire_score_disputes(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<DisputeDeadlines<T>, DisputeDeadlineCursor<T>, _, _>(
+                now,
+                T::MaxDisputeExpiriesPerBlock::get(),
+                db_weight,
+                |dispute_id: u64| {
+                    let mut weight = db_weight.reads_writes(1, 1);
+
+                    let Some(dispute) = ScoreDisputes::<T>::get(dispute_id) else { return weight };
+                    if !dispute.is_open() {
+                        return weight;
+                    }
+
+                    Self::close_dispute(dispute_id, &dispute, ScoreDisputeStatus::Expired);
+                    weight = weight.saturating_add(db_weight.writes(2));
+
+                    Self::deposit_event(Event::ScoreDisputeExpired { dispute_id });
+
+                    weight
+                },
+            )
+        }
+
+        /// Faixa vigente que contém o score, se houver faixas configuradas
+        pub fn band_for(score: u32) -> Option<ScoreBand> {
+            ScoreBands::<T>::get()
+                .into_iter()
+                .rev()
+                .find(|band| band.min_score <= score)
+        }
+
+        /// Faixa vigente do score atual de uma conta
+        pub fn score_band(who: &T::AccountId) -> Option<ScoreBand> {
+            Self::band_for(CreditScores::<T>::get(who)?.score)
+        }
+
+        /// Indica se o score está congelado e sem suspensão temporária em vigor
+        pub fn is_frozen(who: &T::AccountId) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            ScoreFreezes::<T>::get(who)
+                .is_some_and(|freeze| freeze.lifted_until.is_none_or(|until| now >= until))
+        }
+
+        /// Rejeita operações de acesso ao score de contas congeladas
+        fn ensure_not_frozen(who: &T::AccountId) -> DispatchResult {
+            ensure!(!Self::is_frozen(who), Error::<T>::ScoreFrozen);
+            Ok(())
+        }
+
+        fn do_freeze(who: &T::AccountId, authority: FreezeAuthority) {
+            ScoreFreezes::<T>::insert(who, ScoreFreeze {
+                authority,
+                frozen_at: frame_system::Pallet::<T>::block_number(),
+                lifted_until: None,
+            });
+
+            Self::deposit_event(Event::ScoreFrozen { who: who.clone(), authority });
+        }
+
+        fn do_unfreeze(who: &T::AccountId, freeze: &ScoreFreeze<BlockNumberFor<T>>) {
+            if let Some(until) = freeze.lifted_until {
+                FreezeLiftExpiries::<T>::remove(until, who);
+            }
+            ScoreFreezes::<T>::remove(who);
+
+            Self::deposit_event(Event::ScoreUnfrozen { who: who.clone() });
+        }
+
+        /// Restabelece os congelamentos cuja suspensão temporária terminou, no máximo
+        /// `MaxFreezeReinstatesPerBlock` por bloco
+        fn reinstate_score_freezes(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<FreezeLiftExpiries<T>, FreezeLiftCursor<T>, _, _>(
+                now,
+                T::MaxFreezeReinstatesPerBlock::get(),
+                db_weight,
+                |who: T::AccountId| {
+                    let mut weight = db_weight.reads_writes(2, 1);
+
+                    ScoreFreezes::<T>::mutate(&who, |maybe_freeze| {
+                        if let Some(freeze) = maybe_freeze {
+                            if freeze.lifted_until.is_some_and(|until| until <= now) {
+                                freeze.lifted_until = None;
+                                weight = weight.saturating_add(db_weight.writes(1));
+
+                                Self::deposit_event(Event::ScoreFreezeReinstated { who: who.clone() });
+                            }
+                        }
+                    });
+
+                    weight
+                },
+            )
+        }
+
+        /// Recalcula os scores cujas consultas saíram de `InquiryWindow`, no máximo
+        /// `MaxInquiryExpiriesPerBlock` por bloco
+        fn refresh_expired_inquiries(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<InquiryExpirySchedule<T>, InquiryExpiryCursor<T>, _, _>(
+                now,
+                T::MaxInquiryExpiriesPerBlock::get(),
+                db_weight,
+                |user: T::AccountId| {
+                    let weight = db_weight.reads(2);
+                    if !CreditScores::<T>::contains_key(&user) {
+                        return weight;
+                    }
+
+                    let Some(factor) = Self::derived_new_credit_factor(&user) else { return weight };
+                    Self::recalculate_with_derived_factor(&user, factor);
+
+                    weight.saturating_add(T::WeightInfo::update_score(
+                        T::MaxScoreFactors::get(),
+                        T::MaxScoreVerifications::get(),
+                    ))
+                },
+            )
+        }
+
+        /// Rejeita fatores autodeclarados quando o modo exige atestação
+        fn ensure_self_reporting_allowed() -> DispatchResult {
+            ensure!(
+                AttestationMode::<T>::get() != FactorAttestationMode::AttestedOnly,
+                Error::<T>::SelfReportedFactorsNotAllowed
+            );
+            Ok(())
+        }
+
+        /// Calcula, armazena e registra no histórico o score de um usuário.
+        /// Retorna o score anterior (se existir) e o novo score.
+        fn do_store_score(
+            user: &T::AccountId,
+            factors: Vec<ScoreFactor>,
+            source: FactorSource<T::AccountId>,
+            reason: ScoreUpdateReason,
+        ) -> Result<(Option<u32>, u32), DispatchError> {
+            // Fatores derivados de dados on-chain substituem os informados
+            let (raw_factors, derived_types) = Self::merge_derived_factors(user, factors);
+
+            // O scorecard ativo define peso e normalização de cada fator
+            let (factors, scorecard_version) = Self::apply_scorecard(&raw_factors)?;
+
+            // Verifica se não há muitos fatores
+            let factors: BoundedVec<ScoreFactor, T::MaxScoreFactors> = factors
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyScoreFactors)?;
+
+            // Calcula o score baseado nos fatores
+            let score = Self::calculate_score_from_factors(&factors)?;
             
             // Verifica se o score está no range permitido
             ensure!(
-                new_score >= T::MinScore::get() && new_score <= T::MaxScore::get(),
+                score >= T::MinScore::get() && score <= T::MaxScore::get(),
                 Error::<T>::ScoreOutOfRange
             );
 
-            // Gera novo hash
-            let new_score_hash = Self::generate_score_hash(&user, &new_factors, &new_score);
+            let now = frame_system::Pallet::<T>::block_number();
+            let old_data = CreditScores::<T>::get(user);
+            let old_score = old_data.as_ref().map(|data| data.score);
+            let model = T::ScoringModel::info();
+
+            // Reagenda o momento em que o score ficará desatualizado
+            if let Some(old_data) = &old_data {
+                StalenessSchedule::<T>::remove(Self::stale_at(old_data.calculated_at), user);
+            }
+            StalenessSchedule::<T>::insert(Self::stale_at(now), user, ());
+
+            // Gera hash único para este cálculo
+            let score_hash = Self::generate_score_hash(user, &factors, &score);
+
+            // Verificações do score anterior não valem para o novo
+            for (verifier, _) in ScoreVerifications::<T>::drain_prefix(user) {
+                VerifierScores::<T>::remove(&verifier, user);
+            }
+
+            // Faixa anterior e nova são avaliadas com as mesmas faixas vigentes
+            let band = Self::band_for(score).map(|band| band.label);
+            let old_band = old_score.and_then(Self::band_for).map(|band| band.label);
             
-            // Atualiza score
-            let updated_score_data = CreditScoreData {
+            // Armazena o score
+            let score_data = CreditScoreData {
                 user: user.clone(),
-                score: new_score,
-                factors: new_factors.clone(),
-                calculated_at: frame_system::Pallet::<T>::block_number(),
-                score_hash: new_score_hash,
+                score,
+                factors: factors.clone(),
+                calculated_at: now,
+                score_hash,
                 is_verified: false,
                 verification_count: 0,
+                source: source.clone(),
+                model,
+                scorecard_version,
+                is_stale: false,
+                band: band.clone(),
             };
 
-            CreditScores::<T>::insert(&user, updated_score_data);
+            CreditScores::<T>::insert(user, score_data);
+
+            if old_data.is_some() && band != old_band {
+                Self::deposit_event(Event::ScoreBandChanged {
+                    user: user.clone(),
+                    old_band,
+                    new_band: band,
+                });
+            }
             
-            // Armazena histórico da mudança
-            let score_change = ScoreChange {
+            // Armazena histórico
+            Self::append_history(user, |sequence| ScoreChange {
                 user: user.clone(),
-                old_score: Some(old_score_data.score),
-                new_score,
-                factors: new_factors.clone(),
-                block_number: frame_system::Pallet::<T>::block_number(),
-                reason: ScoreUpdateReason::UserUpdate,
-            };
+                old_score,
+                new_score: score,
+                factors: factors.clone(),
+                block_number: now,
+                reason,
+                model,
+                scorecard_version,
+                sequence,
+            });
+            
+            // Atualiza fatores de score (valores brutos), registrando a fonte de cada um
+            for factor in &raw_factors {
+                let factor_source = if derived_types.contains(&factor.factor_type) {
+                    FactorSource::System
+                } else {
+                    source.clone()
+                };
+                ScoreFactors::<T>::insert(user, factor.factor_type, FactorRecord {
+                    value: factor.value,
+                    source: factor_source,
+                    recorded_at: now,
+                });
+            }
 
-            ScoreHistory::<T>::insert(&user, frame_system::Pallet::<T>::block_number(), score_change);
+            // Atualiza contador global
+            if old_score.is_none() {
+                TotalScoresCalculated::<T>::mutate(|count| *count += 1);
+            }
             
-            // Atualiza fatores
-            for factor in &new_factors {
-                ScoreFactors::<T>::insert(&user, factor.factor_type, factor.value);
+            // Armazena hash do último score
+            LastScoreHash::<T>::insert(user, score_hash);
+
+            Ok((old_score, score))
+        }
+
+        /// Fator PaymentHistory calculado a partir dos pagamentos on-chain.
+        /// Retorna `None` enquanto a conta não tiver pagamentos com desfecho.
+        pub fn derived_payment_history_factor(who: &T::AccountId) -> Option<ScoreFactor> {
+            let summary = T::PaymentHistory::payment_summary(who);
+            let total = summary.total();
+            if total == 0 {
+                return None;
             }
 
-            // Atualiza hash do último score
-            LastScoreHash::<T>::insert(&user, new_score_hash);
+            // Percentual de pagamentos concluídos (1-100)
+            let value = (summary.completed.saturating_mul(100) / total).max(1);
 
-            Self::deposit_event(Event::ScoreUpdated {
+            Some(ScoreFactor {
+                factor_type: ScoreFactorType::PaymentHistory,
+                value,
+                weight: T::PaymentHistoryWeight::get(),
+            })
+        }
+
+        /// Deriva o fator NewCredit das hard inquiries recentes. Cada consulta na
+        /// janela reduz o valor em `NEW_CREDIT_PENALTY_PER_INQUIRY` pontos (mínimo 1).
+        /// Retorna `None` se a conta nunca foi consultada; depois disso o fator
+        /// continua derivado, com valor 100 quando não há consultas na janela.
+        pub fn derived_new_credit_factor(who: &T::AccountId) -> Option<ScoreFactor> {
+            if Inquiries::<T>::get(who).is_empty() {
+                return None;
+            }
+
+            let penalty = Self::recent_hard_inquiries(who).saturating_mul(NEW_CREDIT_PENALTY_PER_INQUIRY);
+            let value = 100u32.saturating_sub(penalty).max(1);
+
+            Some(ScoreFactor {
+                factor_type: ScoreFactorType::NewCredit,
+                value,
+                weight: T::NewCreditWeight::get(),
+            })
+        }
+
+        /// Substitui fatores informados pelos derivados de dados on-chain.
+        /// Retorna os fatores resultantes e os tipos que foram derivados.
+        fn merge_derived_factors(
+            user: &T::AccountId,
+            mut factors: Vec<ScoreFactor>,
+        ) -> (Vec<ScoreFactor>, Vec<ScoreFactorType>) {
+            let mut derived_types = Vec::new();
+
+            let derived = [
+                Self::derived_payment_history_factor(user),
+                Self::derived_new_credit_factor(user),
+            ];
+
+            for derived_factor in derived.into_iter().flatten() {
+                factors.retain(|factor| factor.factor_type != derived_factor.factor_type);
+                derived_types.push(derived_factor.factor_type);
+                factors.push(derived_factor);
+            }
+
+            (factors, derived_types)
+        }
+
+        /// Converte fatores brutos nos fatores efetivos do scorecard ativo.
+        /// Sem scorecard ativo, os fatores são usados como informados (versão 0).
+        fn apply_scorecard(factors: &[ScoreFactor]) -> Result<(Vec<ScoreFactor>, u32), Error<T>> {
+            let Some(scorecard) = ActiveScorecard::<T>::get() else {
+                return Ok((factors.to_vec(), 0));
+            };
+
+            let mut effective = Vec::with_capacity(factors.len());
+            for factor in factors {
+                let entry = scorecard
+                    .entry(&factor.factor_type)
+                    .ok_or(Error::<T>::FactorNotInScorecard)?;
+
+                ensure!(
+                    factor.value >= entry.min_value && factor.value <= entry.max_value,
+                    Error::<T>::FactorValueOutOfRange
+                );
+
+                effective.push(ScoreFactor {
+                    factor_type: factor.factor_type,
+                    value: entry.curve.normalize(factor.value, entry.min_value, entry.max_value),
+                    weight: entry.weight,
+                });
+            }
+
+            Ok((effective, scorecard.version))
+        }
+
+        /// Recalcula o score após a mudança de um fator derivado. Sem score
+        /// calculado, apenas registra o fator para o próximo cálculo.
+        fn recalculate_with_derived_factor(user: &T::AccountId, factor: ScoreFactor) {
+            let Some(score_data) = CreditScores::<T>::get(user) else {
+                ScoreFactors::<T>::insert(user, factor.factor_type, FactorRecord {
+                    value: factor.value,
+                    source: FactorSource::System,
+                    recorded_at: frame_system::Pallet::<T>::block_number(),
+                });
+                return;
+            };
+
+            let (factors, source) = Self::stored_raw_inputs(user, &score_data);
+
+            // Falhas de recálculo não devem reverter a operação que o disparou
+            if let Ok((Some(old_score), new_score)) = Self::do_store_score(
                 user,
-                old_score: old_score_data.score,
-                new_score,
-                reason: ScoreUpdateReason::UserUpdate,
+                factors,
+                source,
+                ScoreUpdateReason::SystemUpdate,
+            ) {
+                Self::deposit_event(Event::ScoreUpdated {
+                    user: user.clone(),
+                    old_score,
+                    new_score,
+                    reason: ScoreUpdateReason::SystemUpdate,
+                });
+                Self::deposit_score_reasons(user);
+            }
+        }
+
+        /// Fatores brutos mais recentes de um score, para recálculo pelo sistema,
+        /// e a fonte do recálculo. `add_score_factor` pode ter sobrescrito fatores
+        /// atestados; nesse caso o score recalculado passa a ser autodeclarado.
+        fn stored_raw_inputs(
+            user: &T::AccountId,
+            score_data: &CreditScoreDataOf<T>,
+        ) -> (Vec<ScoreFactor>, FactorSource<T::AccountId>) {
+            let mut source = score_data.source.clone();
+
+            let factors = score_data
+                .factors
+                .iter()
+                .map(|factor| {
+                    let record = ScoreFactors::<T>::get(user, factor.factor_type);
+                    if record.as_ref().is_some_and(|record| record.source == FactorSource::SelfReported) {
+                        source = FactorSource::SelfReported;
+                    }
+
+                    ScoreFactor {
+                        factor_type: factor.factor_type,
+                        value: record.map_or(factor.value, |record| record.value),
+                        weight: factor.weight,
+                    }
+                })
+                .collect();
+
+            (factors, source)
+        }
+
+        /// Acrescenta uma mudança ao histórico da conta, descartando a mais
+        /// antiga quando o limite `MaxHistoryEntries` é atingido
+        fn append_history(
+            user: &T::AccountId,
+            build: impl FnOnce(u64) -> ScoreChangeOf<T>,
+        ) {
+            HistoryIndex::<T>::mutate(user, |cursor| {
+                let sequence = cursor.next;
+                ScoreHistory::<T>::insert(user, sequence, build(sequence));
+                cursor.next = sequence.saturating_add(1);
+
+                let max_entries = u64::from(T::MaxHistoryEntries::get());
+                while cursor.next.saturating_sub(cursor.oldest) > max_entries {
+                    ScoreHistory::<T>::remove(user, cursor.oldest);
+                    cursor.oldest = cursor.oldest.saturating_add(1);
+                }
             });
+        }
 
-            Ok(())
+        /// Página do histórico de uma conta, da mudança mais antiga para a mais
+        /// recente. Começa em `start` (ou na entrada mais antiga retida) e
+        /// retorna no máximo `limit` entradas, junto com a sequência da próxima
+        /// página, se houver.
+        pub fn score_history_page(
+            who: &T::AccountId,
+            start: Option<u64>,
+            limit: u32,
+        ) -> (Vec<ScoreChangeOf<T>>, Option<u64>) {
+            let cursor = HistoryIndex::<T>::get(who);
+            let first = start.unwrap_or(cursor.oldest).max(cursor.oldest);
+            let limit = u64::from(limit.min(T::MaxHistoryEntries::get()));
+            let end = first.saturating_add(limit).min(cursor.next);
+
+            let changes = (first..end)
+                .filter_map(|sequence| ScoreHistory::<T>::get(who, sequence))
+                .collect();
+            let next_page = if end < cursor.next { Some(end) } else { None };
+
+            (changes, next_page)
         }
 
-        /// Verifica um score (apenas para usuários autorizados)
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::verify_score())]
-        pub fn verify_score(
-            origin: OriginFor<T>,
-            target_user: T::AccountId,
-        ) -> DispatchResult {
-            let verifier = ensure_signed(origin)?;
-            
-            // Verifica se o usuário tem score
-            let mut score_data = CreditScores::<T>::get(&target_user)
-                .ok_or(Error::<T>::ScoreNotFound)?;
+        /// Fatores brutos registrados para uma conta, com suas fontes
+        pub fn factor_records(
+            who: &T::AccountId,
+        ) -> Vec<(ScoreFactorType, FactorRecordOf<T>)> {
+            ScoreFactors::<T>::iter_prefix(who).collect()
+        }
 
-            // Gera hash de verificação
-            let verification_hash = Self::generate_verification_hash(&target_user, &score_data.score, &verifier);
-            
-            // Marca como verificado
-            score_data.is_verified = true;
-            score_data.verification_count += 1;
-            
-            CreditScores::<T>::insert(&target_user, score_data);
+        /// Decompõe o score armazenado nas contribuições de cada fator.
+        /// Retorna `None` se o score foi produzido por um modelo diferente do
+        /// atual, pois a decomposição não seria fiel ao cálculo original.
+        pub fn explain_score(who: &T::AccountId) -> Option<ScoreExplanation> {
+            let score_data = CreditScores::<T>::get(who)?;
+            if score_data.model != T::ScoringModel::info() {
+                return None;
+            }
 
-            Self::deposit_event(Event::ScoreVerified {
-                user: target_user,
-                verifier,
+            let points = T::ScoringModel::contributions(&score_data.factors)?;
+            let contributions = score_data
+                .factors
+                .iter()
+                .zip(points)
+                .map(|(factor, points)| FactorContribution {
+                    factor_type: factor.factor_type,
+                    value: factor.value,
+                    weight: factor.weight,
+                    points,
+                })
+                .collect();
+
+            Some(ScoreExplanation {
                 score: score_data.score,
-                verification_hash,
-            });
+                model: score_data.model,
+                scorecard_version: score_data.scorecard_version,
+                contributions,
+            })
+        }
 
-            Ok(())
+        /// Motivos, em ordem de impacto, que reduziram o score atual de uma conta
+        pub fn score_reasons(who: &T::AccountId) -> Vec<ScoreReason> {
+            CreditScores::<T>::get(who)
+                .map(|score_data| Self::reasons_for(&score_data))
+                .unwrap_or_default()
         }
 
-        /// Adiciona um fator de score específico
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::add_score_factor())]
-        pub fn add_score_factor(
-            origin: OriginFor<T>,
-            factor_type: ScoreFactorType,
-            value: u32,
-            weight: u32,
-        ) -> DispatchResult {
-            let user = ensure_signed(origin)?;
-            
-            // Verifica se o valor do fator é válido
-            ensure!(value > 0, Error::<T>::InvalidScoreFactor);
-            ensure!(weight > 0 && weight <= 100, Error::<T>::InvalidFactorWeight);
+        /// Gera os códigos de motivo de um score a partir de seus fatores.
+        ///
+        /// O impacto de cada fator é a diferença entre o score que seria obtido
+        /// com o fator no valor máximo (100) e o score atual. Fatores sem
+        /// impacto são omitidos e a lista é limitada a `MaxReasonCodes`.
+        pub fn reasons_for(score_data: &CreditScoreDataOf<T>) -> Vec<ScoreReason> {
+            // O impacto só é fiel se calculado pelo mesmo modelo que produziu o score
+            if score_data.model != T::ScoringModel::info() {
+                return Vec::new();
+            }
 
-            // Armazena o fator
-            ScoreFactors::<T>::insert(&user, factor_type, value);
+            let mut reasons: Vec<ScoreReason> = score_data
+                .factors
+                .iter()
+                .enumerate()
+                .filter_map(|(index, factor)| {
+                    let mut improved = score_data.factors.to_vec();
+                    improved[index].value = 100;
 
-            Self::deposit_event(Event::ScoreFactorAdded {
-                user,
-                factor_type,
-                value,
-                weight,
-            });
+                    let best_score = T::ScoringModel::compute(&improved)?.min(1000);
+                    let points_lost = best_score.saturating_sub(score_data.score);
 
-            Ok(())
+                    (points_lost > 0).then(|| ScoreReason {
+                        code: ReasonCode::from(&factor.factor_type),
+                        factor_type: factor.factor_type,
+                        points_lost,
+                    })
+                })
+                .collect();
+
+            reasons.sort_by_key(|reason| sp_std::cmp::Reverse(reason.points_lost));
+            reasons.truncate(T::MaxReasonCodes::get() as usize);
+            reasons
+        }
+
+        /// Emite os códigos de motivo do score recém-armazenado
+        fn deposit_score_reasons(user: &T::AccountId) {
+            let reasons = Self::score_reasons(user);
+            if !reasons.is_empty() {
+                Self::deposit_event(Event::ScoreReasonsGenerated {
+                    user: user.clone(),
+                    reasons,
+                });
+            }
+        }
+
+        /// Bloco em que um score calculado em `calculated_at` é agendado como
+        /// desatualizado. Um período nulo agenda para o bloco seguinte, já que a
+        /// agenda do bloco corrente já foi drenada.
+        pub(crate) fn stale_at(calculated_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            calculated_at.saturating_add(T::StalenessPeriod::get().max(One::one()))
+        }
+
+        /// Score efetivo de um usuário, com decaimento aplicado sob demanda.
+        /// Nenhuma conta é percorrida: o decaimento é calculado na consulta.
+        pub fn effective_score(who: &T::AccountId) -> Option<EffectiveScore> {
+            let score_data = CreditScores::<T>::get(who)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let age = now.saturating_sub(score_data.calculated_at);
+            let staleness_period = T::StalenessPeriod::get();
+            let is_stale = score_data.is_stale || age >= staleness_period;
+
+            let decay_period = T::DecayPeriod::get();
+            let decay_periods: u32 = if is_stale && !decay_period.is_zero() {
+                (age.saturating_sub(staleness_period) / decay_period).saturated_into()
+            } else {
+                0
+            };
+
+            Some(EffectiveScore {
+                score: Self::apply_decay(score_data.score, decay_periods),
+                raw_score: score_data.score,
+                is_stale,
+                decay_periods,
+            })
+        }
+
+        /// Aproxima o score do valor neutro em `periods` etapas de decaimento
+        fn apply_decay(score: u32, periods: u32) -> u32 {
+            let rate = T::DecayRate::get();
+            if periods == 0 || rate.is_zero() {
+                return score;
+            }
+
+            let retained = (Permill::one() - rate).saturating_pow(periods as usize);
+            let neutral = T::NeutralScore::get();
+
+            if score >= neutral {
+                neutral.saturating_add(retained.mul_floor(score - neutral))
+            } else {
+                neutral.saturating_sub(retained.mul_floor(neutral - score))
+            }
+        }
+
+        /// Marca como desatualizados os scores agendados até este bloco, no máximo
+        /// `MaxStaleMarksPerBlock` por bloco
+        fn mark_stale_scores(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<StalenessSchedule<T>, StalenessCursor<T>, _, _>(
+                now,
+                T::MaxStaleMarksPerBlock::get(),
+                db_weight,
+                |user: T::AccountId| {
+                    let mut weight = db_weight.reads_writes(2, 1);
+
+                    CreditScores::<T>::mutate(&user, |maybe_data| {
+                        if let Some(score_data) = maybe_data {
+                            if !score_data.is_stale {
+                                score_data.is_stale = true;
+                                weight = weight.saturating_add(db_weight.writes(1));
+
+                                Self::deposit_event(Event::ScoreBecameStale {
+                                    user: user.clone(),
+                                    calculated_at: score_data.calculated_at,
+                                });
+                            }
+                        }
+                    });
+
+                    weight
+                },
+            )
+        }
+
+        /// Ativa o scorecard pendente quando o bloco de ativação é atingido
+        fn activate_pending_scorecard(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            match PendingScorecard::<T>::get() {
+                Some((activate_at, scorecard)) if activate_at <= now => {
+                    let version = scorecard.version;
+                    ActiveScorecard::<T>::put(scorecard);
+                    PendingScorecard::<T>::kill();
+
+                    Self::deposit_event(Event::ScorecardActivated { version });
+
+                    db_weight.reads_writes(1, 2)
+                },
+                _ => db_weight.reads(1),
+            }
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         /// Calcula score baseado nos fatores fornecidos
         fn calculate_score_from_factors(factors: &[ScoreFactor]) -> Result<u32, Error<T>> {
             if factors.is_empty() {
                 return Err(Error::<T>::InvalidScoreFactor);
             }
 
-            let mut total_weighted_score = 0u32;
-            let mut total_weight = 0u32;
-
             for factor in factors {
                 // Valida o fator
                 if factor.value == 0 || factor.weight == 0 || factor.weight > 100 {
                     return Err(Error::<T>::InvalidScoreFactor);
                 }
-
-                // Aplica peso ao valor
-                let weighted_value = factor.value * factor.weight;
-                total_weighted_score += weighted_value;
-                total_weight += factor.weight;
-            }
-
-            if total_weight == 0 {
-                return Err(Error::<T>::InvalidScoreFactor);
             }
 
-            // Calcula score final (0-1000)
-            let final_score = (total_weighted_score * 1000) / (total_weight * 100);
+            // Calcula score final (0-1000) com o modelo configurado
+            let final_score = T::ScoringModel::compute(factors)
+                .ok_or(Error::<T>::ScoreCalculationFailed)?;
             
             Ok(final_score.min(1000))
         }
@@ -415,7 +2317,7 @@ pub mod pallet {
                 data.extend_from_slice(&factor.weight.encode());
             }
 
-            BlakeTwo256::hash(&data)
+            T::Hashing::hash(&data)
         }
 
         /// Gera hash de verificação
@@ -430,24 +2332,256 @@ pub mod pallet {
             data.extend_from_slice(&verifier.encode());
             data.extend_from_slice(&frame_system::Pallet::<T>::block_number().encode());
 
-            BlakeTwo256::hash(&data)
+            T::Hashing::hash(&data)
+        }
+    }
+
+    /// Recalcula o score quando o histórico de pagamentos do usuário muda
+    impl<T: Config> OnPaymentStateChange<T::AccountId> for Pallet<T> {
+        fn on_payment_state_change(payer: &T::AccountId) {
+            if let Some(factor) = Self::derived_payment_history_factor(payer) {
+                Self::recalculate_with_derived_factor(payer, factor);
+            }
         }
     }
 
     /// Dados de um score de crédito
-    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-    pub struct CreditScoreData<AccountId, BlockNumber> {
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxFactors))]
+    #[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen, Hash: MaxEncodedLen))]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(bound(
+        serialize = "AccountId: Serialize, BlockNumber: Serialize, Hash: Serialize",
+        deserialize = "AccountId: Deserialize<'de>, BlockNumber: Deserialize<'de>, Hash: Deserialize<'de>"
+    )))]
+    pub struct CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors: Get<u32>> {
         pub user: AccountId,
         pub score: u32,
-        pub factors: Vec<ScoreFactor>,
+        pub factors: BoundedVec<ScoreFactor, MaxFactors>,
         pub calculated_at: BlockNumber,
-        pub score_hash: <Self as frame_support::traits::Get<()>>::Hash,
+        pub score_hash: Hash,
+        /// Há ao menos uma verificação de um verificador credenciado
         pub is_verified: bool,
         pub verification_count: u32,
+        pub source: FactorSource<AccountId>,
+        pub model: ScoringModelInfo,
+        pub scorecard_version: u32,
+        pub is_stale: bool,
+        /// Faixa do score segundo as faixas vigentes no cálculo
+        pub band: Option<BandLabel>,
     }
 
-    /// Fator que influencia o score
+    impl<AccountId, BlockNumber, Hash, MaxFactors: Get<u32>> CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors> {
+        /// Indica se os fatores deste score foram fornecidos por fonte atestadora
+        /// ou corrigidos por verificador em contestação
+        pub fn is_attested(&self) -> bool {
+            matches!(self.source, FactorSource::Attester(_) | FactorSource::DisputeReviewer(_))
+        }
+    }
+
+    /// Score com decaimento por idade aplicado
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct EffectiveScore {
+        /// Score após o decaimento
+        pub score: u32,
+        /// Score armazenado no último cálculo
+        pub raw_score: u32,
+        pub is_stale: bool,
+        /// Etapas de decaimento aplicadas
+        pub decay_periods: u32,
+    }
+
+    /// Contribuição de um fator para o score
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct FactorContribution {
+        pub factor_type: ScoreFactorType,
+        pub value: u32,
+        pub weight: u32,
+        /// Pontos do score atribuídos ao fator
+        pub points: u32,
+    }
+
+    /// Decomposição de um score nas contribuições de seus fatores
     #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ScoreExplanation {
+        pub score: u32,
+        pub model: ScoringModelInfo,
+        pub scorecard_version: u32,
+        pub contributions: Vec<FactorContribution>,
+    }
+
+    /// Finalidade para a qual o acesso ao score é concedido
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum ConsentPurpose {
+        CreditApplication,  // Análise de pedido de crédito
+        AccountReview,      // Revisão de conta existente
+        Insurance,          // Subscrição de seguro
+        Employment,         // Análise para emprego
+        Rental,             // Locação de imóvel
+    }
+
+    /// Consentimento de acesso ao score concedido a um credor
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Consent<BlockNumber> {
+        pub purpose: ConsentPurpose,
+        pub granted_at: BlockNumber,
+        /// Bloco a partir do qual o consentimento deixa de valer
+        pub expires_at: BlockNumber,
+    }
+
+    /// Tipo de produto de crédito associado a uma consulta
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum ProductType {
+        CreditCard,     // Cartão de crédito
+        PersonalLoan,   // Empréstimo pessoal
+        AutoLoan,       // Financiamento de veículo
+        Mortgage,       // Financiamento imobiliário
+        StudentLoan,    // Crédito estudantil
+        Other,          // Outros produtos
+    }
+
+    /// Consulta ao score registrada on-chain
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct Inquiry<AccountId, BlockNumber> {
+        pub lender: AccountId,
+        pub purpose: ConsentPurpose,
+        pub product: ProductType,
+        pub inquired_at: BlockNumber,
+        /// Consulta para pedido de crédito (hard inquiry)
+        pub is_hard: bool,
+        /// Repetição de hard inquiry do mesmo credor dentro do período de de-duplicação
+        pub is_duplicate: bool,
+    }
+
+    impl<AccountId, BlockNumber> Inquiry<AccountId, BlockNumber> {
+        /// Indica se a consulta é contada no fator NewCredit
+        pub fn counts_toward_new_credit(&self) -> bool {
+            self.is_hard && !self.is_duplicate
+        }
+    }
+
+    /// Rótulo de uma faixa de score (ex.: `b"A"`, `b"excelente"`)
+    pub type BandLabel = BoundedVec<u8, ConstU32<32>>;
+
+    /// Faixa de score definida pela governança
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ScoreBand {
+        pub label: BandLabel,
+        /// Menor score incluído na faixa; a faixa vai até o início da seguinte
+        pub min_score: u32,
+    }
+
+    /// Quem definiu o congelamento do score
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum FreezeAuthority {
+        Owner,          // Próprio titular
+        FraudResponse,  // Equipe de resposta a fraudes
+    }
+
+    /// Congelamento de score ativo
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ScoreFreeze<BlockNumber> {
+        pub authority: FreezeAuthority,
+        pub frozen_at: BlockNumber,
+        /// Fim da suspensão temporária em vigor, se houver
+        pub lifted_until: Option<BlockNumber>,
+    }
+
+    /// Situação de uma contestação de score
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ScoreDisputeStatus {
+        Open,         // Aguardando designação de revisor
+        UnderReview,  // Em análise pelo revisor
+        Accepted,     // Aceita, score corrigido
+        Rejected,     // Rejeitada, score mantido
+        Expired,      // Prazo encerrado sem decisão
+    }
+
+    /// Contestação de score aberta pelo titular
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ScoreDispute<AccountId, BlockNumber, Hash> {
+        pub owner: AccountId,
+        /// Hash das evidências apresentadas (armazenadas fora da cadeia)
+        pub evidence_hash: Hash,
+        /// Score vigente quando a contestação foi aberta
+        pub disputed_score: u32,
+        pub opened_at: BlockNumber,
+        /// Bloco limite para a decisão
+        pub deadline: BlockNumber,
+        pub reviewer: Option<AccountId>,
+        pub status: ScoreDisputeStatus,
+    }
+
+    impl<AccountId, BlockNumber, Hash> ScoreDispute<AccountId, BlockNumber, Hash> {
+        /// Indica se a contestação ainda aguarda decisão
+        pub fn is_open(&self) -> bool {
+            matches!(self.status, ScoreDisputeStatus::Open | ScoreDisputeStatus::UnderReview)
+        }
+    }
+
+    /// Verificador credenciado e suas estatísticas
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxMetadata))]
+    #[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+    pub struct VerifierInfo<BlockNumber, MaxMetadata: Get<u32>> {
+        pub metadata: BoundedVec<u8, MaxMetadata>,
+        pub registered_at: BlockNumber,
+        pub total_verifications: u32,
+        pub last_verification_at: Option<BlockNumber>,
+    }
+
+    /// Birô de crédito registrado
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxName))]
+    #[codec(mel_bound(BlockNumber: MaxEncodedLen))]
+    pub struct BureauInfo<BlockNumber, MaxName: Get<u32>> {
+        pub name: BoundedVec<u8, MaxName>,
+        pub registered_at: BlockNumber,
+    }
+
+    /// Origem de um fator de score
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum FactorSource<AccountId> {
+        /// Enviado pelo próprio usuário, sem atestação
+        SelfReported,
+        /// Atestado por um oráculo ou birô de crédito
+        Attester(AccountId),
+        /// Derivado pelo próprio sistema a partir de dados on-chain
+        System,
+        /// Corrigido pelo verificador que aceitou uma contestação do titular
+        DisputeReviewer(AccountId),
+    }
+
+    /// Fator armazenado com a fonte que o forneceu
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct FactorRecord<AccountId, BlockNumber> {
+        pub value: u32,
+        pub source: FactorSource<AccountId>,
+        pub recorded_at: BlockNumber,
+    }
+
+    /// Política para fatores autodeclarados
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum FactorAttestationMode {
+        /// Aceita fatores autodeclarados, sinalizando-os como não atestados
+        #[default]
+        FlagUnattested,
+        /// Aceita apenas fatores enviados por fontes atestadoras
+        AttestedOnly,
+    }
+
+    /// Fator que influencia o score
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub struct ScoreFactor {
         pub factor_type: ScoreFactorType,
         pub value: u32,
@@ -455,7 +2589,8 @@ pub mod pallet {
     }
 
     /// Tipos de fatores de score
-    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum ScoreFactorType {
         PaymentHistory,      // Histórico de pagamentos
         CreditUtilization,   // Utilização de crédito
@@ -467,51 +2602,85 @@ pub mod pallet {
         DebtToIncome,       // Relação dívida/renda
     }
 
-    /// Mudança no score
+    /// Código de motivo padronizado para comunicação de recusa de crédito
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub enum ReasonCode {
+        LatePayments,              // Atrasos ou falhas de pagamento
+        HighCreditUtilization,     // Utilização de crédito elevada
+        ShortCreditHistory,        // Histórico de crédito curto
+        LimitedCreditMix,          // Pouca diversidade de crédito
+        TooManyRecentInquiries,    // Muitas consultas ou contas recentes
+        UnstableIncome,            // Renda instável
+        LimitedEmploymentHistory,  // Histórico de emprego limitado
+        HighDebtToIncome,          // Relação dívida/renda elevada
+    }
+
+    impl From<&ScoreFactorType> for ReasonCode {
+        fn from(factor_type: &ScoreFactorType) -> Self {
+            match factor_type {
+                ScoreFactorType::PaymentHistory => ReasonCode::LatePayments,
+                ScoreFactorType::CreditUtilization => ReasonCode::HighCreditUtilization,
+                ScoreFactorType::CreditAge => ReasonCode::ShortCreditHistory,
+                ScoreFactorType::CreditMix => ReasonCode::LimitedCreditMix,
+                ScoreFactorType::NewCredit => ReasonCode::TooManyRecentInquiries,
+                ScoreFactorType::IncomeStability => ReasonCode::UnstableIncome,
+                ScoreFactorType::EmploymentHistory => ReasonCode::LimitedEmploymentHistory,
+                ScoreFactorType::DebtToIncome => ReasonCode::HighDebtToIncome,
+            }
+        }
+    }
+
+    /// Motivo que reduziu o score, com o impacto em pontos
     #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-    pub struct ScoreChange<AccountId, BlockNumber> {
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    pub struct ScoreReason {
+        pub code: ReasonCode,
+        pub factor_type: ScoreFactorType,
+        /// Pontos perdidos em relação ao fator no valor máximo
+        pub points_lost: u32,
+    }
+
+    /// Mudança no score
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxFactors))]
+    #[codec(mel_bound(AccountId: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "std", serde(bound(
+        serialize = "AccountId: Serialize, BlockNumber: Serialize",
+        deserialize = "AccountId: Deserialize<'de>, BlockNumber: Deserialize<'de>"
+    )))]
+    pub struct ScoreChange<AccountId, BlockNumber, MaxFactors: Get<u32>> {
         pub user: AccountId,
         pub old_score: Option<u32>,
         pub new_score: u32,
-        pub factors: Vec<ScoreFactor>,
+        pub factors: BoundedVec<ScoreFactor, MaxFactors>,
         pub block_number: BlockNumber,
         pub reason: ScoreUpdateReason,
+        pub model: ScoringModelInfo,
+        pub scorecard_version: u32,
+        /// Posição da mudança no histórico da conta
+        pub sequence: u64,
+    }
+
+    /// Cursor do histórico de uma conta: entradas retidas estão em `oldest..next`
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+    pub struct HistoryCursor {
+        /// Sequência da entrada mais antiga ainda armazenada
+        pub oldest: u64,
+        /// Sequência que será atribuída à próxima mudança
+        pub next: u64,
     }
 
     /// Razão da atualização do score
-    #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
     pub enum ScoreUpdateReason {
         InitialCalculation,
         UserUpdate,
         SystemUpdate,
         VerificationUpdate,
         ComplianceUpdate,
-    }
-
-    /// Trait para informações de peso das funções
-    pub trait WeightInfo {
-        fn calculate_score() -> Weight;
-        fn update_score() -> Weight;
-        fn verify_score() -> Weight;
-        fn add_score_factor() -> Weight;
-    }
-
-    /// Implementação padrão dos pesos
-    impl WeightInfo for () {
-        fn calculate_score() -> Weight {
-            Weight::from_parts(50_000, 0)
-        }
-        
-        fn update_score() -> Weight {
-            Weight::from_parts(30_000, 0)
-        }
-        
-        fn verify_score() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        
-        fn add_score_factor() -> Weight {
-            Weight::from_parts(5_000, 0)
-        }
+        AttestationUpdate,
     }
 }
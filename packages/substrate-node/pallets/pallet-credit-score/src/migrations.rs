@@ -0,0 +1,438 @@
+//! Migrações de storage do pallet de credit score: metadados do score (v1), agendas
+//! drenadas por cursores (v2), faixa do score (v3) e expiração de consultas (v4).
+//!
+//! Scores são acessados por aliases de `CreditScores` com o formato da versão
+//! migrada, para que as migrações anteriores continuem válidas quando o
+//! formato cresce.
+
+use crate::pallet::*;
+use crate::scoring::{ScoringModel, ScoringModelInfo, WeightedAverageModel};
+use codec::{Decode, Encode};
+use frame_support::{
+    pallet_prelude::*,
+    storage_alias,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{One, Saturating};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Versão 1: fontes de fatores, modelo e scorecard registrados com o score,
+/// fatores limitados por `MaxScoreFactors` e histórico indexado por sequência.
+pub mod v1 {
+    use super::*;
+
+    /// Formatos da versão 0
+    pub mod v0 {
+        use super::*;
+
+        #[derive(Encode, Decode)]
+        pub struct CreditScoreData<AccountId, BlockNumber, Hash> {
+            pub user: AccountId,
+            pub score: u32,
+            pub factors: Vec<ScoreFactor>,
+            pub calculated_at: BlockNumber,
+            pub score_hash: Hash,
+            pub is_verified: bool,
+            pub verification_count: u32,
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct ScoreChange<AccountId, BlockNumber> {
+            pub user: AccountId,
+            pub old_score: Option<u32>,
+            pub new_score: u32,
+            pub factors: Vec<ScoreFactor>,
+            pub block_number: BlockNumber,
+            pub reason: ScoreUpdateReason,
+        }
+
+        pub type ScoreChangeOf<T> =
+            ScoreChange<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+        /// Histórico da versão 0, indexado pelo bloco da mudança
+        #[storage_alias]
+        pub type ScoreHistory<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Blake2_128Concat,
+            BlockNumberFor<T>,
+            ScoreChangeOf<T>,
+        >;
+    }
+
+    /// Migra o storage da versão 0 para a versão 1
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads = 1u64;
+            let mut writes = 1u64;
+
+            // Scores: fatores limitados, score atribuído ao modelo da versão 0
+            // (média ponderada) e staleness reagendada
+            v3::v2::CreditScores::<T>::translate::<
+                v0::CreditScoreData<T::AccountId, BlockNumberFor<T>, T::Hash>,
+                _,
+            >(|_, old| {
+                reads += 1;
+                writes += 2;
+
+                let stale_at = Pallet::<T>::stale_at(old.calculated_at);
+                let is_stale = stale_at <= now;
+                if !is_stale {
+                    StalenessSchedule::<T>::insert(stale_at, &old.user, ());
+                }
+
+                Some(v3::v2::CreditScoreData {
+                    user: old.user,
+                    score: old.score,
+                    factors: BoundedVec::truncate_from(old.factors),
+                    calculated_at: old.calculated_at,
+                    score_hash: old.score_hash,
+                    is_verified: old.is_verified,
+                    verification_count: old.verification_count,
+                    source: FactorSource::SelfReported,
+                    model: WeightedAverageModel::info(),
+                    scorecard_version: 0,
+                    is_stale,
+                })
+            });
+
+            // Fatores: valor bruto passa a registrar a fonte (autodeclarada na versão 0)
+            ScoreFactors::<T>::translate::<u32, _>(|_, _, value| {
+                reads += 1;
+                writes += 1;
+
+                Some(FactorRecord {
+                    value,
+                    source: FactorSource::SelfReported,
+                    recorded_at: now,
+                })
+            });
+
+            // Histórico: reindexado por sequência, em ordem de bloco, mantendo
+            // apenas as `MaxHistoryEntries` mudanças mais recentes de cada conta.
+            // As entradas antigas são removidas antes de gravar as novas, pois
+            // ambas usam o mesmo prefixo de storage.
+            let mut history: BTreeMap<T::AccountId, Vec<v0::ScoreChangeOf<T>>> = BTreeMap::new();
+            for (who, _, change) in v0::ScoreHistory::<T>::drain() {
+                reads += 1;
+                writes += 1;
+                history.entry(who).or_default().push(change);
+            }
+
+            let max_entries = T::MaxHistoryEntries::get() as usize;
+            for (who, mut changes) in history {
+                changes.sort_by_key(|change| change.block_number);
+
+                let total = changes.len() as u64;
+                let skipped = changes.len().saturating_sub(max_entries);
+
+                for (sequence, change) in (skipped as u64..).zip(changes.into_iter().skip(skipped)) {
+                    writes += 1;
+                    ScoreHistory::<T>::insert(&who, sequence, ScoreChange {
+                        user: change.user,
+                        old_score: change.old_score,
+                        new_score: change.new_score,
+                        factors: BoundedVec::truncate_from(change.factors),
+                        block_number: change.block_number,
+                        reason: change.reason,
+                        model: WeightedAverageModel::info(),
+                        scorecard_version: 0,
+                        sequence,
+                    });
+                }
+
+                writes += 1;
+                HistoryIndex::<T>::insert(&who, HistoryCursor {
+                    oldest: skipped as u64,
+                    next: total,
+                });
+            }
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let scores = CreditScores::<T>::iter_keys().count() as u64;
+            let factors = ScoreFactors::<T>::iter_keys().count() as u64;
+            Ok((scores, factors).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (scores, factors): (u64, u64) =
+                Decode::decode(&mut &state[..]).map_err(|_| "estado pré-migração inválido")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "versão de storage não atualizada"
+            );
+            ensure!(
+                v3::v2::CreditScores::<T>::iter().count() as u64 == scores,
+                "scores perdidos na migração"
+            );
+            ensure!(
+                ScoreFactors::<T>::iter().count() as u64 == factors,
+                "fatores perdidos na migração"
+            );
+
+            // Cada cursor deve cobrir exatamente as entradas retidas
+            for (who, cursor) in HistoryIndex::<T>::iter() {
+                let retained = ScoreHistory::<T>::iter_prefix(&who).count() as u64;
+                ensure!(
+                    retained == cursor.next.saturating_sub(cursor.oldest),
+                    "cursor de histórico inconsistente"
+                );
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Versão 2: agendas de staleness, prazos de contestação e fim de suspensões de
+/// congelamento drenadas a partir de cursores, com limite por bloco.
+pub mod v2 {
+    use super::*;
+
+    /// Inicia os cursores no bloco da atualização. Até a versão 1 as agendas eram
+    /// drenadas a cada bloco, então não há entradas anteriores pendentes.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            StalenessCursor::<T>::put(now);
+            DisputeDeadlineCursor::<T>::put(now);
+            FreezeLiftCursor::<T>::put(now);
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            db_weight.reads_writes(1, 4)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(Pallet::<T>::on_chain_storage_version().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let previous: StorageVersion =
+                Decode::decode(&mut &state[..]).map_err(|_| "estado pré-migração inválido")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 2,
+                "versão de storage não atualizada"
+            );
+
+            // Migração ignorada: os cursores já podem ter avançado desde a versão 2
+            if previous != 1 {
+                return Ok(());
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                StalenessCursor::<T>::get() == now
+                    && DisputeDeadlineCursor::<T>::get() == now
+                    && FreezeLiftCursor::<T>::get() == now,
+                "cursores não iniciados no bloco da atualização"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Versão 3: faixa do score registrada com o score.
+pub mod v3 {
+    use super::*;
+
+    /// Formatos das versões 1 e 2
+    pub mod v2 {
+        use super::*;
+
+        #[derive(Encode, Decode)]
+        pub struct CreditScoreData<AccountId, BlockNumber, Hash, MaxFactors: Get<u32>> {
+            pub user: AccountId,
+            pub score: u32,
+            pub factors: BoundedVec<ScoreFactor, MaxFactors>,
+            pub calculated_at: BlockNumber,
+            pub score_hash: Hash,
+            pub is_verified: bool,
+            pub verification_count: u32,
+            pub source: FactorSource<AccountId>,
+            pub model: ScoringModelInfo,
+            pub scorecard_version: u32,
+            pub is_stale: bool,
+        }
+
+        pub type CreditScoreDataOf<T> = CreditScoreData<
+            <T as frame_system::Config>::AccountId,
+            BlockNumberFor<T>,
+            <T as frame_system::Config>::Hash,
+            <T as Config>::MaxScoreFactors,
+        >;
+
+        /// Scores das versões 1 e 2, sem a faixa
+        #[storage_alias]
+        pub type CreditScores<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            CreditScoreDataOf<T>,
+        >;
+    }
+
+    /// Classifica cada score existente com as faixas vigentes na atualização
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return db_weight.reads(1);
+            }
+
+            let mut reads = 2u64;
+            let mut writes = 1u64;
+
+            CreditScores::<T>::translate::<v2::CreditScoreDataOf<T>, _>(|_, old| {
+                reads += 1;
+                writes += 1;
+
+                Some(CreditScoreData {
+                    band: Pallet::<T>::band_for(old.score).map(|band| band.label),
+                    user: old.user,
+                    score: old.score,
+                    factors: old.factors,
+                    calculated_at: old.calculated_at,
+                    score_hash: old.score_hash,
+                    is_verified: old.is_verified,
+                    verification_count: old.verification_count,
+                    source: old.source,
+                    model: old.model,
+                    scorecard_version: old.scorecard_version,
+                    is_stale: old.is_stale,
+                })
+            });
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((CreditScores::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let scores: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "estado pré-migração inválido")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 3,
+                "versão de storage não atualizada"
+            );
+
+            let mut migrated = 0u64;
+            for score_data in CreditScores::<T>::iter_values() {
+                migrated += 1;
+                ensure!(
+                    score_data.band == Pallet::<T>::band_for(score_data.score).map(|band| band.label),
+                    "faixa divergente das faixas vigentes"
+                );
+            }
+            ensure!(migrated == scores, "scores perdidos na migração");
+
+            Ok(())
+        }
+    }
+}
+
+/// Versão 4: consultas que saem de `InquiryWindow` agendam o recálculo do score.
+pub mod v4 {
+    use super::*;
+
+    /// Inicia o cursor no bloco da atualização e agenda a saída da janela das
+    /// consultas que ainda contam para o fator NewCredit
+    pub struct MigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 3 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads = 1u64;
+            let mut writes = 2u64;
+
+            for (who, inquiries) in Inquiries::<T>::iter() {
+                reads += 1;
+                for inquiry in inquiries.iter().filter(|inquiry| inquiry.counts_toward_new_credit()) {
+                    let expires_at = inquiry.inquired_at.saturating_add(T::InquiryWindow::get().max(One::one()));
+                    if expires_at > now {
+                        InquiryExpirySchedule::<T>::insert(expires_at, &who, ());
+                        writes += 1;
+                    }
+                }
+            }
+
+            InquiryExpiryCursor::<T>::put(now);
+            StorageVersion::new(4).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(Pallet::<T>::on_chain_storage_version().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let previous: StorageVersion =
+                Decode::decode(&mut &state[..]).map_err(|_| "estado pré-migração inválido")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 4,
+                "versão de storage não atualizada"
+            );
+
+            // Migração ignorada: o cursor já pode ter avançado desde a versão 4
+            if previous != 3 {
+                return Ok(());
+            }
+
+            ensure!(
+                InquiryExpiryCursor::<T>::get() == frame_system::Pallet::<T>::block_number(),
+                "cursor não iniciado no bloco da atualização"
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Runtime de teste para o pallet de credit score
+
+use crate as pallet_credit_score;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Contains, Everything, Randomness},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        CreditScore: pallet_credit_score,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+    pub const TestDecayRate: Permill = Permill::from_percent(10);
+    pub storage StalenessPeriod: u64 = 100;
+    pub storage DisputeReviewPeriod: u64 = 20;
+    pub storage MaxStaleMarksPerBlock: u32 = 200;
+}
+
+impl pallet_credit_score::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MinScore = ConstU32<0>;
+    type MaxScore = ConstU32<1000>;
+    type MaxScoreFactors = ConstU32<10>;
+    type Randomness = TestRandomness;
+    type VerifierOrigin = EnsureRoot<u64>;
+    type MaxVerifierMetadataLength = ConstU32<64>;
+    type MaxScoreVerifications = ConstU32<3>;
+    type GovernanceOrigin = EnsureRoot<u64>;
+    type OracleAttesters = OracleAccounts;
+    type MaxBureauNameLength = ConstU32<32>;
+    type PaymentHistory = MockPaymentHistory;
+    type PaymentHistoryWeight = ConstU32<35>;
+    type ScoringModel = pallet_credit_score::WeightedAverageModel;
+    type StalenessPeriod = StalenessPeriod;
+    type DecayPeriod = ConstU64<10>;
+    type DecayRate = TestDecayRate;
+    type NeutralScore = ConstU32<500>;
+    type MaxHistoryEntries = ConstU32<3>;
+    type MaxReasonCodes = ConstU32<4>;
+    type MaxConsentDuration = ConstU64<1000>;
+    type MaxInquiries = ConstU32<5>;
+    type InquiryWindow = ConstU64<100>;
+    type InquiryDedupPeriod = ConstU64<10>;
+    type NewCreditWeight = ConstU32<10>;
+    type DisputeReviewPeriod = DisputeReviewPeriod;
+    type FraudResponseOrigin = EnsureRoot<u64>;
+    type MaxFreezeLiftDuration = ConstU64<50>;
+    type MaxScoreBands = ConstU32<5>;
+    type MaxStaleMarksPerBlock = MaxStaleMarksPerBlock;
+    type MaxDisputeExpiriesPerBlock = ConstU32<200>;
+    type MaxFreezeReinstatesPerBlock = ConstU32<200>;
+    type MaxInquiryExpiriesPerBlock = ConstU32<200>;
+}
+
+/// Aleatoriedade determinística derivada apenas do assunto
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        (BlakeTwo256::hash(subject), System::block_number())
+    }
+}
+
+// Conta 1 possui 9 pagamentos concluídos e 1 com falha
+pub struct MockPaymentHistory;
+impl credchain_primitives::PaymentHistoryProvider<u64> for MockPaymentHistory {
+    fn payment_summary(who: &u64) -> credchain_primitives::PaymentSummary {
+        match who {
+            1 => credchain_primitives::PaymentSummary { completed: 9, failed: 1, disputed: 0 },
+            _ => Default::default(),
+        }
+    }
+}
+
+// Conta 100 atua como oráculo ativo nos testes
+pub struct OracleAccounts;
+impl Contains<u64> for OracleAccounts {
+    fn contains(who: &u64) -> bool {
+        *who == 100
+    }
+}
+
+// Helper para criar extrinsics
+pub fn new_test_ext() -> TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    TestExternalities::new(storage)
+}
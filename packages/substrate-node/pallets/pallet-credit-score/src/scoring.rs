@@ -0,0 +1,203 @@
+//! # Modelos de Scoring
+//!
+//! Define o trait [`ScoringModel`], usado pelo pallet para transformar um
+//! conjunto de fatores em um score de 0 a 1000. Cada score armazenado registra
+//! o identificador e a versão do modelo que o produziu, de forma que scores
+//! históricos continuem explicáveis após uma troca de modelo.
+//!
+//! Também define o [`Scorecard`] mantido pela governança, que fixa peso,
+//! faixa de valores e curva de normalização de cada tipo de fator.
+
+use crate::pallet::{ScoreFactor, ScoreFactorType};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::{ConstU32, Get}, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Identificação do modelo que produziu um score
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ScoringModelInfo {
+    /// Identificador estável do modelo (ex.: `b"wavg\0\0\0\0"`)
+    pub id: [u8; 8],
+    /// Versão do algoritmo dentro do mesmo modelo
+    pub version: u32,
+}
+
+/// Algoritmo de cálculo de score
+pub trait ScoringModel {
+    /// Identificador e versão do modelo
+    fn info() -> ScoringModelInfo;
+
+    /// Calcula o score (0-1000) a partir de fatores já validados pelo pallet
+    /// (valores e pesos de 1 a 100). Retorna `None` se o cálculo não for possível.
+    fn compute(factors: &[ScoreFactor]) -> Option<u32>;
+
+    /// Pontos do score atribuídos a cada fator, na mesma ordem de `factors`.
+    /// Usado para explicar scores; a soma pode diferir do score por arredondamento.
+    fn contributions(factors: &[ScoreFactor]) -> Option<Vec<u32>>;
+}
+
+/// Média ponderada dos fatores, escalada para 0-1000 (modelo padrão)
+pub struct WeightedAverageModel;
+
+impl ScoringModel for WeightedAverageModel {
+    fn info() -> ScoringModelInfo {
+        ScoringModelInfo {
+            id: *b"wavg\0\0\0\0",
+            version: 1,
+        }
+    }
+
+    fn compute(factors: &[ScoreFactor]) -> Option<u32> {
+        let mut total_weighted_score = 0u32;
+        let mut total_weight = 0u32;
+
+        for factor in factors {
+            // Aplica peso ao valor
+            let weighted_value = factor.value.checked_mul(factor.weight)?;
+            total_weighted_score = total_weighted_score.checked_add(weighted_value)?;
+            total_weight = total_weight.checked_add(factor.weight)?;
+        }
+
+        if total_weight == 0 {
+            return None;
+        }
+
+        // Calcula score final (0-1000)
+        let final_score = total_weighted_score.checked_mul(1000)? / total_weight.checked_mul(100)?;
+
+        Some(final_score.min(1000))
+    }
+
+    fn contributions(factors: &[ScoreFactor]) -> Option<Vec<u32>> {
+        let total_weight = factors
+            .iter()
+            .try_fold(0u32, |total, factor| total.checked_add(factor.weight))?;
+
+        if total_weight == 0 {
+            return None;
+        }
+
+        factors
+            .iter()
+            .map(|factor| {
+                let weighted_value = factor.value.checked_mul(factor.weight)?;
+                Some(weighted_value.checked_mul(1000)? / total_weight.checked_mul(100)?)
+            })
+            .collect()
+    }
+}
+
+/// Pontos (valor bruto, valor normalizado) de uma curva por partes
+pub type CurvePoints = BoundedVec<(u32, u32), ConstU32<16>>;
+
+/// Curva que converte o valor bruto de um fator para a escala 1-100
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum NormalizationCurve {
+    /// Mapeia linearmente `[min, max]` para `[1, 100]`
+    Linear,
+    /// Mapeia linearmente `[min, max]` para `[100, 1]` (valor bruto maior é pior)
+    Inverse,
+    /// Interpolação linear entre pontos ordenados pelo valor bruto
+    Piecewise(CurvePoints),
+}
+
+impl NormalizationCurve {
+    /// Normaliza `value` (já contido em `[min, max]`) para a escala 1-100
+    pub fn normalize(&self, value: u32, min: u32, max: u32) -> u32 {
+        let span = max.saturating_sub(min).max(1) as u64;
+        let offset = value.saturating_sub(min) as u64;
+        let linear = (1 + offset * 99 / span) as u32;
+
+        let normalized = match self {
+            NormalizationCurve::Linear => linear,
+            NormalizationCurve::Inverse => 101 - linear,
+            NormalizationCurve::Piecewise(points) => Self::interpolate(points, value),
+        };
+
+        normalized.clamp(1, 100)
+    }
+
+    /// Indica se a curva é bem formada
+    pub fn is_valid(&self) -> bool {
+        match self {
+            NormalizationCurve::Piecewise(points) => {
+                !points.is_empty()
+                    && points.iter().all(|(_, y)| (1..=100).contains(y))
+                    && points.windows(2).all(|pair| pair[0].0 < pair[1].0)
+            },
+            _ => true,
+        }
+    }
+
+    fn interpolate(points: &[(u32, u32)], value: u32) -> u32 {
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return 1;
+        };
+        if value <= first.0 {
+            return first.1;
+        }
+        if value >= last.0 {
+            return last.1;
+        }
+
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            if value >= x0 && value <= x1 {
+                let dx = (x1 - x0) as i64;
+                let dy = y1 as i64 - y0 as i64;
+                return (y0 as i64 + (value - x0) as i64 * dy / dx) as u32;
+            }
+        }
+
+        last.1
+    }
+}
+
+/// Regra do scorecard para um tipo de fator
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScorecardEntry {
+    pub factor_type: ScoreFactorType,
+    /// Peso fixo do fator (1-100), substitui o peso informado por quem envia
+    pub weight: u32,
+    /// Menor valor bruto aceito
+    pub min_value: u32,
+    /// Maior valor bruto aceito
+    pub max_value: u32,
+    pub curve: NormalizationCurve,
+}
+
+impl ScorecardEntry {
+    /// Indica se a regra é bem formada
+    pub fn is_valid(&self) -> bool {
+        (1..=100).contains(&self.weight)
+            && self.min_value < self.max_value
+            && self.curve.is_valid()
+    }
+
+    /// Indica se todos os valores brutos em `[min, max]` estão dentro da faixa da regra
+    pub fn accepts(&self, min: u32, max: u32) -> bool {
+        self.min_value <= min && max <= self.max_value
+    }
+}
+
+/// Scorecard versionado definido pela governança
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxEntries))]
+#[codec(mel_bound())]
+pub struct Scorecard<MaxEntries: Get<u32>> {
+    pub version: u32,
+    pub entries: BoundedVec<ScorecardEntry, MaxEntries>,
+}
+
+impl<MaxEntries: Get<u32>> Scorecard<MaxEntries> {
+    /// Regra aplicável a um tipo de fator
+    pub fn entry(&self, factor_type: &ScoreFactorType) -> Option<&ScorecardEntry> {
+        self.entries.iter().find(|entry| &entry.factor_type == factor_type)
+    }
+}
@@ -0,0 +1,1528 @@
+/*!
+ * Unit Tests for Credit Score Pallet
+ * 
+ * Testes unitários para o pallet de credit score
+ */
+
+use crate::{mock::*, *};
+use codec::MaxEncodedLen;
+use frame_support::{
+    assert_ok, assert_noop,
+    traits::OnInitialize,
+    BoundedVec,
+};
+
+// Helper para criar `count` fatores de utilização de crédito com o mesmo valor
+fn uniform_factors(count: usize, value: u32) -> Vec<ScoreFactor> {
+    vec![ScoreFactor { factor_type: ScoreFactorType::CreditUtilization, value, weight: 10 }; count]
+}
+
+#[test]
+fn test_credit_score_initialization() {
+    new_test_ext().execute_with(|| {
+        // Verificar se o pallet foi inicializado corretamente
+        assert!(CreditScore::credit_score(1).is_none());
+        assert!(CreditScore::score_history(1, 0).is_none());
+        assert!(CreditScore::active_scorecard().is_none());
+    });
+}
+
+#[test]
+fn test_set_credit_score_success() {
+    new_test_ext().execute_with(|| {
+        let account_id = 2;
+        let factors = sample_factors();
+
+        // Definir score de crédito
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(account_id), factors.clone()));
+
+        // Verificar se foi armazenado corretamente
+        let stored_score = CreditScore::credit_score(account_id);
+        assert!(stored_score.is_some());
+
+        let score_data = stored_score.unwrap();
+        assert_eq!(score_data.score, 707);
+        assert_eq!(score_data.factors.to_vec(), factors);
+        assert_eq!(score_data.calculated_at, 0); // Block number
+
+        // Histórico registra o cálculo inicial
+        let change = CreditScore::score_history(account_id, 0).unwrap();
+        assert_eq!(change.old_score, None);
+        assert_eq!(change.new_score, 707);
+        assert_eq!(change.reason, ScoreUpdateReason::InitialCalculation);
+    });
+}
+
+#[test]
+fn test_set_credit_score_unauthorized() {
+    new_test_ext().execute_with(|| {
+        // Tentar definir score sem conta de origem
+        assert_noop!(
+            CreditScore::calculate_score(RuntimeOrigin::root(), sample_factors()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_set_credit_score_invalid_score() {
+    new_test_ext().execute_with(|| {
+        // Fator com valor zero
+        assert_noop!(
+            CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(1, 0)),
+            Error::<Test>::InvalidScoreFactor
+        );
+
+        // Fator com peso acima de 100
+        assert_noop!(
+            CreditScore::calculate_score(
+                RuntimeOrigin::signed(2),
+                vec![ScoreFactor { factor_type: ScoreFactorType::CreditAge, value: 50, weight: 101 }]
+            ),
+            Error::<Test>::InvalidScoreFactor
+        );
+
+        // Sem fatores
+        assert_noop!(
+            CreditScore::calculate_score(RuntimeOrigin::signed(2), vec![]),
+            Error::<Test>::InvalidScoreFactor
+        );
+    });
+}
+
+#[test]
+fn test_set_credit_score_invalid_factors() {
+    new_test_ext().execute_with(|| {
+        // Fatores além de `MaxScoreFactors`
+        assert_noop!(
+            CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(11, 50)),
+            Error::<Test>::TooManyScoreFactors
+        );
+    });
+}
+
+#[test]
+fn test_update_credit_score() {
+    new_test_ext().execute_with(|| {
+        // Atualizar sem score existente
+        assert_noop!(
+            CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()),
+            Error::<Test>::ScoreNotFound
+        );
+
+        // Definir score inicial
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Atualizar score
+        let new_factors = uniform_factors(2, 80);
+        frame_system::Pallet::<Test>::set_block_number(2);
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), new_factors.clone()));
+
+        // Verificar se foi atualizado
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert_eq!(score_data.score, 800);
+        assert_eq!(score_data.factors.to_vec(), new_factors);
+
+        let change = CreditScore::score_history(2, 1).unwrap();
+        assert_eq!(change.old_score, Some(707));
+        assert_eq!(change.new_score, 800);
+        assert_eq!(change.reason, ScoreUpdateReason::UserUpdate);
+    });
+}
+
+#[test]
+fn test_get_credit_score_not_found() {
+    new_test_ext().execute_with(|| {
+        let account_id = 999; // Conta que não existe
+
+        // Verificar que não existe score
+        let stored_score = CreditScore::credit_score(account_id);
+        assert!(stored_score.is_none());
+    });
+}
+
+#[test]
+fn test_get_credit_score() {
+    new_test_ext().execute_with(|| {
+        let account_id = 2;
+        let factors = sample_factors();
+
+        // Definir score
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(account_id), factors.clone()));
+
+        // Verificar se pode ser recuperado
+        let score_data = CreditScore::credit_score(account_id).unwrap();
+        assert_eq!(score_data.score, 707);
+        assert_eq!(score_data.factors.to_vec(), factors);
+    });
+}
+
+#[test]
+fn test_credit_score_count() {
+    new_test_ext().execute_with(|| {
+        // Contador inicial
+        assert_eq!(TotalScoresCalculated::<Test>::get(), 0);
+
+        // Adicionar primeiro score
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_eq!(TotalScoresCalculated::<Test>::get(), 1);
+
+        // Adicionar segundo score
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(3), sample_factors()));
+        assert_eq!(TotalScoresCalculated::<Test>::get(), 2);
+
+        // Atualizar score existente (contador não deve mudar)
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), uniform_factors(2, 80)));
+        assert_eq!(TotalScoresCalculated::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn test_credit_score_storage_limits() {
+    new_test_ext().execute_with(|| {
+        // Limite máximo de fatores (`MaxScoreFactors` do mock)
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(10, 50)));
+        assert_eq!(CreditScore::credit_score(2).unwrap().factors.len(), 10);
+
+        // Limite mínimo de fatores
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(3), uniform_factors(1, 50)));
+        assert_eq!(CreditScore::credit_score(3).unwrap().factors.len(), 1);
+    });
+}
+
+#[test]
+fn test_credit_score_events() {
+    new_test_ext().execute_with(|| {
+        // Eventos só são registrados a partir do bloco 1
+        System::set_block_number(1);
+
+        // Definir score e verificar evento
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        System::assert_has_event(
+            Event::ScoreCalculated {
+                user: 2,
+                score: 707,
+                factors: sample_factors(),
+                block_number: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_credit_score_edge_cases() {
+    new_test_ext().execute_with(|| {
+        // Valor mínimo
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(1, 1)));
+        assert_eq!(CreditScore::credit_score(2).unwrap().score, 10);
+
+        // Valor máximo é limitado a 1000
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(3), uniform_factors(1, 500)));
+        assert_eq!(CreditScore::credit_score(3).unwrap().score, 1000);
+
+        // Limite de fatores
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(4), uniform_factors(10, 50)));
+        assert_eq!(CreditScore::credit_score(4).unwrap().factors.len(), 10);
+    });
+}
+
+#[test]
+fn test_credit_score_performance() {
+    new_test_ext().execute_with(|| {
+        // Testar performance com múltiplas operações
+        for i in 2..=101 {
+            assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(i), sample_factors()));
+        }
+
+        // Verificar se todos os scores foram armazenados
+        for i in 2..=101 {
+            let stored_score = CreditScore::credit_score(i);
+            assert!(stored_score.is_some());
+            assert_eq!(stored_score.unwrap().score, 707);
+        }
+    });
+}
+
+#[test]
+fn test_credit_score_concurrent_updates() {
+    new_test_ext().execute_with(|| {
+        // Simular atualizações concorrentes
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(1, 75)));
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), uniform_factors(1, 80)));
+
+        // Verificar que o último valor foi mantido
+        let stored_score = CreditScore::credit_score(2);
+        assert!(stored_score.is_some());
+        assert_eq!(stored_score.unwrap().score, 800);
+    });
+}
+
+#[test]
+fn test_credit_score_cleanup() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        let first_stale_at = 1 + StalenessPeriod::get();
+        assert!(StalenessSchedule::<Test>::contains_key(first_stale_at, 2));
+
+        // Um novo cálculo substitui o agendamento anterior; o score permanece
+        System::set_block_number(5);
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert!(!StalenessSchedule::<Test>::contains_key(first_stale_at, 2));
+        assert!(StalenessSchedule::<Test>::contains_key(5 + StalenessPeriod::get(), 2));
+        assert_eq!(StalenessSchedule::<Test>::iter().count(), 1);
+        assert!(CreditScore::credit_score(2).is_some());
+    });
+}
+
+#[test]
+fn test_credit_score_validation() {
+    new_test_ext().execute_with(|| {
+        // Testar validação de valores
+        let valid_values = vec![1, 10, 50, 75, 100];
+        for value in valid_values {
+            assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(1, value)));
+            assert_eq!(CreditScore::credit_score(2).unwrap().score, value * 10);
+        }
+    });
+}
+
+#[test]
+fn test_credit_score_factors_validation() {
+    new_test_ext().execute_with(|| {
+        // Testar quantidades válidas de fatores
+        for count in [1, 3, 5, 10] {
+            assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(count, 50)));
+        }
+
+        // Testar quantidades inválidas
+        for count in [11, 50] {
+            assert_noop!(
+                CreditScore::calculate_score(RuntimeOrigin::signed(2), uniform_factors(count, 50)),
+                Error::<Test>::TooManyScoreFactors
+            );
+        }
+    });
+}
+
+// Helper para criar um conjunto simples de fatores
+fn sample_factors() -> Vec<ScoreFactor> {
+    vec![
+        ScoreFactor { factor_type: ScoreFactorType::PaymentHistory, value: 80, weight: 35 },
+        ScoreFactor { factor_type: ScoreFactorType::CreditUtilization, value: 60, weight: 30 },
+    ]
+}
+
+#[test]
+fn test_verify_score_requires_registered_verifier() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+
+        // Conta sem credenciamento não pode verificar
+        assert_noop!(
+            CreditScore::verify_score(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::NotAVerifier
+        );
+
+        // Governança credencia o verificador
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, b"Serasa".to_vec()));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(score_data.is_verified);
+        assert_eq!(score_data.verification_count, 1);
+        assert_eq!(CreditScore::verifiers(2).unwrap().total_verifications, 1);
+
+        // O mesmo verificador não pode verificar o mesmo score novamente
+        assert_noop!(
+            CreditScore::verify_score(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::AlreadyVerified
+        );
+    });
+}
+
+#[test]
+fn test_verifier_registry_governance() {
+    new_test_ext().execute_with(|| {
+        // Apenas a origem de governança pode gerenciar verificadores
+        assert_noop!(
+            CreditScore::add_verifier(RuntimeOrigin::signed(1), 2, vec![]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        assert_noop!(
+            CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]),
+            Error::<Test>::VerifierAlreadyExists
+        );
+        assert_noop!(
+            CreditScore::add_verifier(RuntimeOrigin::root(), 3, vec![0u8; 65]),
+            Error::<Test>::VerifierMetadataTooLong
+        );
+
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 0));
+        assert!(CreditScore::verifiers(2).is_none());
+        assert_noop!(
+            CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 0),
+            Error::<Test>::VerifierNotFound
+        );
+    });
+}
+
+#[test]
+fn test_removed_verifier_no_longer_counts() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 3, vec![]));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+
+        // O descredenciamento desfaz as verificações do verificador
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 1));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(!score_data.is_verified);
+        assert_eq!(score_data.verification_count, 0);
+        assert!(CreditScore::score_verifications(1, 2).is_none());
+
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(3), 1));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(score_data.is_verified);
+        assert_eq!(score_data.verification_count, 1);
+
+        // Sem verificadores credenciados o selo deixa de valer
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 3, 1));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(!score_data.is_verified);
+        assert_eq!(score_data.verification_count, 0);
+    });
+}
+
+#[test]
+fn test_reregistered_verifier_starts_without_verifications() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 1));
+
+        // Recredenciado, o verificador não recupera as verificações antigas
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(!score_data.is_verified);
+        assert_eq!(score_data.verification_count, 0);
+
+        // E pode verificar o mesmo score novamente
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+        assert_eq!(CreditScore::credit_score(1).unwrap().verification_count, 1);
+    });
+}
+
+#[test]
+fn test_remove_verifier_requires_sufficient_witness() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(4), sample_factors()));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 4));
+
+        // O número informado precisa cobrir todos os scores verificados
+        assert_noop!(
+            CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 1),
+            Error::<Test>::VerifiedScoresWitnessTooLow
+        );
+
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 2, 2));
+        assert_eq!(CreditScore::credit_score(1).unwrap().verification_count, 0);
+        assert_eq!(CreditScore::credit_score(4).unwrap().verification_count, 0);
+        assert_eq!(VerifierScores::<Test>::iter_prefix(2).count(), 0);
+    });
+}
+
+#[test]
+fn test_new_score_clears_verifications() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 2, vec![]));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 3, vec![]));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(3), 1));
+
+        // Um novo cálculo descarta as verificações do score anterior
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(1), sample_factors()));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(!score_data.is_verified);
+        assert_eq!(score_data.verification_count, 0);
+        assert_eq!(ScoreVerifications::<Test>::iter_prefix(1).count(), 0);
+        assert_eq!(VerifierScores::<Test>::iter_prefix(2).count(), 0);
+        assert_eq!(VerifierScores::<Test>::iter_prefix(3).count(), 0);
+
+        // O novo score pode ser verificado pelos mesmos verificadores
+        assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(2), 1));
+        assert_eq!(CreditScore::credit_score(1).unwrap().verification_count, 1);
+        assert_ok!(CreditScore::remove_verifier(RuntimeOrigin::root(), 3, 0));
+    });
+}
+
+#[test]
+fn test_verifications_per_score_are_capped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        for verifier in 2..=5 {
+            assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), verifier, vec![]));
+        }
+        for verifier in 2..=4 {
+            assert_ok!(CreditScore::verify_score(RuntimeOrigin::signed(verifier), 1));
+        }
+
+        // MaxScoreVerifications = 3
+        assert_noop!(
+            CreditScore::verify_score(RuntimeOrigin::signed(5), 1),
+            Error::<Test>::TooManyVerifications
+        );
+        assert_eq!(CreditScore::credit_score(1).unwrap().verification_count, 3);
+    });
+}
+
+#[test]
+fn test_attested_factors_record_attester() {
+    new_test_ext().execute_with(|| {
+        // Conta comum não pode atestar fatores
+        assert_noop!(
+            CreditScore::submit_attested_factors(RuntimeOrigin::signed(3), 2, sample_factors()),
+            Error::<Test>::NotAnAttester
+        );
+
+        // Oráculo ativo atesta os fatores do usuário (sem pagamentos on-chain)
+        assert_ok!(CreditScore::submit_attested_factors(RuntimeOrigin::signed(100), 2, sample_factors()));
+
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert!(score_data.is_attested());
+        assert_eq!(score_data.source, FactorSource::Attester(100));
+
+        let record = CreditScore::score_factors(2, ScoreFactorType::PaymentHistory).unwrap();
+        assert_eq!(record.value, 80);
+        assert_eq!(record.source, FactorSource::Attester(100));
+
+        // Birô registrado pela governança também pode atestar
+        assert_ok!(CreditScore::register_bureau(RuntimeOrigin::root(), 3, b"Boa Vista".to_vec()));
+        assert_ok!(CreditScore::submit_attested_factors(RuntimeOrigin::signed(3), 2, sample_factors()));
+        assert_eq!(CreditScore::credit_score(2).unwrap().source, FactorSource::Attester(3));
+    });
+}
+
+#[test]
+fn test_self_reported_factors_flagged_or_rejected() {
+    new_test_ext().execute_with(|| {
+        // No modo padrão, fatores autodeclarados são aceitos mas sinalizados
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert!(!score_data.is_attested());
+        assert_eq!(score_data.source, FactorSource::SelfReported);
+
+        // No modo restrito, fatores autodeclarados são rejeitados
+        assert_ok!(CreditScore::set_attestation_mode(
+            RuntimeOrigin::root(),
+            FactorAttestationMode::AttestedOnly
+        ));
+        assert_noop!(
+            CreditScore::update_score(RuntimeOrigin::signed(1), sample_factors()),
+            Error::<Test>::SelfReportedFactorsNotAllowed
+        );
+        assert_noop!(
+            CreditScore::add_score_factor(RuntimeOrigin::signed(1), ScoreFactorType::CreditAge, 50, 10),
+            Error::<Test>::SelfReportedFactorsNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_payment_history_factor_derived_from_payments() {
+    new_test_ext().execute_with(|| {
+        // O valor informado pelo usuário (80) é substituído pelo derivado on-chain (90)
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+
+        let record = CreditScore::score_factors(1, ScoreFactorType::PaymentHistory).unwrap();
+        assert_eq!(record.value, 90);
+        assert_eq!(record.source, FactorSource::System);
+
+        // Sem pagamentos on-chain, o fator informado é mantido
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        let record = CreditScore::score_factors(2, ScoreFactorType::PaymentHistory).unwrap();
+        assert_eq!(record.value, 80);
+        assert_eq!(record.source, FactorSource::SelfReported);
+    });
+}
+
+#[test]
+fn test_payment_state_change_triggers_system_update() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::OnPaymentStateChange;
+
+        // Sem score calculado, apenas o fator é registrado
+        CreditScore::on_payment_state_change(&1);
+        assert!(CreditScore::credit_score(1).is_none());
+        assert_eq!(CreditScore::score_factors(1, ScoreFactorType::PaymentHistory).unwrap().value, 90);
+
+        // Com score existente, o score é recalculado pelo sistema
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(1), sample_factors()));
+        frame_system::Pallet::<Test>::set_block_number(2);
+        CreditScore::on_payment_state_change(&1);
+
+        let change = CreditScore::score_history(1, 1).unwrap();
+        assert_eq!(change.reason, ScoreUpdateReason::SystemUpdate);
+    });
+}
+
+#[test]
+fn test_system_update_downgrades_overwritten_attested_score() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::OnPaymentStateChange;
+
+        assert_ok!(CreditScore::submit_attested_factors(RuntimeOrigin::signed(100), 1, sample_factors()));
+
+        // Recálculo sem fatores sobrescritos mantém a fonte atestada
+        frame_system::Pallet::<Test>::set_block_number(2);
+        CreditScore::on_payment_state_change(&1);
+        assert_eq!(CreditScore::credit_score(1).unwrap().source, FactorSource::Attester(100));
+
+        // O titular sobrescreve um fator atestado e dispara um recálculo
+        assert_ok!(CreditScore::add_score_factor(RuntimeOrigin::signed(1), ScoreFactorType::CreditUtilization, 100, 30));
+        frame_system::Pallet::<Test>::set_block_number(3);
+        CreditScore::on_payment_state_change(&1);
+
+        let score_data = CreditScore::credit_score(1).unwrap();
+        assert_eq!(score_data.source, FactorSource::SelfReported);
+        assert!(!score_data.is_attested());
+        assert_eq!(
+            CreditScore::score_factors(1, ScoreFactorType::CreditUtilization).unwrap().source,
+            FactorSource::SelfReported
+        );
+    });
+}
+
+#[test]
+fn test_scoring_model_recorded_with_score() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Score e histórico registram o modelo que os produziu
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert_eq!(score_data.model, WeightedAverageModel::info());
+        assert_eq!(CreditScore::score_history(2, 0).unwrap().model, WeightedAverageModel::info());
+
+        // Média ponderada: (80*35 + 60*30) * 1000 / (65 * 100) = 707
+        assert_eq!(score_data.score, 707);
+    });
+}
+
+#[test]
+fn test_weighted_average_model() {
+    assert_eq!(WeightedAverageModel::compute(&sample_factors()), Some(707));
+    assert_eq!(WeightedAverageModel::compute(&[]), None);
+}
+
+// Helper para um scorecard com os dois fatores de `sample_factors` e o fator derivado NewCredit
+fn sample_scorecard() -> Vec<ScorecardEntry> {
+    vec![
+        ScorecardEntry {
+            factor_type: ScoreFactorType::NewCredit,
+            weight: 50,
+            min_value: 1,
+            max_value: 100,
+            curve: NormalizationCurve::Linear,
+        },
+        ScorecardEntry {
+            factor_type: ScoreFactorType::PaymentHistory,
+            weight: 50,
+            min_value: 0,
+            max_value: 100,
+            curve: NormalizationCurve::Linear,
+        },
+        ScorecardEntry {
+            factor_type: ScoreFactorType::CreditUtilization,
+            weight: 50,
+            min_value: 0,
+            max_value: 100,
+            curve: NormalizationCurve::Inverse,
+        },
+    ]
+}
+
+#[test]
+fn test_scorecard_activation_at_future_block() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+
+        // Bloco de ativação deve estar no futuro
+        assert_noop!(
+            CreditScore::propose_scorecard(RuntimeOrigin::root(), sample_scorecard(), 1),
+            Error::<Test>::ActivationBlockInPast
+        );
+        assert_noop!(
+            CreditScore::propose_scorecard(RuntimeOrigin::signed(1), sample_scorecard(), 10),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(CreditScore::propose_scorecard(RuntimeOrigin::root(), sample_scorecard(), 10));
+        assert!(CreditScore::active_scorecard().is_none());
+
+        // Ainda não ativado antes do bloco definido
+        CreditScore::on_initialize(9);
+        assert!(CreditScore::active_scorecard().is_none());
+
+        CreditScore::on_initialize(10);
+        assert_eq!(CreditScore::active_scorecard().unwrap().version, 1);
+        assert!(CreditScore::pending_scorecard().is_none());
+    });
+}
+
+#[test]
+fn test_scorecard_requires_derived_factor_rules() {
+    new_test_ext().execute_with(|| {
+        // Sem regra para NewCredit
+        let mut missing = sample_scorecard();
+        missing.retain(|entry| entry.factor_type != ScoreFactorType::NewCredit);
+        assert_noop!(
+            CreditScore::propose_scorecard(RuntimeOrigin::root(), missing, 10),
+            Error::<Test>::IncompatibleDerivedFactorRule
+        );
+
+        // Faixa de PaymentHistory não cobre os valores derivados 1-100
+        let mut narrow = sample_scorecard();
+        for entry in narrow.iter_mut().filter(|entry| entry.factor_type == ScoreFactorType::PaymentHistory) {
+            entry.min_value = 10;
+        }
+        assert_noop!(
+            CreditScore::propose_scorecard(RuntimeOrigin::root(), narrow, 10),
+            Error::<Test>::IncompatibleDerivedFactorRule
+        );
+
+        let mut wide = sample_scorecard();
+        for entry in wide.iter_mut() {
+            entry.max_value = 1000;
+        }
+        assert_ok!(CreditScore::propose_scorecard(RuntimeOrigin::root(), wide, 10));
+    });
+}
+
+#[test]
+fn test_scorecard_overrides_caller_weights() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::propose_scorecard(RuntimeOrigin::root(), sample_scorecard(), 1));
+        CreditScore::on_initialize(1);
+
+        // Pesos informados (35/30) são ignorados; utilização é invertida
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert_eq!(score_data.scorecard_version, 1);
+        assert!(score_data.factors.iter().all(|factor| factor.weight == 50));
+
+        // Fator fora do scorecard é rejeitado
+        assert_noop!(
+            CreditScore::update_score(
+                RuntimeOrigin::signed(2),
+                vec![ScoreFactor { factor_type: ScoreFactorType::CreditAge, value: 50, weight: 10 }]
+            ),
+            Error::<Test>::FactorNotInScorecard
+        );
+
+        // Valor fora da faixa é rejeitado
+        assert_noop!(
+            CreditScore::update_score(
+                RuntimeOrigin::signed(2),
+                vec![ScoreFactor { factor_type: ScoreFactorType::PaymentHistory, value: 150, weight: 10 }]
+            ),
+            Error::<Test>::FactorValueOutOfRange
+        );
+    });
+}
+
+#[test]
+fn test_normalization_curves() {
+    assert_eq!(NormalizationCurve::Linear.normalize(0, 0, 100), 1);
+    assert_eq!(NormalizationCurve::Linear.normalize(100, 0, 100), 100);
+    assert_eq!(NormalizationCurve::Inverse.normalize(100, 0, 100), 1);
+
+    let points = vec![(0, 100), (30, 80), (100, 10)].try_into().unwrap();
+    let curve = NormalizationCurve::Piecewise(points);
+    assert!(curve.is_valid());
+    assert_eq!(curve.normalize(15, 0, 100), 90);
+    assert_eq!(curve.normalize(100, 0, 100), 10);
+}
+
+#[test]
+fn test_score_staleness_and_decay() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Score recente: sem decaimento
+        let effective = CreditScore::effective_score(&2).unwrap();
+        assert_eq!(effective.score, 707);
+        assert!(!effective.is_stale);
+
+        // Após o período de validade, o score é marcado como desatualizado
+        frame_system::Pallet::<Test>::set_block_number(100);
+        CreditScore::on_initialize(100);
+        assert!(CreditScore::credit_score(2).unwrap().is_stale);
+
+        // O decaimento é aplicado na consulta, aproximando do score neutro
+        frame_system::Pallet::<Test>::set_block_number(150);
+        let effective = CreditScore::effective_score(&2).unwrap();
+        assert_eq!(effective.raw_score, 707);
+        assert_eq!(effective.decay_periods, 5);
+        assert!(effective.score > 500 && effective.score < 707);
+
+        // Recalcular o score remove a marcação
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert!(!CreditScore::credit_score(2).unwrap().is_stale);
+        assert_eq!(CreditScore::effective_score(&2).unwrap().score, 707);
+    });
+}
+
+#[test]
+fn test_history_keeps_updates_in_same_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Duas mudanças no mesmo bloco recebem sequências distintas
+        assert_eq!(CreditScore::score_history(2, 0).unwrap().reason, ScoreUpdateReason::InitialCalculation);
+        assert_eq!(CreditScore::score_history(2, 1).unwrap().reason, ScoreUpdateReason::UserUpdate);
+        assert_eq!(CreditScore::history_index(2).next, 2);
+    });
+}
+
+#[test]
+fn test_history_prunes_oldest_and_paginates() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        for _ in 0..4 {
+            assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+        }
+
+        // Limite de 3 entradas: as sequências 0 e 1 foram descartadas
+        let cursor = CreditScore::history_index(2);
+        assert_eq!((cursor.oldest, cursor.next), (2, 5));
+        assert!(CreditScore::score_history(2, 1).is_none());
+
+        let (page, next) = CreditScore::score_history_page(&2, None, 2);
+        assert_eq!(page.iter().map(|c| c.sequence).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(next, Some(4));
+
+        let (page, next) = CreditScore::score_history_page(&2, next, 2);
+        assert_eq!(page.iter().map(|c| c.sequence).collect::<Vec<_>>(), vec![4]);
+        assert_eq!(next, None);
+    });
+}
+
+#[test]
+fn test_explain_score_contributions() {
+    new_test_ext().execute_with(|| {
+        assert!(CreditScore::explain_score(&2).is_none());
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // 80*35*1000/6500 = 430 e 60*30*1000/6500 = 276
+        let explanation = CreditScore::explain_score(&2).unwrap();
+        assert_eq!(explanation.score, 707);
+        let points: Vec<u32> = explanation.contributions.iter().map(|c| c.points).collect();
+        assert_eq!(points, vec![430, 276]);
+    });
+}
+
+#[test]
+fn test_score_reasons_ranked_by_impact() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Utilização (60) custa mais pontos que histórico de pagamentos (80)
+        let reasons = CreditScore::score_reasons(&2);
+        assert_eq!(reasons.len(), 2);
+        assert_eq!(reasons[0].code, ReasonCode::HighCreditUtilization);
+        assert_eq!(reasons[1].code, ReasonCode::LatePayments);
+        assert!(reasons[0].points_lost > reasons[1].points_lost);
+    });
+}
+
+#[test]
+fn test_consent_grant_pull_and_revoke() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Sem consentimento o credor não pode consultar
+        assert_noop!(
+            CreditScore::pull_score(
+                RuntimeOrigin::signed(3),
+                2,
+                ConsentPurpose::CreditApplication,
+                ProductType::CreditCard
+            ),
+            Error::<Test>::NoValidConsent
+        );
+
+        assert_ok!(CreditScore::grant_consent(
+            RuntimeOrigin::signed(2),
+            3,
+            ConsentPurpose::CreditApplication,
+            10
+        ));
+        assert!(CreditScore::has_valid_consent(&2, &3, ConsentPurpose::CreditApplication));
+        assert!(!CreditScore::has_valid_consent(&2, &3, ConsentPurpose::Insurance));
+
+        // Consulta fica registrada como inquiry
+        assert_ok!(CreditScore::pull_score(
+            RuntimeOrigin::signed(3),
+            2,
+            ConsentPurpose::CreditApplication,
+            ProductType::CreditCard
+        ));
+        let inquiries = CreditScore::inquiries(2);
+        assert_eq!(inquiries.len(), 1);
+        assert_eq!(inquiries[0].lender, 3);
+
+        // Revogação encerra o acesso
+        assert_ok!(CreditScore::revoke_consent(RuntimeOrigin::signed(2), 3));
+        assert!(!CreditScore::has_valid_consent(&2, &3, ConsentPurpose::CreditApplication));
+        assert_noop!(
+            CreditScore::revoke_consent(RuntimeOrigin::signed(2), 3),
+            Error::<Test>::ConsentNotFound
+        );
+    });
+}
+
+#[test]
+fn test_consent_expires_and_duration_is_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::Rental, 1001),
+            Error::<Test>::InvalidConsentDuration
+        );
+        assert_noop!(
+            CreditScore::grant_consent(RuntimeOrigin::signed(2), 2, ConsentPurpose::Rental, 10),
+            Error::<Test>::CannotConsentToSelf
+        );
+
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::Rental, 10));
+        frame_system::Pallet::<Test>::set_block_number(10);
+        assert!(!CreditScore::has_valid_consent(&2, &3, ConsentPurpose::Rental));
+    });
+}
+
+#[test]
+fn test_consent_not_overwritten_while_valid() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::CreditApplication, 10));
+
+        // Nova concessão ao mesmo credor não substitui a vigente
+        assert_noop!(
+            CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::Rental, 10),
+            Error::<Test>::ConsentAlreadyExists
+        );
+        assert!(CreditScore::has_valid_consent(&2, &3, ConsentPurpose::CreditApplication));
+
+        // Após o vencimento, pode ser concedida novamente
+        frame_system::Pallet::<Test>::set_block_number(10);
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::Rental, 10));
+        assert!(CreditScore::has_valid_consent(&2, &3, ConsentPurpose::Rental));
+    });
+}
+
+#[test]
+fn test_pull_score_rejects_expired_consent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::CreditApplication, 10));
+
+        // Consentimento ainda válido não pode ser removido
+        assert_noop!(
+            CreditScore::prune_expired_consent(RuntimeOrigin::signed(4), 2, 3),
+            Error::<Test>::ConsentNotExpired
+        );
+
+        // Consulta com consentimento vencido falha sem registrar inquiry
+        System::set_block_number(11);
+        assert_noop!(
+            CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::CreditCard),
+            Error::<Test>::NoValidConsent
+        );
+        assert!(CreditScore::inquiries(2).is_empty());
+
+        // Qualquer conta pode remover o consentimento vencido
+        assert_ok!(CreditScore::prune_expired_consent(RuntimeOrigin::signed(4), 2, 3));
+        assert!(CreditScore::consent(2, 3).is_none());
+        System::assert_last_event(Event::ConsentExpired { owner: 2, lender: 3 }.into());
+        assert_noop!(
+            CreditScore::prune_expired_consent(RuntimeOrigin::signed(4), 2, 3),
+            Error::<Test>::ConsentNotFound
+        );
+    });
+}
+
+#[test]
+fn test_hard_inquiries_derive_new_credit_with_dedup() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        for lender in [3, 4] {
+            assert_ok!(CreditScore::grant_consent(
+                RuntimeOrigin::signed(2),
+                lender,
+                ConsentPurpose::CreditApplication,
+                500
+            ));
+        }
+
+        // Duas consultas do credor 3 no período de de-duplicação contam uma vez
+        assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::AutoLoan));
+        assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::AutoLoan));
+        assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(4), 2, ConsentPurpose::CreditApplication, ProductType::Mortgage));
+
+        let inquiries = CreditScore::inquiries(2);
+        assert_eq!(inquiries.len(), 3);
+        assert!(inquiries[1].is_duplicate);
+        assert_eq!(CreditScore::recent_hard_inquiries(&2), 2);
+
+        // NewCredit derivado: 100 - 2 * 15 = 70, registrado como fator do sistema
+        let record = CreditScore::score_factors(2, ScoreFactorType::NewCredit).unwrap();
+        assert_eq!(record.value, 70);
+        assert_eq!(record.source, FactorSource::System);
+
+        // Fora da janela as consultas deixam de contar
+        frame_system::Pallet::<Test>::set_block_number(100);
+        assert_eq!(CreditScore::derived_new_credit_factor(&2).unwrap().value, 100);
+    });
+}
+
+#[test]
+fn test_new_credit_refreshed_when_inquiry_leaves_window() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::CreditApplication, 500));
+        assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::CreditCard));
+
+        let penalized = CreditScore::credit_score(2).unwrap().score;
+        assert_eq!(CreditScore::score_factors(2, ScoreFactorType::NewCredit).unwrap().value, 85);
+
+        // Ainda dentro da janela (InquiryWindow = 100): nada muda
+        System::set_block_number(100);
+        CreditScore::on_initialize(100);
+        assert_eq!(CreditScore::score_factors(2, ScoreFactorType::NewCredit).unwrap().value, 85);
+
+        // A consulta sai da janela e o score é recalculado sem a penalidade
+        System::set_block_number(101);
+        CreditScore::on_initialize(101);
+        let record = CreditScore::score_factors(2, ScoreFactorType::NewCredit).unwrap();
+        assert_eq!(record.value, 100);
+        assert_eq!(record.recorded_at, 101);
+
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert_eq!(score_data.calculated_at, 101);
+        assert!(score_data.score > penalized);
+        assert_eq!(CreditScore::inquiry_expiry_cursor(), 102);
+    });
+}
+
+#[test]
+fn test_new_credit_still_derived_after_inquiries_rotate() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::CreditApplication, 500));
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 4, ConsentPurpose::Rental, 500));
+        assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::CreditCard));
+
+        // Consultas comuns (MaxInquiries = 5) descartam a hard inquiry do registro
+        for _ in 0..5 {
+            assert_ok!(CreditScore::pull_score(RuntimeOrigin::signed(4), 2, ConsentPurpose::Rental, ProductType::Other));
+        }
+        assert!(CreditScore::inquiries(2).iter().all(|inquiry| !inquiry.is_hard));
+
+        // O fator continua derivado, sem penalidade, e prevalece sobre o informado
+        assert_eq!(CreditScore::derived_new_credit_factor(&2).unwrap().value, 100);
+
+        let mut factors = sample_factors();
+        factors.push(ScoreFactor { factor_type: ScoreFactorType::NewCredit, value: 10, weight: 10 });
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), factors));
+
+        let record = CreditScore::score_factors(2, ScoreFactorType::NewCredit).unwrap();
+        assert_eq!(record.value, 100);
+        assert_eq!(record.source, FactorSource::System);
+    });
+}
+
+#[test]
+fn test_score_dispute_accepted_applies_compliance_update() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::add_verifier(RuntimeOrigin::root(), 5, b"revisor".to_vec()));
+
+        assert_ok!(CreditScore::open_score_dispute(RuntimeOrigin::signed(2), sp_core::H256::repeat_byte(1)));
+        assert_noop!(
+            CreditScore::open_score_dispute(RuntimeOrigin::signed(2), sp_core::H256::repeat_byte(2)),
+            Error::<Test>::ScoreDisputeAlreadyOpen
+        );
+
+        // Apenas o revisor designado pode decidir
+        assert_ok!(CreditScore::assign_dispute_reviewer(RuntimeOrigin::root(), 0, 5));
+        assert_noop!(
+            CreditScore::reject_score_dispute(RuntimeOrigin::signed(6), 0),
+            Error::<Test>::NotDisputeReviewer
+        );
+
+        let corrected = vec![
+            ScoreFactor { factor_type: ScoreFactorType::PaymentHistory, value: 90, weight: 35 },
+            ScoreFactor { factor_type: ScoreFactorType::CreditUtilization, value: 60, weight: 30 },
+        ];
+        assert_ok!(CreditScore::accept_score_dispute(RuntimeOrigin::signed(5), 0, corrected));
+
+        assert_eq!(CreditScore::score_dispute(0).unwrap().status, ScoreDisputeStatus::Accepted);
+        assert!(CreditScore::open_dispute_of(2).is_none());
+        assert_eq!(CreditScore::score_history(2, 1).unwrap().reason, ScoreUpdateReason::ComplianceUpdate);
+
+        // Fatores corrigidos são atribuídos ao revisor, não à fonte original
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert!(score_data.score > 707);
+        assert_eq!(score_data.source, FactorSource::DisputeReviewer(5));
+        let record = CreditScore::score_factors(2, ScoreFactorType::CreditUtilization).unwrap();
+        assert_eq!(record.source, FactorSource::DisputeReviewer(5));
+    });
+}
+
+#[test]
+fn test_score_dispute_expires_at_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::open_score_dispute(RuntimeOrigin::signed(2), sp_core::H256::repeat_byte(1)));
+
+        CreditScore::on_initialize(20);
+
+        assert_eq!(CreditScore::score_dispute(0).unwrap().status, ScoreDisputeStatus::Expired);
+        assert!(CreditScore::open_dispute_of(2).is_none());
+        assert_eq!(CreditScore::credit_score(2).unwrap().score, 707);
+    });
+}
+
+#[test]
+fn test_score_dispute_zero_review_period_still_expires() {
+    new_test_ext().execute_with(|| {
+        DisputeReviewPeriod::set(&0);
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::open_score_dispute(RuntimeOrigin::signed(2), sp_core::H256::repeat_byte(1)));
+
+        // Prazo nulo vira um bloco, drenado no bloco seguinte
+        assert_eq!(CreditScore::score_dispute(0).unwrap().deadline, 1);
+        CreditScore::on_initialize(1);
+        assert_eq!(CreditScore::score_dispute(0).unwrap().status, ScoreDisputeStatus::Expired);
+    });
+}
+
+#[test]
+fn test_score_dispute_expires_after_skipped_deadline() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::open_score_dispute(RuntimeOrigin::signed(2), sp_core::H256::repeat_byte(1)));
+
+        // O bloco do prazo não foi processado; o cursor recupera o atraso
+        CreditScore::on_initialize(25);
+
+        assert_eq!(CreditScore::score_dispute(0).unwrap().status, ScoreDisputeStatus::Expired);
+        assert_eq!(CreditScore::dispute_deadline_cursor(), 26);
+    });
+}
+
+#[test]
+fn test_freeze_blocks_consent_and_pulls_until_lifted() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_ok!(CreditScore::grant_consent(RuntimeOrigin::signed(2), 3, ConsentPurpose::CreditApplication, 100));
+
+        assert_ok!(CreditScore::freeze_score(RuntimeOrigin::signed(2)));
+        assert!(CreditScore::is_frozen(&2));
+        assert_noop!(
+            CreditScore::pull_score(RuntimeOrigin::signed(3), 2, ConsentPurpose::CreditApplication, ProductType::PersonalLoan),
+            Error::<Test>::ScoreFrozen
+        );
+        assert_noop!(
+            CreditScore::grant_consent(RuntimeOrigin::signed(2), 4, ConsentPurpose::Rental, 10),
+            Error::<Test>::ScoreFrozen
+        );
+
+        // Suspensão temporária libera consultas até o bloco limite
+        assert_ok!(CreditScore::lift_freeze_temporarily(RuntimeOrigin::signed(2), 5));
+        assert!(!CreditScore::is_frozen(&2));
+        assert_ok!(CreditScore::pull_score(
+            RuntimeOrigin::signed(3),
+            2,
+            ConsentPurpose::CreditApplication,
+            ProductType::PersonalLoan
+        ));
+
+        frame_system::Pallet::<Test>::set_block_number(5);
+        CreditScore::on_initialize(5);
+        assert!(CreditScore::is_frozen(&2));
+        assert_eq!(CreditScore::score_freeze(2).unwrap().lifted_until, None);
+
+        assert_ok!(CreditScore::unfreeze_score(RuntimeOrigin::signed(2)));
+        assert!(!CreditScore::is_frozen(&2));
+    });
+}
+
+#[test]
+fn test_freeze_reinstated_after_skipped_lift_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::freeze_score(RuntimeOrigin::signed(2)));
+        assert_ok!(CreditScore::lift_freeze_temporarily(RuntimeOrigin::signed(2), 5));
+
+        // O bloco 5 não foi processado; o cursor recupera o atraso
+        frame_system::Pallet::<Test>::set_block_number(8);
+        CreditScore::on_initialize(8);
+
+        assert_eq!(CreditScore::score_freeze(2).unwrap().lifted_until, None);
+        assert_eq!(CreditScore::freeze_lift_cursor(), 9);
+    });
+}
+
+#[test]
+fn test_fraud_response_freeze_requires_fraud_origin_to_lift() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::force_freeze_score(RuntimeOrigin::root(), 2));
+
+        assert_noop!(
+            CreditScore::unfreeze_score(RuntimeOrigin::signed(2)),
+            Error::<Test>::FreezeHeldByFraudResponse
+        );
+        assert_noop!(
+            CreditScore::lift_freeze_temporarily(RuntimeOrigin::signed(2), 5),
+            Error::<Test>::FreezeHeldByFraudResponse
+        );
+        assert_noop!(
+            CreditScore::force_unfreeze_score(RuntimeOrigin::signed(2), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(CreditScore::force_unfreeze_score(RuntimeOrigin::root(), 2));
+        assert!(CreditScore::score_freeze(2).is_none());
+    });
+}
+
+fn band(label: &[u8], min_score: u32) -> ScoreBand {
+    ScoreBand { label: label.to_vec().try_into().unwrap(), min_score }
+}
+
+#[test]
+fn test_score_bands_classify_and_track_changes() {
+    new_test_ext().execute_with(|| {
+        // Faixas devem começar no score mínimo e crescer estritamente
+        assert_noop!(
+            CreditScore::set_score_bands(RuntimeOrigin::root(), vec![band(b"B", 300), band(b"A", 700)]),
+            Error::<Test>::InvalidScoreBands
+        );
+        assert_noop!(
+            CreditScore::set_score_bands(RuntimeOrigin::root(), vec![band(b"C", 0), band(b"B", 700), band(b"A", 700)]),
+            Error::<Test>::InvalidScoreBands
+        );
+
+        assert_ok!(CreditScore::set_score_bands(
+            RuntimeOrigin::root(),
+            vec![band(b"C", 0), band(b"B", 500), band(b"A", 750)]
+        ));
+
+        // 707 fica na faixa B
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_eq!(CreditScore::score_band(&2).unwrap().label.to_vec(), b"B".to_vec());
+        assert_eq!(CreditScore::credit_score(2).unwrap().band, Some(band(b"B", 0).label));
+
+        // Fatores melhores levam o score à faixa A
+        let improved = vec![
+            ScoreFactor { factor_type: ScoreFactorType::PaymentHistory, value: 95, weight: 35 },
+            ScoreFactor { factor_type: ScoreFactorType::CreditUtilization, value: 90, weight: 30 },
+        ];
+        System::set_block_number(1);
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), improved));
+        assert_eq!(CreditScore::score_band(&2).unwrap().label.to_vec(), b"A".to_vec());
+        assert_eq!(CreditScore::credit_score(2).unwrap().band, Some(band(b"A", 0).label));
+        System::assert_has_event(
+            Event::ScoreBandChanged {
+                user: 2,
+                old_band: Some(band(b"B", 0).label),
+                new_band: Some(band(b"A", 0).label),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_score_band_follows_current_bands() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(CreditScore::set_score_bands(
+            RuntimeOrigin::root(),
+            vec![band(b"C", 0), band(b"B", 500), band(b"A", 750)]
+        ));
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_eq!(CreditScore::score_band(&2).unwrap().label.to_vec(), b"B".to_vec());
+
+        // Nova configuração com faixa extra: o score 707 passa a ser "B+" sem recálculo
+        assert_ok!(CreditScore::set_score_bands(
+            RuntimeOrigin::root(),
+            vec![band(b"D", 0), band(b"C", 300), band(b"B", 500), band(b"B+", 700), band(b"A", 750)]
+        ));
+        assert_eq!(CreditScore::score_band(&2).unwrap().label.to_vec(), b"B+".to_vec());
+        // A faixa registrada no score é a do cálculo até o próximo recálculo
+        assert_eq!(CreditScore::credit_score(2).unwrap().band, Some(band(b"B", 0).label));
+
+        // Recálculo com o mesmo score não muda de faixa, embora a posição tenha mudado
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert_eq!(CreditScore::credit_score(2).unwrap().band, Some(band(b"B+", 0).label));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::CreditScore(Event::ScoreBandChanged { .. })
+        )));
+    });
+}
+
+#[test]
+fn test_factors_bounded_by_max_score_factors() {
+    new_test_ext().execute_with(|| {
+        let too_many: Vec<ScoreFactor> = (0..11)
+            .map(|_| ScoreFactor { factor_type: ScoreFactorType::CreditMix, value: 50, weight: 10 })
+            .collect();
+
+        assert_noop!(
+            CreditScore::calculate_score(RuntimeOrigin::signed(2), too_many),
+            Error::<Test>::TooManyScoreFactors
+        );
+
+        // Hash do score é o mesmo registrado em LastScoreHash
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+        let score_data = CreditScore::credit_score(2).unwrap();
+        assert_eq!(Some(score_data.score_hash), CreditScore::last_score_hash(2));
+        assert!(CreditScoreDataOf::<Test>::max_encoded_len() > 0);
+    });
+}
+
+#[test]
+fn test_migration_v0_to_v1() {
+    use crate::migrations::v1::{v0, MigrateToV1};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CreditScore>();
+        frame_system::Pallet::<Test>::set_block_number(50);
+
+        // Score e fator no formato da versão 0
+        let old = v0::CreditScoreData {
+            user: 2u64,
+            score: 707,
+            factors: sample_factors(),
+            calculated_at: 10u64,
+            score_hash: sp_core::H256::repeat_byte(1),
+            is_verified: false,
+            verification_count: 0,
+        };
+        frame_support::storage::unhashed::put(&CreditScores::<Test>::hashed_key_for(2), &old);
+        frame_support::storage::unhashed::put(
+            &ScoreFactors::<Test>::hashed_key_for(2, ScoreFactorType::PaymentHistory),
+            &85u32,
+        );
+
+        // Quatro mudanças no histórico; o mock retém apenas três
+        for block in [4u64, 1, 3, 2] {
+            v0::ScoreHistory::<Test>::insert(2, block, v0::ScoreChange {
+                user: 2u64,
+                old_score: None,
+                new_score: 600 + block as u32,
+                factors: sample_factors(),
+                block_number: block,
+                reason: ScoreUpdateReason::UserUpdate,
+            });
+        }
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CreditScore::on_chain_storage_version(), 1);
+
+        let score = crate::migrations::v3::v2::CreditScores::<Test>::get(2).unwrap();
+        assert_eq!(score.score, 707);
+        assert_eq!(score.source, FactorSource::SelfReported);
+        assert_eq!(score.scorecard_version, 0);
+        assert!(!score.is_stale);
+        assert!(StalenessSchedule::<Test>::contains_key(110, 2));
+
+        let factor = ScoreFactors::<Test>::get(2, ScoreFactorType::PaymentHistory).unwrap();
+        assert_eq!(factor.value, 85);
+        assert_eq!(factor.recorded_at, 50);
+
+        // Histórico reindexado em ordem de bloco, sem a mudança mais antiga
+        assert_eq!(HistoryIndex::<Test>::get(2), HistoryCursor { oldest: 1, next: 4 });
+        let (page, _) = CreditScore::score_history_page(&2, None, 10);
+        let scores: Vec<u32> = page.iter().map(|change| change.new_score).collect();
+        assert_eq!(scores, vec![602, 603, 604]);
+
+        // Reexecutar não altera nada
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(HistoryIndex::<Test>::get(2), HistoryCursor { oldest: 1, next: 4 });
+    });
+}
+
+#[test]
+fn test_migration_v1_to_v2_starts_cursors() {
+    use crate::migrations::v2::MigrateToV2;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<CreditScore>();
+        frame_system::Pallet::<Test>::set_block_number(50);
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CreditScore::on_chain_storage_version(), 2);
+        assert_eq!(CreditScore::staleness_cursor(), 50);
+        assert_eq!(CreditScore::dispute_deadline_cursor(), 50);
+        assert_eq!(CreditScore::freeze_lift_cursor(), 50);
+
+        // Reexecutar não altera nada
+        frame_system::Pallet::<Test>::set_block_number(60);
+        MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(CreditScore::staleness_cursor(), 50);
+    });
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn test_try_runtime_v2_skips_cursor_checks_when_applied() {
+    use crate::migrations::v2::MigrateToV2;
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // Cursores já avançados por blocos processados após a versão 2
+        StorageVersion::new(2).put::<CreditScore>();
+        frame_system::Pallet::<Test>::set_block_number(50);
+        StalenessCursor::<Test>::put(40);
+        assert_ok!(MigrateToV2::<Test>::try_on_runtime_upgrade(true));
+
+        StorageVersion::new(1).put::<CreditScore>();
+        assert_ok!(MigrateToV2::<Test>::try_on_runtime_upgrade(true));
+        assert_eq!(CreditScore::staleness_cursor(), 50);
+    });
+}
+
+#[test]
+fn test_migration_v2_to_v3_records_band() {
+    use crate::migrations::v3::{v2, MigrateToV3};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(CreditScore::set_score_bands(
+            RuntimeOrigin::root(),
+            vec![band(b"C", 0), band(b"B", 500), band(b"A", 750)]
+        ));
+        StorageVersion::new(2).put::<CreditScore>();
+
+        // Score no formato da versão 2, sem a faixa
+        v2::CreditScores::<Test>::insert(2, v2::CreditScoreData {
+            user: 2u64,
+            score: 707,
+            factors: sample_factors().try_into().unwrap(),
+            calculated_at: 10u64,
+            score_hash: sp_core::H256::repeat_byte(1),
+            is_verified: false,
+            verification_count: 0,
+            source: FactorSource::SelfReported,
+            model: ScoringModelInfo::default(),
+            scorecard_version: 0,
+            is_stale: false,
+        });
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CreditScore::on_chain_storage_version(), 3);
+        let score = CreditScore::credit_score(2).unwrap();
+        assert_eq!(score.score, 707);
+        assert_eq!(score.band, Some(band(b"B", 0).label));
+    });
+}
+
+#[test]
+fn test_migration_v3_to_v4_schedules_inquiry_expiries() {
+    use crate::migrations::v4::MigrateToV4;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(3).put::<CreditScore>();
+        frame_system::Pallet::<Test>::set_block_number(120);
+
+        // Consultas da versão 3, sem agenda: apenas a hard inquiry ainda na janela conta
+        let inquiry = |lender: u64, inquired_at: u64, is_hard: bool| Inquiry {
+            lender,
+            purpose: ConsentPurpose::CreditApplication,
+            product: ProductType::CreditCard,
+            inquired_at,
+            is_hard,
+            is_duplicate: false,
+        };
+        Inquiries::<Test>::insert(2, BoundedVec::truncate_from(vec![
+            inquiry(3, 10, true),
+            inquiry(4, 40, false),
+            inquiry(5, 40, true),
+        ]));
+        Inquiries::<Test>::insert(6, BoundedVec::truncate_from(vec![inquiry(3, 0, true)]));
+
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CreditScore::on_chain_storage_version(), 4);
+        assert_eq!(CreditScore::inquiry_expiry_cursor(), 120);
+        assert_eq!(InquiryExpirySchedule::<Test>::iter().count(), 1);
+        assert!(InquiryExpirySchedule::<Test>::contains_key(140, 2));
+    });
+}
+
+#[test]
+fn test_zero_staleness_period_still_marks_stale() {
+    new_test_ext().execute_with(|| {
+        StalenessPeriod::set(&0);
+        frame_system::Pallet::<Test>::set_block_number(5);
+        CreditScore::on_initialize(5);
+        assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(2), sample_factors()));
+
+        // Período nulo vira um bloco, drenado no bloco seguinte
+        assert!(StalenessSchedule::<Test>::contains_key(6, 2));
+        frame_system::Pallet::<Test>::set_block_number(6);
+        CreditScore::on_initialize(6);
+        assert!(CreditScore::credit_score(2).unwrap().is_stale);
+
+        // O recálculo remove o agendamento anterior pela mesma chave
+        assert_ok!(CreditScore::update_score(RuntimeOrigin::signed(2), sample_factors()));
+        assert!(!StalenessSchedule::<Test>::contains_key(6, 2));
+        assert!(StalenessSchedule::<Test>::contains_key(7, 2));
+    });
+}
+
+#[test]
+fn test_staleness_backlog_carries_over() {
+    new_test_ext().execute_with(|| {
+        MaxStaleMarksPerBlock::set(&2);
+        for user in [2, 3, 4] {
+            assert_ok!(CreditScore::calculate_score(RuntimeOrigin::signed(user), sample_factors()));
+        }
+
+        // Até o bloco 99 o cursor apenas acompanha os blocos vazios
+        for block in 1..100 {
+            CreditScore::on_initialize(block);
+        }
+        assert_eq!(CreditScore::staleness_cursor(), 100);
+
+        // No bloco 100 apenas dois scores são marcados; o terceiro fica para o seguinte
+        CreditScore::on_initialize(100);
+        let stale = [2, 3, 4].iter().filter(|user| CreditScore::credit_score(*user).unwrap().is_stale).count();
+        assert_eq!(stale, 2);
+        assert_eq!(CreditScore::staleness_cursor(), 100);
+
+        CreditScore::on_initialize(101);
+        assert!([2, 3, 4].iter().all(|user| CreditScore::credit_score(user).unwrap().is_stale));
+        assert_eq!(CreditScore::staleness_cursor(), 102);
+    });
+}
@@ -0,0 +1,360 @@
+//! Pesos do pallet de credit score
+//!
+//! Os acessos a storage refletem o pior caso de cada benchmark em `benchmarking.rs`.
+//! Os tempos de execução são estimativas iniciais e devem ser substituídos pela saída
+//! do comando abaixo, executado em hardware de referência, sempre que uma extrinsic
+//! ou seu benchmark mudar:
+//!
+//! ```text
+//! ./target/release/credchain-node benchmark pallet \
+//!     --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet_credit_score --extrinsic='*' \
+//!     --wasm-execution=compiled --heap-pages=4096 \
+//!     --output=pallets/pallet-credit-score/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Pesos das extrinsics do pallet
+pub trait WeightInfo {
+    fn calculate_score(f: u32, v: u32, ) -> Weight;
+    fn update_score(f: u32, v: u32, ) -> Weight;
+    fn verify_score() -> Weight;
+    fn add_score_factor() -> Weight;
+    fn add_verifier() -> Weight;
+    fn remove_verifier(v: u32, ) -> Weight;
+    fn submit_attested_factors(f: u32, v: u32, ) -> Weight;
+    fn register_bureau() -> Weight;
+    fn remove_bureau() -> Weight;
+    fn set_attestation_mode() -> Weight;
+    fn propose_scorecard() -> Weight;
+    fn cancel_scorecard_proposal() -> Weight;
+    fn grant_consent() -> Weight;
+    fn revoke_consent() -> Weight;
+    fn pull_score(v: u32, ) -> Weight;
+    fn open_score_dispute() -> Weight;
+    fn assign_dispute_reviewer() -> Weight;
+    fn accept_score_dispute(f: u32, v: u32, ) -> Weight;
+    fn reject_score_dispute() -> Weight;
+    fn freeze_score() -> Weight;
+    fn unfreeze_score() -> Weight;
+    fn lift_freeze_temporarily() -> Weight;
+    fn force_freeze_score() -> Weight;
+    fn force_unfreeze_score() -> Weight;
+    fn set_score_bands(b: u32, ) -> Weight;
+    fn prune_expired_consent() -> Weight;
+}
+
+/// Pesos para o runtime, usando o peso de acesso ao banco do próprio runtime
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn calculate_score(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(52_000_000, 6_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn update_score(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(53_000_000, 6_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn verify_score() -> Weight {
+        Weight::from_parts(28_000_000, 4_000)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn add_score_factor() -> Weight {
+        Weight::from_parts(14_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn add_verifier() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_verifier(v: u32, ) -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(Weight::from_parts(6_000_000, 2_500).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+    }
+    fn submit_attested_factors(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(55_000_000, 7_000)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn register_bureau() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_bureau() -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_attestation_mode() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn propose_scorecard() -> Weight {
+        Weight::from_parts(24_000_000, 2_000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn cancel_scorecard_proposal() -> Weight {
+        Weight::from_parts(13_000_000, 2_000)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn grant_consent() -> Weight {
+        Weight::from_parts(18_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn revoke_consent() -> Weight {
+        Weight::from_parts(16_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn pull_score(v: u32, ) -> Weight {
+        Weight::from_parts(95_000_000, 9_000)
+            .saturating_add(T::DbWeight::get().reads(15_u64))
+            .saturating_add(T::DbWeight::get().writes(20_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn open_score_dispute() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn assign_dispute_reviewer() -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn accept_score_dispute(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(60_000_000, 7_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn reject_score_dispute() -> Weight {
+        Weight::from_parts(22_000_000, 2_000)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn freeze_score() -> Weight {
+        Weight::from_parts(15_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_score() -> Weight {
+        Weight::from_parts(17_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn lift_freeze_temporarily() -> Weight {
+        Weight::from_parts(19_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn force_freeze_score() -> Weight {
+        Weight::from_parts(19_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn force_unfreeze_score() -> Weight {
+        Weight::from_parts(17_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn set_score_bands(b: u32, ) -> Weight {
+        Weight::from_parts(11_000_000, 0)
+            .saturating_add(Weight::from_parts(350_000, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn prune_expired_consent() -> Weight {
+        Weight::from_parts(16_000_000, 1_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+/// Pesos para testes e compatibilidade, usando o banco RocksDB
+impl WeightInfo for () {
+    fn calculate_score(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(52_000_000, 6_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn update_score(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(53_000_000, 6_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn verify_score() -> Weight {
+        Weight::from_parts(28_000_000, 4_000)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn add_score_factor() -> Weight {
+        Weight::from_parts(14_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn add_verifier() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_verifier(v: u32, ) -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(Weight::from_parts(6_000_000, 2_500).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+    }
+    fn submit_attested_factors(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(55_000_000, 7_000)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn register_bureau() -> Weight {
+        Weight::from_parts(16_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_bureau() -> Weight {
+        Weight::from_parts(15_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_attestation_mode() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn propose_scorecard() -> Weight {
+        Weight::from_parts(24_000_000, 2_000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn cancel_scorecard_proposal() -> Weight {
+        Weight::from_parts(13_000_000, 2_000)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn grant_consent() -> Weight {
+        Weight::from_parts(18_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn revoke_consent() -> Weight {
+        Weight::from_parts(16_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn pull_score(v: u32, ) -> Weight {
+        Weight::from_parts(95_000_000, 9_000)
+            .saturating_add(RocksDbWeight::get().reads(15_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn open_score_dispute() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn assign_dispute_reviewer() -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn accept_score_dispute(f: u32, v: u32, ) -> Weight {
+        Weight::from_parts(60_000_000, 7_500)
+            .saturating_add(Weight::from_parts(1_100_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+    }
+    fn reject_score_dispute() -> Weight {
+        Weight::from_parts(22_000_000, 2_000)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn freeze_score() -> Weight {
+        Weight::from_parts(15_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn unfreeze_score() -> Weight {
+        Weight::from_parts(17_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn lift_freeze_temporarily() -> Weight {
+        Weight::from_parts(19_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn force_freeze_score() -> Weight {
+        Weight::from_parts(19_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn force_unfreeze_score() -> Weight {
+        Weight::from_parts(17_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_score_bands(b: u32, ) -> Weight {
+        Weight::from_parts(11_000_000, 0)
+            .saturating_add(Weight::from_parts(350_000, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn prune_expired_consent() -> Weight {
+        Weight::from_parts(16_000_000, 1_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,75 @@
+//! Benchmarks for the identity verification pallet.
+
+use super::*;
+use crate::Pallet as IdentityVerification;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::pallet_prelude::*;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Submit a pending document for `user` and return its id
+fn setup_document<T: Config>(user: &T::AccountId) -> u64 {
+    IdentityVerification::<T>::submit_document(
+        RawOrigin::Signed(user.clone()).into(),
+        DocumentType::Passport,
+        vec![0u8; 32],
+        vec![0u8; 32],
+        vec![0u8; 64],
+    )
+    .expect("below the document limit");
+    DocumentCounter::<T>::get()
+}
+
+/// Submit `count` documents for `user` and verify all of them
+fn verified_documents<T: Config>(user: &T::AccountId, count: u32) {
+    for _ in 0..count {
+        let document_id = setup_document::<T>(user);
+        IdentityVerification::<T>::verify_document(
+            RawOrigin::Signed(account("verifier", 0, SEED)).into(),
+            document_id,
+        )
+        .expect("pending document");
+    }
+}
+
+benchmarks! {
+    submit_document {
+        let d in 0 .. T::MaxDocumentsPerUser::get().saturating_sub(1);
+        let caller: T::AccountId = whitelisted_caller();
+        verified_documents::<T>(&caller, d);
+    }: _(RawOrigin::Signed(caller.clone()), DocumentType::Passport, vec![0u8; 32], vec![0u8; 32], vec![0u8; 64])
+    verify {
+        assert_eq!(DocumentCounter::<T>::get(), d as u64 + 1);
+    }
+
+    verify_document {
+        let d in 1 .. T::MaxDocumentsPerUser::get();
+        let user: T::AccountId = account("user", 0, SEED);
+        let verifier: T::AccountId = whitelisted_caller();
+        verified_documents::<T>(&user, d - 1);
+        let document_id = setup_document::<T>(&user);
+    }: _(RawOrigin::Signed(verifier), document_id)
+    verify {
+        assert!(IdentityProfiles::<T>::contains_key(&user));
+    }
+
+    reject_document {
+        let user: T::AccountId = account("user", 0, SEED);
+        let verifier: T::AccountId = whitelisted_caller();
+        let document_id = setup_document::<T>(&user);
+    }: _(RawOrigin::Signed(verifier), document_id, vec![0u8; 64])
+    verify {
+        assert!(!VerificationQueue::<T>::contains_key(document_id));
+    }
+
+    update_identity_profile {
+        let d in 0 .. T::MaxDocumentsPerUser::get();
+        let caller: T::AccountId = whitelisted_caller();
+        verified_documents::<T>(&caller, d);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(IdentityProfiles::<T>::contains_key(&caller));
+    }
+}
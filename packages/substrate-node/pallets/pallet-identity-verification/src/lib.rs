@@ -1,13 +1,30 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use crate::weights::WeightInfo;
+    use credchain_primitives::process_schedule;
+    use frame_support::{pallet_prelude::*, traits::StorageVersion};
     use frame_system::pallet_prelude::*;
+    use serde::{Deserialize, Serialize};
     use sp_std::prelude::*;
-    use sp_runtime::traits::Hash;
+    use sp_runtime::SaturatedConversion;
+
+    /// The current storage version; migrations live in `crate::migrations`
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -21,6 +38,11 @@ pub mod pallet {
         /// Identity verification timeout (in blocks)
         #[pallet::constant]
         type VerificationTimeout: Get<u32>;
+
+        /// Maximum number of pending documents expired in a single block.
+        /// Documents beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxExpirationsPerBlock: Get<u32>;
         
         /// Required verification level
         #[pallet::constant]
@@ -28,6 +50,8 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     /// Identity verification status
@@ -53,6 +77,7 @@ pub mod pallet {
 
     /// Identity document structure
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
     pub struct IdentityDocument<T: Config> {
         pub id: u64,
         pub user: T::AccountId,
@@ -68,6 +93,7 @@ pub mod pallet {
 
     /// User identity profile
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
     pub struct IdentityProfile<T: Config> {
         pub user: T::AccountId,
         pub verification_level: u8,
@@ -124,13 +150,36 @@ pub mod pallet {
         u64,
     >;
 
+    /// Storage: Pending documents by the block at which they expire
+    #[pallet::storage]
+    pub type ExpirySchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        u64,
+        (),
+    >;
+
+    /// Storage: Earliest block whose expiry schedule may still hold documents
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_cursor)]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage: Raw key of the last verification queue entry scheduled by the version 1
+    /// migration, present until every document queued before it is scheduled
+    #[pallet::storage]
+    pub type QueueMigrationCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
     /// Storage: Identity statistics
     #[pallet::storage]
     #[pallet::getter(fn identity_stats)]
     pub type IdentityStats<T: Config> = StorageValue<_, IdentityStatistics, ValueQuery>;
 
     /// Identity statistics structure
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default, Serialize, Deserialize,
+    )]
     pub struct IdentityStatistics {
         pub total_documents: u64,
         pub verified_documents: u64,
@@ -199,7 +248,7 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Submit identity document
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_document(T::MaxDocumentsPerUser::get()))]
         #[pallet::call_index(0)]
         pub fn submit_document(
             origin: OriginFor<T>,
@@ -237,7 +286,7 @@ pub mod pallet {
             UserDocuments::<T>::insert(&user, document_id, ());
 
             // Add to verification queue
-            VerificationQueue::<T>::insert(document_id, <frame_system::Pallet<T>>::block_number().saturated_into());
+            Self::enqueue_verification(document_id);
 
             // Update statistics
             let mut stats = IdentityStats::<T>::get();
@@ -255,7 +304,7 @@ pub mod pallet {
         }
 
         /// Verify document
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::verify_document(T::MaxDocumentsPerUser::get()))]
         #[pallet::call_index(1)]
         pub fn verify_document(
             origin: OriginFor<T>,
@@ -279,10 +328,10 @@ pub mod pallet {
             Documents::<T>::insert(document_id, &document);
 
             // Remove from verification queue
-            VerificationQueue::<T>::remove(document_id);
+            Self::dequeue_verification(document_id);
 
             // Update user identity profile
-            Self::update_identity_profile(&document.user)?;
+            Self::do_update_identity_profile(&document.user)?;
 
             // Update statistics
             let mut stats = IdentityStats::<T>::get();
@@ -299,7 +348,7 @@ pub mod pallet {
         }
 
         /// Reject document
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::reject_document())]
         #[pallet::call_index(2)]
         pub fn reject_document(
             origin: OriginFor<T>,
@@ -324,7 +373,7 @@ pub mod pallet {
             Documents::<T>::insert(document_id, &document);
 
             // Remove from verification queue
-            VerificationQueue::<T>::remove(document_id);
+            Self::dequeue_verification(document_id);
 
             // Update statistics
             let mut stats = IdentityStats::<T>::get();
@@ -342,39 +391,38 @@ pub mod pallet {
         }
 
         /// Update identity profile
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_identity_profile(T::MaxDocumentsPerUser::get()))]
         #[pallet::call_index(3)]
         pub fn update_identity_profile(
             origin: OriginFor<T>,
         ) -> DispatchResult {
             let user = ensure_signed(origin)?;
-            Self::update_identity_profile(&user)?;
+            Self::do_update_identity_profile(&user)?;
             Ok(())
         }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            // Process verification queue
-            Self::process_verification_queue()
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            // Schedule documents queued before storage version 1, then process verification queue
+            crate::migrations::v1::schedule_queue_step::<T>(n)
+                .saturating_add(Self::process_verification_queue(n))
         }
     }
 
     impl<T: Config> Pallet<T> {
         /// Update user identity profile
-        fn update_identity_profile(user: &T::AccountId) -> DispatchResult {
+        fn do_update_identity_profile(user: &T::AccountId) -> DispatchResult {
             let user_documents = UserDocuments::<T>::iter_prefix(user)
-                .map(|(_, doc_id)| doc_id)
+                .map(|(doc_id, ())| doc_id)
                 .collect::<Vec<_>>();
 
-            let mut verified_count = 0;
             let mut verification_level = 0u8;
 
-            for doc_id in user_documents {
+            for doc_id in user_documents.iter().copied() {
                 if let Some(document) = Documents::<T>::get(doc_id) {
                     if document.status == VerificationStatus::Verified {
-                        verified_count += 1;
                         verification_level = verification_level.max(match document.document_type {
                             DocumentType::CPF => 1,
                             DocumentType::RG => 1,
@@ -398,7 +446,7 @@ pub mod pallet {
                 user: user.clone(),
                 verification_level,
                 documents: user_documents,
-                kyc_status,
+                kyc_status: kyc_status.clone(),
                 created_at: <frame_system::Pallet<T>>::block_number().saturated_into(),
                 updated_at: <frame_system::Pallet<T>>::block_number().saturated_into(),
             };
@@ -418,76 +466,66 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Process verification queue
-        fn process_verification_queue() -> Weight {
-            let current_block = <frame_system::Pallet<T>>::block_number().saturated_into();
-            let timeout = T::VerificationTimeout::get();
+        /// Block at which a document submitted at `submitted_at` expires if still pending
+        pub(crate) fn expiry_due(submitted_at: u64) -> BlockNumberFor<T> {
+            submitted_at
+                .saturating_add(T::VerificationTimeout::get() as u64)
+                .saturating_add(1)
+                .saturated_into()
+        }
+
+        /// Queue a new document for verification, scheduling its expiry
+        fn enqueue_verification(document_id: u64) {
+            let submitted_at: u64 = <frame_system::Pallet<T>>::block_number().saturated_into();
+            VerificationQueue::<T>::insert(document_id, submitted_at);
+            ExpirySchedule::<T>::insert(Self::expiry_due(submitted_at), document_id, ());
+        }
 
-            let mut processed = 0;
-            let mut weight = 0;
+        /// Remove a document from the verification queue and its expiry schedule
+        fn dequeue_verification(document_id: u64) {
+            if let Some(submitted_at) = VerificationQueue::<T>::take(document_id) {
+                ExpirySchedule::<T>::remove(Self::expiry_due(submitted_at), document_id);
+            }
+        }
 
-            for (document_id, submitted_at) in VerificationQueue::<T>::iter() {
-                if current_block - submitted_at > timeout {
-                    // Auto-reject document after timeout
+        /// Process verification queue, expiring at most `MaxExpirationsPerBlock` documents
+        /// from the expiry schedule
+        fn process_verification_queue(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<ExpirySchedule<T>, ExpiryCursor<T>, _, _>(
+                now,
+                T::MaxExpirationsPerBlock::get(),
+                db_weight,
+                |document_id: u64| {
+                    VerificationQueue::<T>::remove(document_id);
                     if let Some(mut document) = Documents::<T>::get(document_id) {
                         if document.status == VerificationStatus::Pending {
+                            // Expire document after timeout
                             document.status = VerificationStatus::Expired;
                             Documents::<T>::insert(document_id, &document);
-                            VerificationQueue::<T>::remove(document_id);
-                            processed += 1;
                         }
                     }
-                }
-                weight += 1;
-            }
-
-            Weight::from_parts(weight, 0)
+                    db_weight.reads_writes(1, 3)
+                },
+            )
         }
     }
 
     #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
         pub document_counter: u64,
         pub identity_stats: IdentityStatistics,
-    }
-
-    #[cfg(feature = "std")]
-    impl<T: Config> Default for GenesisConfig<T> {
-        fn default() -> Self {
-            Self {
-                document_counter: 0,
-                identity_stats: IdentityStatistics::default(),
-            }
-        }
+        #[serde(skip)]
+        pub _config: PhantomData<T>,
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             DocumentCounter::<T>::put(self.document_counter);
             IdentityStats::<T>::put(&self.identity_stats);
         }
     }
-
-    pub trait WeightInfo {
-        fn submit_document() -> Weight;
-        fn verify_document() -> Weight;
-        fn reject_document() -> Weight;
-        fn update_identity_profile() -> Weight;
-    }
-
-    impl WeightInfo for () {
-        fn submit_document() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn verify_document() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn reject_document() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn update_identity_profile() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-    }
 }
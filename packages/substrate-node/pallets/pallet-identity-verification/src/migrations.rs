@@ -0,0 +1,109 @@
+//! Storage migrations of the identity verification pallet: v1 moves the verification
+//! queue to a block-indexed expiry schedule.
+
+use crate::pallet::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Version 1: block-indexed expiry schedule processed from `ExpiryCursor`.
+pub mod v1 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::vec::Vec;
+
+    /// Start the expiry schedule at the upgrade block and leave the documents queued before
+    /// it to `schedule_queue_step`, which schedules them over the following blocks.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return db_weight.reads(1);
+            }
+
+            ExpiryCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+            QueueMigrationCursor::<T>::put(Vec::<u8>::new());
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            db_weight.reads_writes(2, 3)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let queued = VerificationQueue::<T>::iter_keys().count() as u64;
+            Ok(queued.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let queued: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "storage version not updated"
+            );
+            ensure!(
+                VerificationQueue::<T>::iter_keys().count() as u64 == queued,
+                "verification queue changed during migration"
+            );
+            ensure!(
+                QueueMigrationCursor::<T>::exists(),
+                "queued documents not left to schedule"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Schedule the expiry of at most `MaxExpirationsPerBlock` documents queued before
+    /// version 1, continuing after `QueueMigrationCursor`, and drop queue entries that are
+    /// no longer pending. Overdue documents expire from the current block on.
+    ///
+    /// A queue of `q` documents takes `q / MaxExpirationsPerBlock + 1` blocks, each costing
+    /// at most two reads and one write per document. Documents queued after the upgrade are
+    /// already scheduled and are scheduled again at the same block.
+    pub fn schedule_queue_step<T: Config>(now: BlockNumberFor<T>) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let cursor = match QueueMigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return db_weight.reads(1),
+        };
+
+        let cap = T::MaxExpirationsPerBlock::get().max(1) as usize;
+        let mut entries = if cursor.is_empty() {
+            VerificationQueue::<T>::iter()
+        } else {
+            VerificationQueue::<T>::iter_from(cursor)
+        };
+        let batch: Vec<(u64, u64)> = entries.by_ref().take(cap).collect();
+        let last_key = entries.last_raw_key().to_vec();
+
+        for (document_id, submitted_at) in batch.iter().copied() {
+            let pending = Documents::<T>::get(document_id)
+                .is_some_and(|document| document.status == VerificationStatus::Pending);
+            if pending {
+                let due: BlockNumberFor<T> = Pallet::<T>::expiry_due(submitted_at).max(now);
+                ExpirySchedule::<T>::insert(due, document_id, ());
+            } else {
+                VerificationQueue::<T>::remove(document_id);
+            }
+        }
+
+        if batch.len() < cap {
+            QueueMigrationCursor::<T>::kill();
+        } else {
+            QueueMigrationCursor::<T>::put(last_key);
+        }
+
+        let processed = batch.len() as u64;
+        db_weight.reads_writes(1 + 2 * processed, 1 + processed)
+    }
+}
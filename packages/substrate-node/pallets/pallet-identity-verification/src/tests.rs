@@ -0,0 +1,131 @@
+/*!
+ * Unit Tests for Identity Verification Pallet
+ *
+ * Testes unitários para o pallet de verificação de identidade
+ */
+
+use crate::{mock::*, *};
+use frame_support::{assert_ok, traits::OnInitialize};
+
+/// Envia um CPF do usuário `user`
+fn submit_test_document(user: u64) {
+    assert_ok!(IdentityVerification::submit_document(
+        RuntimeOrigin::signed(user),
+        DocumentType::CPF,
+        b"12345678900".to_vec(),
+        b"hash".to_vec(),
+        vec![],
+    ));
+}
+
+/// Quantos dos documentos `ids` expiraram
+fn expired(ids: &[u64]) -> usize {
+    ids.iter()
+        .filter(|id| IdentityVerification::documents(*id).unwrap().status == VerificationStatus::Expired)
+        .count()
+}
+
+#[test]
+fn test_pending_documents_expire_after_timeout() {
+    new_test_ext().execute_with(|| {
+        submit_test_document(1);
+        submit_test_document(2);
+        // Documento verificado sai da fila e da agenda de expiração
+        assert_ok!(IdentityVerification::verify_document(RuntimeOrigin::signed(3), 2));
+        assert_eq!(IdentityVerification::verification_queue(2), None);
+
+        let due = IdentityVerification::expiry_due(1);
+        for block in 1..due {
+            IdentityVerification::on_initialize(block);
+        }
+        assert_eq!(IdentityVerification::documents(1).unwrap().status, VerificationStatus::Pending);
+
+        IdentityVerification::on_initialize(due);
+        assert_eq!(IdentityVerification::documents(1).unwrap().status, VerificationStatus::Expired);
+        assert_eq!(IdentityVerification::documents(2).unwrap().status, VerificationStatus::Verified);
+        assert_eq!(IdentityVerification::verification_queue(1), None);
+    });
+}
+
+#[test]
+fn test_expiry_backlog_carries_over() {
+    new_test_ext().execute_with(|| {
+        for user in 1..=3 {
+            submit_test_document(user);
+        }
+        let due = IdentityVerification::expiry_due(1);
+
+        // Até o bloco anterior ao vencimento o cursor apenas acompanha os blocos vazios
+        for block in 1..due {
+            IdentityVerification::on_initialize(block);
+        }
+        assert_eq!(IdentityVerification::expiry_cursor(), due);
+
+        // No vencimento apenas dois documentos expiram; o terceiro fica para o bloco seguinte
+        IdentityVerification::on_initialize(due);
+        assert_eq!(expired(&[1, 2, 3]), 2);
+        assert_eq!(IdentityVerification::expiry_cursor(), due);
+
+        IdentityVerification::on_initialize(due + 1);
+        assert_eq!(expired(&[1, 2, 3]), 3);
+        assert_eq!(IdentityVerification::expiry_cursor(), due + 2);
+    });
+}
+
+#[test]
+fn test_empty_blocks_use_up_expiry_cap() {
+    new_test_ext().execute_with(|| {
+        submit_test_document(1);
+        let due = IdentityVerification::expiry_due(1);
+
+        // Um cursor atrasado avança no máximo dois blocos vazios por bloco
+        IdentityVerification::on_initialize(100);
+        assert_eq!(IdentityVerification::expiry_cursor(), 2);
+        assert_eq!(expired(&[1]), 0);
+
+        let mut block = 100;
+        while IdentityVerification::expiry_cursor() <= due {
+            block += 1;
+            IdentityVerification::on_initialize(block);
+        }
+        assert_eq!(expired(&[1]), 1);
+    });
+}
+
+#[test]
+fn test_migration_v0_to_v1_schedules_queue_over_blocks() {
+    use crate::migrations::v1::MigrateToV1;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // Estado da versão 0: fila sem agenda de expiração
+        for user in 1..=4 {
+            submit_test_document(user);
+        }
+        assert_ok!(IdentityVerification::verify_document(RuntimeOrigin::signed(3), 4));
+        VerificationQueue::<Test>::insert(4, 1);
+        let _ = ExpirySchedule::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<IdentityVerification>();
+
+        // A migração só inicia os cursores; a fila é agendada nos blocos seguintes
+        System::set_block_number(20);
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(IdentityVerification::on_chain_storage_version(), 1);
+        assert_eq!(IdentityVerification::expiry_cursor(), 20);
+        assert_eq!(ExpirySchedule::<Test>::iter().count(), 0);
+
+        // Duas entradas da fila por bloco: vencidos expiram no bloco em que são agendados e
+        // documentos que não estão pendentes saem da fila
+        IdentityVerification::on_initialize(20);
+        assert!(QueueMigrationCursor::<Test>::exists());
+        let dropped = IdentityVerification::verification_queue(4).is_none() as usize;
+        assert_eq!(expired(&[1, 2, 3]) + dropped, 2);
+
+        IdentityVerification::on_initialize(21);
+        IdentityVerification::on_initialize(22);
+        assert!(!QueueMigrationCursor::<Test>::exists());
+        assert_eq!(expired(&[1, 2, 3]), 3);
+        assert_eq!(IdentityVerification::documents(4).unwrap().status, VerificationStatus::Verified);
+        assert_eq!(VerificationQueue::<Test>::iter().count(), 0);
+    });
+}
@@ -0,0 +1,88 @@
+//! Weights for the identity verification pallet.
+//!
+//! Storage accesses reflect the worst case of each benchmark in `benchmarking.rs`.
+//! Execution times are initial estimates and must be replaced with the output of the
+//! command below, run on reference hardware, whenever an extrinsic or its benchmark
+//! changes:
+//!
+//! ```text
+//! ./target/release/credchain-node benchmark pallet \
+//!     --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet_identity_verification --extrinsic='*' \
+//!     --wasm-execution=compiled --heap-pages=4096 \
+//!     --output=pallets/pallet-identity-verification/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for the identity verification pallet.
+pub trait WeightInfo {
+    fn submit_document(d: u32, ) -> Weight;
+    fn verify_document(d: u32, ) -> Weight;
+    fn reject_document() -> Weight;
+    fn update_identity_profile(d: u32, ) -> Weight;
+}
+
+/// Weights for the identity verification pallet, using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn submit_document(d: u32, ) -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn verify_document(d: u32, ) -> Weight {
+        Weight::from_parts(38_000_000, 4_000)
+            .saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn reject_document() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn update_identity_profile(d: u32, ) -> Weight {
+        Weight::from_parts(18_000_000, 3_000)
+            .saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+/// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn submit_document(d: u32, ) -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn verify_document(d: u32, ) -> Weight {
+        Weight::from_parts(38_000_000, 4_000)
+            .saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn reject_document() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn update_identity_profile(d: u32, ) -> Weight {
+        Weight::from_parts(18_000_000, 3_000)
+            .saturating_add(Weight::from_parts(5_200_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,129 @@
+//! Benchmarks for the oracle integration pallet.
+
+use super::*;
+use crate::Pallet as OracleIntegration;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::pallet_prelude::*;
+use frame_system::RawOrigin;
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// Register `count` data sources; only the last one provides credit scores
+fn add_data_sources<T: Config>(count: u32) -> Vec<DataSourceId> {
+    (0..count)
+        .map(|i| {
+            let source_id: DataSourceId = i.encode();
+            let data_type = if i + 1 == count {
+                DataType::CreditScore
+            } else {
+                DataType::PaymentHistory
+            };
+            OracleIntegration::<T>::add_data_source(
+                RawOrigin::Signed(account("admin", 0, SEED)).into(),
+                source_id.clone(),
+                vec![0u8; 32],
+                b"https://oracle.credchain.io/data".to_vec(),
+                data_type,
+            )
+            .expect("new data source with a valid URL");
+            source_id
+        })
+        .collect()
+}
+
+/// Create a pending oracle request and return its id
+fn create_request<T: Config>() -> RequestId {
+    OracleIntegration::<T>::create_oracle_request(
+        RawOrigin::Signed(account("requester", 0, SEED)).into(),
+        DataType::CreditScore,
+        1_000,
+    )
+    .expect("request is always accepted");
+    OracleRequests::<T>::iter_keys().next().expect("request was just created")
+}
+
+benchmarks! {
+    register_oracle {
+        let s in 0 .. T::MaxDataSources::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let data_sources = add_data_sources::<T>(s);
+    }: _(RawOrigin::Signed(caller.clone()), data_sources)
+    verify {
+        assert!(OracleData::<T>::contains_key(&caller));
+    }
+
+    add_data_source {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(
+        RawOrigin::Signed(caller),
+        b"source".to_vec(),
+        vec![0u8; 32],
+        b"https://oracle.credchain.io/data".to_vec(),
+        DataType::CreditScore
+    )
+    verify {
+        assert!(DataSources::<T>::contains_key(b"source".to_vec()));
+    }
+
+    update_external_data {
+        let s in 1 .. T::MaxDataSources::get();
+        let caller: T::AccountId = whitelisted_caller();
+        let data_sources = add_data_sources::<T>(s);
+        OracleIntegration::<T>::register_oracle(RawOrigin::Signed(caller.clone()).into(), data_sources)?;
+    }: _(RawOrigin::Signed(caller), DataType::CreditScore, b"700".to_vec(), 0)
+    verify {
+        assert!(ExternalData::<T>::contains_key(DataType::CreditScore));
+    }
+
+    create_oracle_request {
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), DataType::CreditScore, 1_000)
+    verify {
+        assert_eq!(OracleRequests::<T>::iter_keys().count(), 1);
+    }
+
+    fulfill_oracle_request {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = create_request::<T>();
+    }: _(RawOrigin::Signed(caller), request_id.clone(), vec![0u8; 64])
+    verify {
+        assert_eq!(
+            OracleRequests::<T>::get(&request_id).map(|request| request.status),
+            Some(RequestStatus::Fulfilled)
+        );
+    }
+
+    approve_attester {
+        let oracle: T::AccountId = account("oracle", 0, SEED);
+        OracleIntegration::<T>::register_oracle(RawOrigin::Signed(oracle.clone()).into(), Vec::new())?;
+        let origin = T::AttesterOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, oracle.clone())
+    verify {
+        assert!(TrustedAttesters::<T>::contains_key(&oracle));
+    }
+
+    revoke_attester {
+        let oracle: T::AccountId = account("oracle", 0, SEED);
+        OracleIntegration::<T>::register_oracle(RawOrigin::Signed(oracle.clone()).into(), Vec::new())?;
+        let origin = T::AttesterOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        OracleIntegration::<T>::approve_attester(origin.clone(), oracle.clone())?;
+    }: _<T::RuntimeOrigin>(origin, oracle.clone())
+    verify {
+        assert!(!TrustedAttesters::<T>::contains_key(&oracle));
+    }
+
+    fail_oracle_request {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = create_request::<T>();
+    }: _(RawOrigin::Signed(caller), request_id.clone(), vec![0u8; 64])
+    verify {
+        assert_eq!(
+            OracleRequests::<T>::get(&request_id).map(|request| request.status),
+            Some(RequestStatus::Failed)
+        );
+    }
+}
@@ -1,18 +1,36 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use super::*;
+    use crate::weights::WeightInfo;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Contains, StorageVersion},
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::SaturatedConversion;
     use sp_std::vec::Vec;
-    use codec::{Decode, Encode};
-    use scale_info::TypeInfo;
-    use serde::{Deserialize, Serialize};
+
+    /// The current storage version; migrations live in `crate::migrations`
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     #[pallet::pallet]
-    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
@@ -31,6 +49,9 @@ pub mod pallet {
         /// Oracle update interval in blocks
         #[pallet::constant]
         type UpdateInterval: Get<u32>;
+
+        /// Origin that approves and revokes trusted attesters
+        type AttesterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::storage]
@@ -73,9 +94,21 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Oracles approved by governance to attest data for other pallets, with the
+    /// block of approval. Registering an oracle does not make it an attester.
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_attesters)]
+    pub type TrustedAttesters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn last_update)]
-    pub type LastUpdate<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+    pub type LastUpdate<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -109,6 +142,10 @@ pub mod pallet {
             oracle: T::AccountId, 
             reason: Vec<u8>,
         },
+        /// Oracle approved as a trusted attester
+        AttesterApproved { oracle: T::AccountId },
+        /// Oracle no longer a trusted attester
+        AttesterRevoked { oracle: T::AccountId },
     }
 
     #[pallet::error]
@@ -135,12 +172,17 @@ pub mod pallet {
         InvalidDataFormat,
         /// Update interval not reached
         UpdateIntervalNotReached,
+        /// Oracle is already a trusted attester
+        AlreadyAttester,
+        /// Oracle is not a trusted attester
+        NotAttester,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a new oracle
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_oracle(T::MaxDataSources::get()))]
         pub fn register_oracle(
             origin: OriginFor<T>,
             data_sources: Vec<DataSourceId>,
@@ -167,7 +209,7 @@ pub mod pallet {
             
             let oracle_info = OracleInfo {
                 account: oracle.clone(),
-                data_sources,
+                data_sources: data_sources.clone(),
                 is_active: true,
                 last_update: 0,
                 reputation: 100,
@@ -177,14 +219,15 @@ pub mod pallet {
             
             Self::deposit_event(Event::OracleRegistered {
                 oracle,
-                data_sources: data_sources.clone(),
+                data_sources,
             });
             
             Ok(())
         }
 
         /// Add a new data source
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::add_data_source())]
         pub fn add_data_source(
             origin: OriginFor<T>,
             source_id: DataSourceId,
@@ -207,8 +250,8 @@ pub mod pallet {
             
             let data_source = DataSourceInfo {
                 id: source_id.clone(),
-                name,
-                url,
+                name: name.clone(),
+                url: url.clone(),
                 data_type,
                 is_active: true,
                 last_update: 0,
@@ -218,15 +261,16 @@ pub mod pallet {
             
             Self::deposit_event(Event::DataSourceAdded {
                 source_id,
-                name: name.clone(),
-                url: url.clone(),
+                name,
+                url,
             });
             
             Ok(())
         }
 
         /// Update external data
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::update_external_data(T::MaxDataSources::get()))]
         pub fn update_external_data(
             origin: OriginFor<T>,
             data_type: DataType,
@@ -263,10 +307,10 @@ pub mod pallet {
                 oracle: oracle.clone(),
                 value: value.clone(),
                 timestamp,
-                block_number: frame_system::Pallet::<T>::block_number(),
+                block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
             };
             
-            ExternalData::<T>::insert(&data_type, external_data);
+            ExternalData::<T>::insert(data_type, external_data);
             
             Self::deposit_event(Event::ExternalDataUpdated {
                 data_type,
@@ -279,7 +323,8 @@ pub mod pallet {
         }
 
         /// Create an oracle request
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::create_oracle_request())]
         pub fn create_oracle_request(
             origin: OriginFor<T>,
             data_type: DataType,
@@ -290,12 +335,12 @@ pub mod pallet {
             let request_id = Self::generate_request_id();
             
             let request = OracleRequest {
-                id: request_id,
+                id: request_id.clone(),
                 requester: requester.clone(),
                 data_type,
                 max_fee,
                 status: RequestStatus::Pending,
-                created_at: frame_system::Pallet::<T>::block_number(),
+                created_at: frame_system::Pallet::<T>::block_number().saturated_into(),
                 fulfilled_at: None,
                 oracle: None,
                 data: None,
@@ -314,7 +359,8 @@ pub mod pallet {
         }
 
         /// Fulfill an oracle request
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::fulfill_oracle_request())]
         pub fn fulfill_oracle_request(
             origin: OriginFor<T>,
             request_id: RequestId,
@@ -332,7 +378,7 @@ pub mod pallet {
             
             // Update request
             request.status = RequestStatus::Fulfilled;
-            request.fulfilled_at = Some(frame_system::Pallet::<T>::block_number());
+            request.fulfilled_at = Some(frame_system::Pallet::<T>::block_number().saturated_into());
             request.oracle = Some(oracle.clone());
             request.data = Some(data.clone());
             
@@ -348,7 +394,8 @@ pub mod pallet {
         }
 
         /// Fail an oracle request
-        #[pallet::weight(10_000)]
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::fail_oracle_request())]
         pub fn fail_oracle_request(
             origin: OriginFor<T>,
             request_id: RequestId,
@@ -366,7 +413,7 @@ pub mod pallet {
             
             // Update request
             request.status = RequestStatus::Failed;
-            request.fulfilled_at = Some(frame_system::Pallet::<T>::block_number());
+            request.fulfilled_at = Some(frame_system::Pallet::<T>::block_number().saturated_into());
             request.oracle = Some(oracle.clone());
             request.error = Some(reason.clone());
             
@@ -380,6 +427,42 @@ pub mod pallet {
             
             Ok(())
         }
+
+        /// Approve a registered, active oracle as a trusted attester
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::approve_attester())]
+        pub fn approve_attester(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+            T::AttesterOrigin::ensure_origin(origin)?;
+
+            let oracle_info = OracleData::<T>::get(&oracle).ok_or(Error::<T>::OracleNotFound)?;
+            ensure!(oracle_info.is_active, Error::<T>::OracleNotFound);
+            ensure!(
+                !TrustedAttesters::<T>::contains_key(&oracle),
+                Error::<T>::AlreadyAttester,
+            );
+
+            TrustedAttesters::<T>::insert(&oracle, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::AttesterApproved { oracle });
+
+            Ok(())
+        }
+
+        /// Revoke a trusted attester; the oracle stays registered
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::revoke_attester())]
+        pub fn revoke_attester(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+            T::AttesterOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                TrustedAttesters::<T>::take(&oracle).is_some(),
+                Error::<T>::NotAttester,
+            );
+
+            Self::deposit_event(Event::AttesterRevoked { oracle });
+
+            Ok(())
+        }
     }
 
     // Helper functions
@@ -411,11 +494,11 @@ pub mod pallet {
                 },
                 DataType::IdentityVerification => {
                     // Validate identity verification data
-                    value.len() > 0
+                    !value.is_empty()
                 },
                 DataType::ExternalData => {
                     // Generic external data validation
-                    value.len() > 0
+                    !value.is_empty()
                 },
             }
         }
@@ -426,12 +509,26 @@ pub mod pallet {
             let extrinsic_index = frame_system::Pallet::<T>::extrinsic_index()
                 .unwrap_or(0);
             
-            (block_number, extrinsic_index).encode().into()
+            (block_number, extrinsic_index).encode()
+        }
+    }
+
+    /// Active oracles approved through `AttesterOrigin` are trusted data attesters
+    /// for other pallets
+    impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+        fn contains(who: &T::AccountId) -> bool {
+            TrustedAttesters::<T>::contains_key(who)
+                && OracleData::<T>::get(who).is_some_and(|oracle| oracle.is_active)
         }
     }
 }
 
 // Types
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Serialize, Deserialize)]
 pub struct OracleInfo<AccountId> {
     pub account: AccountId,
@@ -474,7 +571,7 @@ pub struct OracleRequest<AccountId> {
     pub error: Option<Vec<u8>>,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Serialize, Deserialize)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, Serialize, Deserialize)]
 pub enum DataType {
     CreditScore,
     PaymentHistory,
@@ -491,39 +588,3 @@ pub enum RequestStatus {
 
 pub type DataSourceId = Vec<u8>;
 pub type RequestId = Vec<u8>;
-
-// Weight information
-pub trait WeightInfo {
-    fn register_oracle() -> Weight;
-    fn add_data_source() -> Weight;
-    fn update_external_data() -> Weight;
-    fn create_oracle_request() -> Weight;
-    fn fulfill_oracle_request() -> Weight;
-    fn fail_oracle_request() -> Weight;
-}
-
-impl WeightInfo for () {
-    fn register_oracle() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-    
-    fn add_data_source() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-    
-    fn update_external_data() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-    
-    fn create_oracle_request() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-    
-    fn fulfill_oracle_request() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-    
-    fn fail_oracle_request() -> Weight {
-        Weight::from_ref_time(10_000)
-    }
-}
@@ -0,0 +1,77 @@
+//! Storage migrations of the oracle integration pallet: v1 introduces `TrustedAttesters`.
+
+use crate::pallet::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Version 1: attesters approved by governance in `TrustedAttesters`.
+pub mod v1 {
+    use super::*;
+
+    /// Trust every oracle that is active at the upgrade, as version 0 did, approved at the
+    /// upgrade block. Oracles registered afterwards need governance approval.
+    ///
+    /// Reads every `OracleData` entry in one block; the weight is one read and at most one
+    /// write per registered oracle.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads = 2u64;
+            let mut writes = 1u64;
+            for (account, oracle) in OracleData::<T>::iter() {
+                reads += 1;
+                if oracle.is_active {
+                    TrustedAttesters::<T>::insert(account, now);
+                    writes += 1;
+                }
+            }
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let active = OracleData::<T>::iter_values().filter(|oracle| oracle.is_active).count() as u64;
+            Ok(active.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let active: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 1,
+                "storage version not updated"
+            );
+            ensure!(
+                TrustedAttesters::<T>::iter_keys().count() as u64 >= active,
+                "active oracles not trusted"
+            );
+            ensure!(
+                TrustedAttesters::<T>::iter_keys()
+                    .all(|account| OracleData::<T>::get(&account).is_some_and(|oracle| oracle.is_active)),
+                "attester without an active oracle"
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,198 @@
+/*!
+ * Unit Tests for Oracle Integration Pallet
+ *
+ * Testes unitários para o pallet de integração com oráculos
+ */
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+
+/// Registra a fonte `source_id` do tipo `data_type` com uma URL válida
+fn add_source(source_id: &[u8], data_type: DataType) {
+    assert_ok!(OracleIntegration::add_data_source(
+        RuntimeOrigin::signed(1),
+        source_id.to_vec(),
+        b"bureau".to_vec(),
+        b"https://oracle.credchain.io/data".to_vec(),
+        data_type
+    ));
+}
+
+#[test]
+fn test_add_data_source_validates_url() {
+    new_test_ext().execute_with(|| {
+        add_source(b"score", DataType::CreditScore);
+        assert_eq!(OracleIntegration::data_sources(b"score".to_vec()).unwrap().data_type, DataType::CreditScore);
+
+        // URL sem esquema http(s)
+        assert_noop!(
+            OracleIntegration::add_data_source(
+                RuntimeOrigin::signed(1),
+                b"ftp".to_vec(),
+                b"bureau".to_vec(),
+                b"ftp://oracle.credchain.io".to_vec(),
+                DataType::CreditScore
+            ),
+            Error::<Test>::InvalidDataSourceUrl
+        );
+    });
+}
+
+#[test]
+fn test_register_oracle() {
+    new_test_ext().execute_with(|| {
+        // Fonte inexistente
+        assert_noop!(
+            OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![b"score".to_vec()]),
+            Error::<Test>::DataSourceNotFound
+        );
+
+        add_source(b"score", DataType::CreditScore);
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![b"score".to_vec()]));
+        assert!(OracleIntegration::oracle_data(100).unwrap().is_active);
+
+        // Registro duplicado
+        assert_noop!(
+            OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![]),
+            Error::<Test>::OracleAlreadyExists
+        );
+
+        // Fontes além de `MaxDataSources`
+        assert_noop!(
+            OracleIntegration::register_oracle(RuntimeOrigin::signed(101), vec![b"score".to_vec(); 4]),
+            Error::<Test>::DataSourceLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn test_update_external_data_requires_matching_source() {
+    new_test_ext().execute_with(|| {
+        add_source(b"score", DataType::CreditScore);
+        add_source(b"history", DataType::PaymentHistory);
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![b"history".to_vec()]));
+
+        // Oráculo sem fonte do tipo
+        assert_noop!(
+            OracleIntegration::update_external_data(RuntimeOrigin::signed(100), DataType::CreditScore, b"700".to_vec(), 0),
+            Error::<Test>::InsufficientPermissions
+        );
+
+        // Conta não registrada
+        assert_noop!(
+            OracleIntegration::update_external_data(RuntimeOrigin::signed(5), DataType::PaymentHistory, b"{}".to_vec(), 0),
+            Error::<Test>::OracleNotFound
+        );
+
+        assert_ok!(OracleIntegration::update_external_data(
+            RuntimeOrigin::signed(100),
+            DataType::PaymentHistory,
+            b"{}".to_vec(),
+            42
+        ));
+        let data = OracleIntegration::external_data(DataType::PaymentHistory).unwrap();
+        assert_eq!(data.oracle, 100);
+        assert_eq!(data.timestamp, 42);
+        assert_eq!(data.block_number, 1);
+    });
+}
+
+#[test]
+fn test_credit_score_data_must_be_in_range() {
+    new_test_ext().execute_with(|| {
+        add_source(b"score", DataType::CreditScore);
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![b"score".to_vec()]));
+
+        assert_noop!(
+            OracleIntegration::update_external_data(RuntimeOrigin::signed(100), DataType::CreditScore, b"1001".to_vec(), 0),
+            Error::<Test>::InvalidDataFormat
+        );
+        assert_ok!(OracleIntegration::update_external_data(
+            RuntimeOrigin::signed(100),
+            DataType::CreditScore,
+            b"1000".to_vec(),
+            0
+        ));
+    });
+}
+
+#[test]
+fn test_oracle_request_lifecycle() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(OracleIntegration::create_oracle_request(RuntimeOrigin::signed(2), DataType::CreditScore, 1_000));
+        let request_id = OracleRequests::<Test>::iter_keys().next().unwrap();
+
+        assert_ok!(OracleIntegration::fulfill_oracle_request(
+            RuntimeOrigin::signed(100),
+            request_id.clone(),
+            b"700".to_vec()
+        ));
+        let request = OracleIntegration::oracle_requests(&request_id).unwrap();
+        assert_eq!(request.status, RequestStatus::Fulfilled);
+        assert_eq!(request.oracle, Some(100));
+
+        // Requisição já atendida não pode falhar depois
+        assert_noop!(
+            OracleIntegration::fail_oracle_request(RuntimeOrigin::signed(100), request_id, b"timeout".to_vec()),
+            Error::<Test>::RequestAlreadyFulfilled
+        );
+    });
+}
+
+#[test]
+fn test_self_registered_oracle_cannot_attest() {
+    new_test_ext().execute_with(|| {
+        // Qualquer conta pode se registrar como oráculo, mesmo sem fontes
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![]));
+        assert!(!<OracleIntegration as Contains<u64>>::contains(&100));
+
+        // Somente a governança aprova atestadores
+        assert_noop!(
+            OracleIntegration::approve_attester(RuntimeOrigin::signed(100), 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            OracleIntegration::approve_attester(RuntimeOrigin::root(), 5),
+            Error::<Test>::OracleNotFound
+        );
+
+        assert_ok!(OracleIntegration::approve_attester(RuntimeOrigin::root(), 100));
+        assert!(<OracleIntegration as Contains<u64>>::contains(&100));
+        System::assert_last_event(Event::AttesterApproved { oracle: 100 }.into());
+
+        assert_ok!(OracleIntegration::revoke_attester(RuntimeOrigin::root(), 100));
+        assert!(!<OracleIntegration as Contains<u64>>::contains(&100));
+        assert!(OracleIntegration::oracle_data(100).is_some());
+        assert_noop!(
+            OracleIntegration::revoke_attester(RuntimeOrigin::root(), 100),
+            Error::<Test>::NotAttester
+        );
+    });
+}
+
+#[test]
+fn test_migration_v0_to_v1_trusts_active_oracles() {
+    use crate::migrations::v1::MigrateToV1;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(0).put::<OracleIntegration>();
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(100), vec![]));
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(101), vec![]));
+        OracleData::<Test>::mutate(101, |oracle| oracle.as_mut().unwrap().is_active = false);
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        // Oráculos ativos continuam atestando como na versão 0; inativos não
+        assert_eq!(OracleIntegration::on_chain_storage_version(), 1);
+        assert_eq!(OracleIntegration::trusted_attesters(100), Some(7));
+        assert!(<OracleIntegration as Contains<u64>>::contains(&100));
+        assert_eq!(OracleIntegration::trusted_attesters(101), None);
+
+        // Oráculos registrados depois da migração dependem da aprovação da governança
+        assert_ok!(OracleIntegration::register_oracle(RuntimeOrigin::signed(102), vec![]));
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert!(!<OracleIntegration as Contains<u64>>::contains(&102));
+    });
+}
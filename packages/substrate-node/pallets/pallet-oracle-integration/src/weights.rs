@@ -0,0 +1,128 @@
+//! Weights for the oracle integration pallet.
+//!
+//! Storage accesses reflect the worst case of each benchmark in `benchmarking.rs`.
+//! Execution times are initial estimates and must be replaced with the output of the
+//! command below, run on reference hardware, whenever an extrinsic or its benchmark
+//! changes:
+//!
+//! ```text
+//! ./target/release/credchain-node benchmark pallet \
+//!     --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet_oracle_integration --extrinsic='*' \
+//!     --wasm-execution=compiled --heap-pages=4096 \
+//!     --output=pallets/pallet-oracle-integration/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for the oracle integration pallet.
+pub trait WeightInfo {
+    fn register_oracle(s: u32, ) -> Weight;
+    fn add_data_source() -> Weight;
+    fn update_external_data(s: u32, ) -> Weight;
+    fn create_oracle_request() -> Weight;
+    fn fulfill_oracle_request() -> Weight;
+    fn fail_oracle_request() -> Weight;
+    fn approve_attester() -> Weight;
+    fn revoke_attester() -> Weight;
+}
+
+/// Weights for the oracle integration pallet, using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn register_oracle(s: u32, ) -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(Weight::from_parts(2_700_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn add_data_source() -> Weight {
+        Weight::from_parts(18_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn update_external_data(s: u32, ) -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_700_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn create_oracle_request() -> Weight {
+        Weight::from_parts(17_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn fulfill_oracle_request() -> Weight {
+        Weight::from_parts(19_000_000, 3_000)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn fail_oracle_request() -> Weight {
+        Weight::from_parts(19_000_000, 3_000)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn approve_attester() -> Weight {
+        Weight::from_parts(16_000_000, 3_000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn revoke_attester() -> Weight {
+        Weight::from_parts(14_000_000, 2_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+/// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn register_oracle(s: u32, ) -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(Weight::from_parts(2_700_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn add_data_source() -> Weight {
+        Weight::from_parts(18_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn update_external_data(s: u32, ) -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_700_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn create_oracle_request() -> Weight {
+        Weight::from_parts(17_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn fulfill_oracle_request() -> Weight {
+        Weight::from_parts(19_000_000, 3_000)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn fail_oracle_request() -> Weight {
+        Weight::from_parts(19_000_000, 3_000)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn approve_attester() -> Weight {
+        Weight::from_parts(16_000_000, 3_000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn revoke_attester() -> Weight {
+        Weight::from_parts(14_000_000, 2_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,435 @@
+//! Benchmarks for the payment registry pallet.
+
+use super::*;
+use crate::Pallet as PaymentRegistry;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller, BenchmarkError};
+use frame_support::{
+    pallet_prelude::*,
+    traits::fungible::{Inspect, Mutate},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+    traits::{Hash, Saturating},
+    SaturatedConversion,
+};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Keystore key type of the processor keys generated for receipts
+const RECEIPT_KEY_TYPE: KeyTypeId = KeyTypeId(*b"rcpt");
+
+/// Give `who` enough free balance to escrow many minimum payments
+fn fund<T: Config>(who: &T::AccountId) {
+    let amount: BalanceOf<T> = T::MinPaymentAmount::get().saturating_mul(1_000).saturated_into();
+    T::Currency::set_balance(who, T::Currency::minimum_balance().saturating_add(amount));
+}
+
+/// Register `who` as a payment verifier
+fn register_verifier<T: Config>(who: &T::AccountId) {
+    PaymentVerifiers::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+}
+
+/// Register a new sr25519 processor key and sign a receipt matching `payment_id` with it
+fn signed_receipt<T: Config>(payment_id: u64) -> (PaymentReceipt<T::AccountId>, ReceiptSignature) {
+    let public = sp_io::crypto::sr25519_generate(RECEIPT_KEY_TYPE, None);
+    let processor = ProcessorKey::Sr25519(public);
+    PaymentProcessors::<T>::insert(&processor, frame_system::Pallet::<T>::block_number());
+
+    let payment = Payments::<T>::get(payment_id).expect("existing payment");
+    let receipt = PaymentReceipt {
+        payment_id,
+        processor,
+        payer: payment.payer,
+        payee: payment.payee,
+        amount: payment.amount,
+        currency: payment.currency,
+        transaction_hash: vec![0u8; 32],
+        timestamp: 0,
+    };
+    let signature = sp_io::crypto::sr25519_sign(RECEIPT_KEY_TYPE, &public, &receipt.signing_payload())
+        .expect("key generated in the keystore");
+    (receipt, ReceiptSignature::Sr25519(signature))
+}
+
+/// Accredit `lender` and register a loan with `installments` installments to `borrower`,
+/// accepted by the borrower
+fn setup_loan<T: Config>(lender: &T::AccountId, borrower: &T::AccountId, installments: u32) -> u64 {
+    Lenders::<T>::insert(lender, frame_system::Pallet::<T>::block_number());
+    PaymentRegistry::<T>::register_loan(
+        RawOrigin::Signed(lender.clone()).into(),
+        borrower.clone(),
+        T::MinPaymentAmount::get().saturating_mul(installments.into()),
+        b"BRL".to_vec(),
+        T::MinPaymentAmount::get(),
+        installments,
+        frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+        10u32.into(),
+    )
+    .expect("valid loan schedule");
+    let loan_id = LoanCounter::<T>::get();
+    PaymentRegistry::<T>::accept_loan(RawOrigin::Signed(borrower.clone()).into(), loan_id)
+        .expect("proposed loan");
+    loan_id
+}
+
+/// Register a monthly rent owed by `payer` to `creditor`, accepted by the payer
+fn setup_obligation<T: Config>(creditor: &T::AccountId, payer: &T::AccountId) -> u64 {
+    PaymentRegistry::<T>::register_obligation(
+        RawOrigin::Signed(creditor.clone()).into(),
+        payer.clone(),
+        ObligationKind::Rent,
+        T::MinPaymentAmount::get(),
+        b"BRL".to_vec(),
+        frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+        T::MinObligationPeriod::get().max(1).into(),
+    )
+    .expect("valid obligation schedule");
+    let obligation_id = ObligationCounter::<T>::get();
+    PaymentRegistry::<T>::accept_obligation(RawOrigin::Signed(payer.clone()).into(), obligation_id)
+        .expect("proposed obligation");
+    obligation_id
+}
+
+/// Create a pending escrowed payment from `payer` and return its id
+fn setup_payment<T: Config>(payer: &T::AccountId) -> u64 {
+    fund::<T>(payer);
+    fund::<T>(&account("payee", 0, SEED));
+    PaymentRegistry::<T>::create_escrowed_payment(
+        RawOrigin::Signed(payer.clone()).into(),
+        account("payee", 0, SEED),
+        T::MinPaymentAmount::get(),
+        b"BRL".to_vec(),
+        vec![0u8; 64],
+        vec![0u8; 64],
+        Some(frame_system::Pallet::<T>::block_number().saturating_add(10u32.into())),
+    )
+    .expect("valid payment");
+    PaymentCounter::<T>::get()
+}
+
+/// Create a payment from `payer` and move it to `Verified`
+fn verified_payment<T: Config>(payer: &T::AccountId) -> u64 {
+    let payment_id = setup_payment::<T>(payer);
+    let verifier: T::AccountId = account("verifier", 0, SEED);
+    register_verifier::<T>(&verifier);
+    let (receipt, signature) = signed_receipt::<T>(payment_id);
+    PaymentRegistry::<T>::verify_payment(
+        RawOrigin::Signed(verifier).into(),
+        payment_id,
+        receipt,
+        signature,
+    )
+    .expect("pending payment");
+    payment_id
+}
+
+/// Create a payment from `payer` and move it to `Completed`
+fn completed_payment<T: Config>(payer: &T::AccountId) -> u64 {
+    let payment_id = verified_payment::<T>(payer);
+    PaymentRegistry::<T>::complete_payment(
+        RawOrigin::Signed(account("verifier", 0, SEED)).into(),
+        payment_id,
+    )
+    .expect("verified payment");
+    payment_id
+}
+
+benchmarks! {
+    create_payment {
+        let p in 0 .. T::MaxPaymentsPerUser::get().saturating_sub(1);
+        let caller: T::AccountId = whitelisted_caller();
+        for _ in 0..p {
+            setup_payment::<T>(&caller);
+        }
+        let payee: T::AccountId = account("payee", 0, SEED);
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        payee,
+        T::MinPaymentAmount::get(),
+        b"BRL".to_vec(),
+        vec![0u8; 64],
+        vec![0u8; 64],
+        Some(frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()))
+    )
+    verify {
+        assert_eq!(PaymentCounter::<T>::get(), p as u64 + 1);
+    }
+
+    create_escrowed_payment {
+        let p in 0 .. T::MaxPaymentsPerUser::get().saturating_sub(1);
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        for _ in 0..p {
+            setup_payment::<T>(&caller);
+        }
+        let payee: T::AccountId = account("payee", 0, SEED);
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        payee,
+        T::MinPaymentAmount::get(),
+        b"BRL".to_vec(),
+        vec![0u8; 64],
+        vec![0u8; 64],
+        Some(frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()))
+    )
+    verify {
+        let payment_id = PaymentCounter::<T>::get();
+        assert_eq!(
+            Payments::<T>::get(payment_id).map(|payment| payment.escrow),
+            Some(EscrowState::HeldFromPayer)
+        );
+    }
+
+    accept_due_date {
+        let payee: T::AccountId = account("payee", 0, SEED);
+        let payment_id = setup_payment::<T>(&account("payer", 0, SEED));
+    }: _(RawOrigin::Signed(payee), payment_id)
+    verify {
+        assert!(Payments::<T>::get(payment_id).and_then(|payment| payment.due_at).is_some());
+        assert!(!ProposedDueDates::<T>::contains_key(payment_id));
+    }
+
+    verify_payment {
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let verifier: T::AccountId = whitelisted_caller();
+        register_verifier::<T>(&verifier);
+        let payment_id = setup_payment::<T>(&payer);
+        let (receipt, signature) = signed_receipt::<T>(payment_id);
+    }: _(RawOrigin::Signed(verifier), payment_id, receipt, signature)
+    verify {
+        assert!(!VerificationQueue::<T>::contains_key(payment_id));
+        assert!(PaymentReceipts::<T>::contains_key(payment_id));
+    }
+
+    complete_payment {
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        register_verifier::<T>(&caller);
+        let payment_id = verified_payment::<T>(&payer);
+    }: _(RawOrigin::Signed(caller), payment_id)
+    verify {
+        assert_eq!(PayerSummaries::<T>::get(&payer).completed, 1);
+        assert_eq!(
+            Payments::<T>::get(payment_id).map(|payment| payment.escrow),
+            Some(EscrowState::Released)
+        );
+    }
+
+    fail_payment {
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let caller: T::AccountId = whitelisted_caller();
+        register_verifier::<T>(&caller);
+        let payment_id = verified_payment::<T>(&payer);
+    }: _(RawOrigin::Signed(caller), payment_id, vec![0u8; 64])
+    verify {
+        assert_eq!(PayerSummaries::<T>::get(&payer).failed, 1);
+    }
+
+    dispute_payment {
+        let payer: T::AccountId = whitelisted_caller();
+        let payment_id = completed_payment::<T>(&payer);
+    }: _(RawOrigin::Signed(payer.clone()), payment_id, vec![0u8; 64])
+    verify {
+        assert_eq!(PayerSummaries::<T>::get(&payer).disputed, 1);
+    }
+
+    resolve_dispute {
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let origin = T::ArbiterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let payment_id = completed_payment::<T>(&payer);
+        PaymentRegistry::<T>::dispute_payment(
+            RawOrigin::Signed(payer.clone()).into(),
+            payment_id,
+            vec![0u8; 64],
+        )?;
+        // A partial refund moves escrowed funds to both parties
+        let outcome = DisputeOutcome::PartialRefund { amount: T::MinPaymentAmount::get() / 2 };
+    }: _<T::RuntimeOrigin>(origin, payment_id, outcome, vec![0u8; 64])
+    verify {
+        assert_eq!(PayerSummaries::<T>::get(&payer).disputed, 0);
+        assert_eq!(PaymentStats::<T>::get().resolved_disputes, 1);
+    }
+
+    submit_dispute_evidence {
+        let payer: T::AccountId = whitelisted_caller();
+        let payment_id = completed_payment::<T>(&payer);
+        PaymentRegistry::<T>::dispute_payment(
+            RawOrigin::Signed(payer.clone()).into(),
+            payment_id,
+            vec![0u8; 64],
+        )?;
+        let evidence_hash = T::Hashing::hash(b"evidence");
+    }: _(RawOrigin::Signed(payer), payment_id, evidence_hash)
+    verify {
+        assert_eq!(
+            Disputes::<T>::get(payment_id).and_then(|dispute| dispute.payer_evidence),
+            Some(evidence_hash)
+        );
+    }
+
+    add_verifier {
+        let origin = T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+    }: _<T::RuntimeOrigin>(origin, verifier.clone())
+    verify {
+        assert!(PaymentVerifiers::<T>::contains_key(&verifier));
+    }
+
+    remove_verifier {
+        let origin = T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let verifier: T::AccountId = account("verifier", 0, SEED);
+        register_verifier::<T>(&verifier);
+    }: _<T::RuntimeOrigin>(origin, verifier.clone())
+    verify {
+        assert!(!PaymentVerifiers::<T>::contains_key(&verifier));
+    }
+
+    add_processor {
+        let origin = T::ProcessorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let key = ProcessorKey::Sr25519(sp_io::crypto::sr25519_generate(RECEIPT_KEY_TYPE, None));
+    }: _<T::RuntimeOrigin>(origin, key.clone())
+    verify {
+        assert!(PaymentProcessors::<T>::contains_key(&key));
+    }
+
+    remove_processor {
+        let origin = T::ProcessorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let key = ProcessorKey::Sr25519(sp_io::crypto::sr25519_generate(RECEIPT_KEY_TYPE, None));
+        PaymentProcessors::<T>::insert(&key, frame_system::Pallet::<T>::block_number());
+    }: _<T::RuntimeOrigin>(origin, key.clone())
+    verify {
+        assert!(!PaymentProcessors::<T>::contains_key(&key));
+    }
+
+    add_lender {
+        let origin = T::LenderOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let lender: T::AccountId = account("lender", 0, SEED);
+    }: _<T::RuntimeOrigin>(origin, lender.clone())
+    verify {
+        assert!(Lenders::<T>::contains_key(&lender));
+    }
+
+    remove_lender {
+        let origin = T::LenderOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let lender: T::AccountId = account("lender", 0, SEED);
+        Lenders::<T>::insert(&lender, frame_system::Pallet::<T>::block_number());
+    }: _<T::RuntimeOrigin>(origin, lender.clone())
+    verify {
+        assert!(!Lenders::<T>::contains_key(&lender));
+    }
+
+    register_loan {
+        let lender: T::AccountId = whitelisted_caller();
+        let borrower: T::AccountId = account("borrower", 0, SEED);
+        Lenders::<T>::insert(&lender, frame_system::Pallet::<T>::block_number());
+        let first_due = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+        let installments = T::MaxInstallments::get();
+    }: _(
+        RawOrigin::Signed(lender),
+        borrower,
+        T::MinPaymentAmount::get().saturating_mul(installments.into()),
+        b"BRL".to_vec(),
+        T::MinPaymentAmount::get(),
+        installments,
+        first_due,
+        10u32.into()
+    )
+    verify {
+        assert_eq!(Loans::<T>::get(1).map(|loan| loan.status), Some(LoanStatus::Proposed));
+    }
+
+    accept_loan {
+        let i in 1 .. T::MaxInstallments::get();
+        let lender: T::AccountId = account("lender", 0, SEED);
+        let borrower: T::AccountId = whitelisted_caller();
+        Lenders::<T>::insert(&lender, frame_system::Pallet::<T>::block_number());
+        PaymentRegistry::<T>::register_loan(
+            RawOrigin::Signed(lender).into(),
+            borrower.clone(),
+            T::MinPaymentAmount::get().saturating_mul(i.into()),
+            b"BRL".to_vec(),
+            T::MinPaymentAmount::get(),
+            i,
+            frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+            10u32.into(),
+        )
+        .expect("valid loan schedule");
+    }: _(RawOrigin::Signed(borrower), 1)
+    verify {
+        assert_eq!(Installments::<T>::iter_prefix(1).count(), i as usize);
+    }
+
+    pay_installment {
+        let p in 0 .. T::MaxPaymentsPerUser::get().saturating_sub(1);
+        let borrower: T::AccountId = whitelisted_caller();
+        for _ in 0..p {
+            setup_payment::<T>(&borrower);
+        }
+        fund::<T>(&borrower);
+        let loan_id = setup_loan::<T>(&account("lender", 0, SEED), &borrower, 1);
+    }: _(RawOrigin::Signed(borrower), loan_id, 0, true, vec![0u8; 64])
+    verify {
+        assert!(Installments::<T>::get(loan_id, 0).and_then(|installment| installment.payment_id).is_some());
+    }
+
+    register_obligation {
+        let creditor: T::AccountId = whitelisted_caller();
+        let payer: T::AccountId = account("payer", 0, SEED);
+        let first_due = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+        let period: BlockNumberFor<T> = T::MinObligationPeriod::get().max(1).into();
+    }: _(
+        RawOrigin::Signed(creditor),
+        payer,
+        ObligationKind::Utility,
+        T::MinPaymentAmount::get(),
+        b"BRL".to_vec(),
+        first_due,
+        period
+    )
+    verify {
+        assert!(Obligations::<T>::contains_key(1));
+    }
+
+    accept_obligation {
+        let payer: T::AccountId = whitelisted_caller();
+        PaymentRegistry::<T>::register_obligation(
+            RawOrigin::Signed(account("creditor", 0, SEED)).into(),
+            payer.clone(),
+            ObligationKind::Rent,
+            T::MinPaymentAmount::get(),
+            b"BRL".to_vec(),
+            frame_system::Pallet::<T>::block_number().saturating_add(10u32.into()),
+            T::MinObligationPeriod::get().max(1).into(),
+        )
+        .expect("valid obligation schedule");
+    }: _(RawOrigin::Signed(payer), 1)
+    verify {
+        assert!(Occurrences::<T>::contains_key(1, 0));
+    }
+
+    cancel_obligation {
+        let payer: T::AccountId = whitelisted_caller();
+        let obligation_id = setup_obligation::<T>(&account("creditor", 0, SEED), &payer);
+    }: _(RawOrigin::Signed(payer), obligation_id)
+    verify {
+        assert_eq!(
+            Obligations::<T>::get(obligation_id).map(|obligation| obligation.status),
+            Some(ObligationStatus::Cancelled)
+        );
+    }
+
+    pay_obligation {
+        let p in 0 .. T::MaxPaymentsPerUser::get().saturating_sub(1);
+        let payer: T::AccountId = whitelisted_caller();
+        for _ in 0..p {
+            setup_payment::<T>(&payer);
+        }
+        fund::<T>(&payer);
+        let obligation_id = setup_obligation::<T>(&account("creditor", 0, SEED), &payer);
+    }: _(RawOrigin::Signed(payer), obligation_id, 0, true, vec![0u8; 64])
+    verify {
+        assert!(Occurrences::<T>::get(obligation_id, 0).and_then(|occurrence| occurrence.payment_id).is_some());
+    }
+}
@@ -1,13 +1,50 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use crate::weights::WeightInfo;
+    use credchain_primitives::{
+        process_schedule, OnPaymentStateChange, OnTimeStreak, OnTimeStreakProvider, PaymentHistoryProvider, PaymentSummary,
+    };
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Restriction},
+            StorageVersion,
+        },
+    };
     use frame_system::pallet_prelude::*;
+    use serde::{Deserialize, Serialize};
+    use sp_core::{ed25519, sr25519};
     use sp_std::prelude::*;
-    use sp_runtime::traits::Hash;
+    use sp_runtime::{
+        traits::{One, Saturating, Zero},
+        SaturatedConversion,
+    };
+
+    /// The current storage version; migrations live in `crate::migrations`
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+
+    /// Domain tag prefixed to the SCALE encoding of a receipt before it is signed
+    pub const RECEIPT_CONTEXT: &[u8] = b"credchain:payment-receipt";
+
+    /// Balance type of the escrow currency
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -29,9 +66,93 @@ pub mod pallet {
         /// Payment verification period (in blocks)
         #[pallet::constant]
         type PaymentVerificationPeriod: Get<u32>;
+
+        /// Maximum number of queued payments expired in a single block.
+        /// Payments beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxVerificationsPerBlock: Get<u32>;
+
+        /// Hook notified when a payment changes state (e.g. pallet-credit-score)
+        type OnPaymentStateChange: OnPaymentStateChange<Self::AccountId>;
+
+        /// Currency holding the funds of escrowed payments
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Origin that registers and removes payment verifiers
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that resolves payment disputes
+        type ArbiterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin that registers and removes the keys of payment processors and banks
+        /// allowed to sign payment receipts
+        type ProcessorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Period (in blocks) after a dispute is opened during which payer and payee
+        /// may submit evidence. Disputes still open afterwards get a default judgment.
+        #[pallet::constant]
+        type DisputeEvidencePeriod: Get<u32>;
+
+        /// Maximum number of default judgments issued in a single block.
+        /// Disputes beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxDefaultJudgmentsPerBlock: Get<u32>;
+
+        /// Origin that accredits and removes lenders allowed to register loans
+        type LenderOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of installments in a loan schedule
+        #[pallet::constant]
+        type MaxInstallments: Get<u32>;
+
+        /// Maximum number of due installments checked in a single block.
+        /// Installments beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxDueInstallmentsPerBlock: Get<u32>;
+
+        /// Number of blocks in a day, used to compute days past due
+        #[pallet::constant]
+        type BlocksPerDay: Get<u32>;
+
+        /// Days past due after which a payment is in default (at least 91)
+        #[pallet::constant]
+        type DefaultAfterDays: Get<u32>;
+
+        /// Maximum number of overdue payments moved to their next delinquency bucket in a
+        /// single block. Payments beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxDelinquencyChecksPerBlock: Get<u32>;
+
+        /// Minimum period (in blocks) between occurrences of a recurring obligation
+        #[pallet::constant]
+        type MinObligationPeriod: Get<u32>;
+
+        /// Maximum number of recurring obligations moved to their next cycle in a single block.
+        /// Obligations beyond the cap are carried over to the following blocks.
+        #[pallet::constant]
+        type MaxOccurrencesPerBlock: Get<u32>;
+
+        /// Maximum number of latest resolved occurrences kept per payer to recompute the
+        /// on-time streak when one of them is reverted. Older occurrences are folded into a
+        /// fixed base streak.
+        #[pallet::constant]
+        type MaxStreakHistory: Get<u32>;
+    }
+
+    /// Reasons for the pallet to hold funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds of an escrowed payment awaiting settlement
+        PaymentEscrow,
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     /// Payment status enumeration
@@ -42,10 +163,338 @@ pub mod pallet {
         Completed,
         Failed,
         Disputed,
+        /// Dispute upheld for the payer; the payer was refunded
+        Refunded,
+        /// Dispute annulled the payment; it is treated as never made
+        Annulled,
+        /// Dispute ruled a full or partial refund to the payer, but the pallet held no funds for
+        /// the payment (never escrowed, or no longer held by the payee) and nothing was refunded
+        Unrefunded,
+    }
+
+    /// Outcome of a payment dispute
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum DisputeOutcome {
+        /// The payer's claim stands: the full amount is returned to the payer
+        UpheldForPayer,
+        /// The payee's claim stands: the payment is completed
+        UpheldForPayee,
+        /// `amount` is returned to the payer and the rest is paid to the payee
+        PartialRefund { amount: u128 },
+        /// The payment is void: the full amount is returned to the payer
+        Annulled,
+    }
+
+    /// Dispute record structure
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DisputeRecord<T: Config> {
+        pub opened_by: T::AccountId,
+        pub opened_at: BlockNumberFor<T>,
+        pub evidence_deadline: BlockNumberFor<T>,
+        pub payer_evidence: Option<T::Hash>,
+        pub payee_evidence: Option<T::Hash>,
+        pub outcome: Option<DisputeOutcome>,
+    }
+
+    /// Roles that may move a payment between statuses
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum PaymentRole {
+        /// The account that created the payment
+        Payer,
+        /// The account receiving the payment
+        Payee,
+        /// An account registered in `PaymentVerifiers`
+        Verifier,
+        /// The `ArbiterOrigin`
+        Arbiter,
+    }
+
+    /// Escrow state of a payment's funds
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum EscrowState {
+        /// The payment is only recorded; no funds are moved by the pallet
+        NotEscrowed,
+        /// Funds are held on the payer's account
+        HeldFromPayer,
+        /// Funds were released to the payee and are held there while a dispute is open
+        HeldFromPayee,
+        /// Funds were transferred to the payee
+        Released,
+        /// Funds were returned to the payer
+        Refunded,
+    }
+
+    /// Delinquency bucket by days past due (DPD)
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
+    pub enum DelinquencyBucket {
+        /// Not past due
+        Current,
+        /// 1 to 30 days past due
+        Days1To30,
+        /// 31 to 60 days past due
+        Days31To60,
+        /// 61 to 90 days past due
+        Days61To90,
+        /// More than 90 days past due, not yet in default
+        Over90,
+        /// At least `DefaultAfterDays` days past due
+        Defaulted,
+    }
+
+    impl DelinquencyBucket {
+        /// Bucket for `days` past due, where `default_after` days past due is a default
+        pub fn from_days(days: u32, default_after: u32) -> Self {
+            match days {
+                0 => Self::Current,
+                d if d >= default_after.max(91) => Self::Defaulted,
+                1..=30 => Self::Days1To30,
+                31..=60 => Self::Days31To60,
+                61..=90 => Self::Days61To90,
+                _ => Self::Over90,
+            }
+        }
+
+        /// Fewest days past due of the following bucket, if there is one
+        pub fn next_threshold(&self, default_after: u32) -> Option<u32> {
+            match self {
+                Self::Current => Some(1),
+                Self::Days1To30 => Some(31),
+                Self::Days31To60 => Some(61),
+                Self::Days61To90 => Some(91),
+                Self::Over90 => Some(default_after.max(91)),
+                Self::Defaulted => None,
+            }
+        }
+    }
+
+    /// Number of a payer's payments with a due date in each delinquency bucket
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct DelinquencyCounts {
+        pub current: u32,
+        pub days_1_to_30: u32,
+        pub days_31_to_60: u32,
+        pub days_61_to_90: u32,
+        pub over_90: u32,
+        pub defaulted: u32,
+    }
+
+    impl DelinquencyCounts {
+        fn count_mut(&mut self, bucket: DelinquencyBucket) -> &mut u32 {
+            match bucket {
+                DelinquencyBucket::Current => &mut self.current,
+                DelinquencyBucket::Days1To30 => &mut self.days_1_to_30,
+                DelinquencyBucket::Days31To60 => &mut self.days_31_to_60,
+                DelinquencyBucket::Days61To90 => &mut self.days_61_to_90,
+                DelinquencyBucket::Over90 => &mut self.over_90,
+                DelinquencyBucket::Defaulted => &mut self.defaulted,
+            }
+        }
+
+        /// Worst bucket holding at least one payment
+        pub fn worst(&self) -> Option<DelinquencyBucket> {
+            [
+                (self.defaulted, DelinquencyBucket::Defaulted),
+                (self.over_90, DelinquencyBucket::Over90),
+                (self.days_61_to_90, DelinquencyBucket::Days61To90),
+                (self.days_31_to_60, DelinquencyBucket::Days31To60),
+                (self.days_1_to_30, DelinquencyBucket::Days1To30),
+                (self.current, DelinquencyBucket::Current),
+            ]
+            .into_iter()
+            .find(|(count, _)| *count > 0)
+            .map(|(_, bucket)| bucket)
+        }
+    }
+
+    /// Loan status enumeration
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum LoanStatus {
+        /// Installments are still outstanding
+        Active,
+        /// Every installment has been paid
+        Repaid,
+        /// Registered by the lender, awaiting the borrower's acceptance
+        Proposed,
+    }
+
+    /// Loan record structure
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LoanRecord<T: Config> {
+        pub id: u64,
+        pub lender: T::AccountId,
+        pub borrower: T::AccountId,
+        pub principal: u128,
+        pub currency: Vec<u8>,
+        pub installment_amount: u128,
+        pub installment_count: u32,
+        pub first_due: BlockNumberFor<T>,
+        pub interval: BlockNumberFor<T>,
+        pub created_at: BlockNumberFor<T>,
+        pub status: LoanStatus,
+        pub paid_on_time: u32,
+        pub paid_late: u32,
+        /// Installments past their due block and still unpaid
+        pub missed: u32,
+    }
+
+    /// Installment status enumeration
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum InstallmentStatus {
+        /// Not paid yet and not past its due block
+        Scheduled,
+        /// Paid by a payment completed on or before the due block
+        PaidOnTime,
+        /// Paid by a payment completed after the due block
+        PaidLate,
+        /// Past its due block without a completed payment
+        Missed,
+    }
+
+    /// Installment structure
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Installment<T: Config> {
+        pub amount: u128,
+        pub due: BlockNumberFor<T>,
+        pub status: InstallmentStatus,
+        /// Payment currently paying this installment, if any
+        pub payment_id: Option<u64>,
+    }
+
+    /// Kind of recurring bill
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ObligationKind {
+        Rent,
+        Utility,
+        Telecom,
+        Other,
+    }
+
+    /// Recurring obligation status enumeration
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ObligationStatus {
+        /// Registered by the creditor, awaiting the payer's acceptance
+        Proposed,
+        /// Accepted; a new occurrence is created every period
+        Active,
+        /// Cancelled by either party; no further occurrences are created
+        Cancelled,
+    }
+
+    /// Recurring obligation record structure
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RecurringObligation<T: Config> {
+        pub id: u64,
+        pub creditor: T::AccountId,
+        pub payer: T::AccountId,
+        pub kind: ObligationKind,
+        pub amount: u128,
+        pub currency: Vec<u8>,
+        pub period: BlockNumberFor<T>,
+        pub first_due: BlockNumberFor<T>,
+        pub created_at: BlockNumberFor<T>,
+        pub status: ObligationStatus,
+        /// Occurrences created so far; the latest has index `cycles - 1`
+        pub cycles: u32,
+        pub paid_on_time: u32,
+        pub paid_late: u32,
+        /// Occurrences past their due block and still unpaid
+        pub missed: u32,
+    }
+
+    /// Occurrence of a recurring obligation for one cycle; it goes through the same
+    /// statuses as a loan installment
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Occurrence<T: Config> {
+        pub due: BlockNumberFor<T>,
+        pub status: InstallmentStatus,
+        /// Payment currently paying this occurrence, if any
+        pub payment_id: Option<u64>,
+    }
+
+    /// Occurrence counted in a payer's on-time streak
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct StreakEntry<T: Config> {
+        pub due: BlockNumberFor<T>,
+        pub obligation_id: u64,
+        pub cycle: u32,
+        pub on_time: bool,
+    }
+
+    /// Latest resolved occurrences of a payer, from which the on-time streak is recomputed
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct StreakHistory<T: Config> {
+        /// Streak over the occurrences resolved before those in `recent`
+        pub base: OnTimeStreak,
+        /// At most `MaxStreakHistory` latest resolved occurrences, in due order
+        pub recent: Vec<StreakEntry<T>>,
+    }
+
+    /// Public key of a payment processor or bank signing payment receipts
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ProcessorKey {
+        Sr25519(sr25519::Public),
+        Ed25519(ed25519::Public),
+    }
+
+    impl ProcessorKey {
+        /// Whether `signature` is a signature of `message` by this key
+        pub fn verify(&self, message: &[u8], signature: &ReceiptSignature) -> bool {
+            match (self, signature) {
+                (ProcessorKey::Sr25519(key), ReceiptSignature::Sr25519(signature)) => {
+                    sp_io::crypto::sr25519_verify(signature, message, key)
+                }
+                (ProcessorKey::Ed25519(key), ReceiptSignature::Ed25519(signature)) => {
+                    sp_io::crypto::ed25519_verify(signature, message, key)
+                }
+                _ => false,
+            }
+        }
+    }
+
+    /// Signature of a payment receipt
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ReceiptSignature {
+        Sr25519(sr25519::Signature),
+        Ed25519(ed25519::Signature),
+    }
+
+    /// Receipt issued by a payment processor or bank for a settled payment
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PaymentReceipt<AccountId> {
+        /// Payment the receipt attests to
+        pub payment_id: u64,
+        /// Key that signed the receipt
+        pub processor: ProcessorKey,
+        pub payer: AccountId,
+        pub payee: AccountId,
+        pub amount: u128,
+        pub currency: Vec<u8>,
+        /// Transaction reference at the processor
+        pub transaction_hash: Vec<u8>,
+        /// Settlement time reported by the processor (Unix milliseconds)
+        pub timestamp: u64,
+    }
+
+    impl<AccountId: Encode> PaymentReceipt<AccountId> {
+        /// Message signed by the processor: `RECEIPT_CONTEXT` followed by the receipt's
+        /// SCALE encoding
+        pub fn signing_payload(&self) -> Vec<u8> {
+            let mut payload = RECEIPT_CONTEXT.to_vec();
+            self.encode_to(&mut payload);
+            payload
+        }
     }
 
     /// Payment record structure
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
     pub struct PaymentRecord<T: Config> {
         pub id: u64,
         pub payer: T::AccountId,
@@ -59,6 +508,14 @@ pub mod pallet {
         pub completed_at: Option<u64>,
         pub transaction_hash: Option<Vec<u8>>,
         pub metadata: Vec<u8>,
+        pub escrow: EscrowState,
+        /// Loan and installment index paid by this payment
+        pub installment: Option<(u64, u32)>,
+        /// Block by which the payment is due
+        pub due_at: Option<u64>,
+        /// Current delinquency bucket; `None` without a due date or once the payment
+        /// failed, was refunded or annulled
+        pub delinquency: Option<DelinquencyBucket>,
     }
 
     /// Storage: Payment counter
@@ -76,6 +533,16 @@ pub mod pallet {
         PaymentRecord<T>,
     >;
 
+    /// Storage: Due blocks proposed by payers, awaiting the payee's acceptance
+    #[pallet::storage]
+    #[pallet::getter(fn proposed_due_date)]
+    pub type ProposedDueDates<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BlockNumberFor<T>,
+    >;
+
     /// Storage: User payments
     #[pallet::storage]
     #[pallet::getter(fn user_payments)]
@@ -98,19 +565,269 @@ pub mod pallet {
         u64,
     >;
 
+    /// Storage: Queued payments by the block at which their verification period expires
+    #[pallet::storage]
+    pub type VerificationSchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        u64,
+        (),
+    >;
+
+    /// Storage: Earliest block whose verification schedule may still hold payments
+    #[pallet::storage]
+    #[pallet::getter(fn verification_cursor)]
+    pub type VerificationCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage: Registered payment verifiers and the block they were added at
+    #[pallet::storage]
+    #[pallet::getter(fn payment_verifiers)]
+    pub type PaymentVerifiers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+    >;
+
+    /// Storage: Registered payment processor keys and the block they were added at
+    #[pallet::storage]
+    #[pallet::getter(fn payment_processors)]
+    pub type PaymentProcessors<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ProcessorKey,
+        BlockNumberFor<T>,
+    >;
+
+    /// Storage: Signed receipts by the payment they verified
+    #[pallet::storage]
+    #[pallet::getter(fn payment_receipts)]
+    pub type PaymentReceipts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        (PaymentReceipt<T::AccountId>, ReceiptSignature),
+    >;
+
+    /// Storage: Disputes by payment ID, kept after resolution
+    #[pallet::storage]
+    #[pallet::getter(fn disputes)]
+    pub type Disputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        DisputeRecord<T>,
+    >;
+
+    /// Storage: Open disputes by the block at which their evidence period ends
+    #[pallet::storage]
+    pub type DisputeDeadlines<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        u64,
+        (),
+    >;
+
+    /// Storage: Earliest block whose dispute deadlines may still hold open disputes
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_cursor)]
+    pub type DisputeCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage: Accredited lenders and the block they were added at
+    #[pallet::storage]
+    #[pallet::getter(fn lenders)]
+    pub type Lenders<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+    >;
+
+    /// Storage: Loan counter
+    #[pallet::storage]
+    #[pallet::getter(fn loan_counter)]
+    pub type LoanCounter<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Storage: Loans by ID
+    #[pallet::storage]
+    #[pallet::getter(fn loans)]
+    pub type Loans<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        LoanRecord<T>,
+    >;
+
+    /// Storage: Installments by loan ID and installment index
+    #[pallet::storage]
+    #[pallet::getter(fn installments)]
+    pub type Installments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Twox64Concat,
+        u32,
+        Installment<T>,
+    >;
+
+    /// Storage: Installments by the first block after their due block
+    #[pallet::storage]
+    pub type InstallmentSchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (u64, u32),
+        (),
+    >;
+
+    /// Storage: Earliest block whose installment schedule may still hold installments
+    #[pallet::storage]
+    #[pallet::getter(fn installment_cursor)]
+    pub type InstallmentCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage: Recurring obligation counter
+    #[pallet::storage]
+    #[pallet::getter(fn obligation_counter)]
+    pub type ObligationCounter<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Storage: Recurring obligations by ID
+    #[pallet::storage]
+    #[pallet::getter(fn obligations)]
+    pub type Obligations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        RecurringObligation<T>,
+    >;
+
+    /// Storage: Occurrences by obligation ID and cycle
+    #[pallet::storage]
+    #[pallet::getter(fn occurrences)]
+    pub type Occurrences<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Twox64Concat,
+        u32,
+        Occurrence<T>,
+    >;
+
+    /// Storage: Obligation ID and cycle paid by each payment
+    #[pallet::storage]
+    #[pallet::getter(fn obligation_payments)]
+    pub type ObligationPayments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        (u64, u32),
+    >;
+
+    /// Storage: Active obligations by the first block after the due block of their latest
+    /// occurrence, when that occurrence is checked and the next one created
+    #[pallet::storage]
+    pub type ObligationSchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        u64,
+        (),
+    >;
+
+    /// Storage: Earliest block whose obligation schedule may still hold obligations
+    #[pallet::storage]
+    #[pallet::getter(fn obligation_cursor)]
+    pub type ObligationCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage: On-time streak of recurring obligation occurrences per payer
+    #[pallet::storage]
+    #[pallet::getter(fn on_time_streaks)]
+    pub type OnTimeStreaks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        OnTimeStreak,
+        ValueQuery,
+    >;
+
+    /// Storage: Latest resolved occurrences per payer, kept to recompute the on-time streak
+    #[pallet::storage]
+    #[pallet::getter(fn streak_histories)]
+    pub type StreakHistories<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        StreakHistory<T>,
+    >;
+
+    /// Storage: Delinquency bucket counts per payer
+    #[pallet::storage]
+    #[pallet::getter(fn payer_delinquency)]
+    pub type PayerDelinquency<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        DelinquencyCounts,
+        ValueQuery,
+    >;
+
+    /// Storage: Outstanding payments by the block at which they enter their next bucket
+    #[pallet::storage]
+    pub type DelinquencySchedule<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        u64,
+        (),
+    >;
+
+    /// Storage: Earliest block whose delinquency schedule may still hold payments
+    #[pallet::storage]
+    #[pallet::getter(fn delinquency_cursor)]
+    pub type DelinquencyCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     /// Storage: Payment statistics
     #[pallet::storage]
     #[pallet::getter(fn payment_stats)]
     pub type PaymentStats<T: Config> = StorageValue<_, PaymentStatistics, ValueQuery>;
 
-    /// Payment statistics structure
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    /// Storage: Payment outcome summary per payer
+    #[pallet::storage]
+    #[pallet::getter(fn payer_summary)]
+    pub type PayerSummaries<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PaymentSummary,
+        ValueQuery,
+    >;
+
+    /// Payment statistics structure
+    #[derive(
+        Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default, Serialize, Deserialize,
+    )]
     pub struct PaymentStatistics {
         pub total_payments: u64,
+        /// Volume of all payments, net of refunds and annulments
         pub total_volume: u128,
+        /// Payments currently `Completed`
         pub successful_payments: u64,
+        /// Payments currently `Failed`
         pub failed_payments: u64,
+        /// Disputes currently open
         pub disputed_payments: u64,
+        /// Disputes resolved, by the arbiter or by default judgment
+        pub resolved_disputes: u64,
+        /// Payments refunded in full by a dispute upheld for the payer
+        pub refunded_payments: u64,
+        /// Payments annulled by a dispute
+        pub annulled_payments: u64,
     }
 
     #[pallet::event]
@@ -127,6 +844,7 @@ pub mod pallet {
         PaymentVerified {
             payment_id: u64,
             verifier: T::AccountId,
+            processor: ProcessorKey,
         },
         /// Payment completed
         PaymentCompleted {
@@ -138,17 +856,179 @@ pub mod pallet {
             payment_id: u64,
             reason: Vec<u8>,
         },
+        /// Payment failed because no receipt verified it within the verification period
+        PaymentVerificationExpired {
+            payment_id: u64,
+        },
+        /// Due block proposed by the payer, awaiting the payee's acceptance
+        DueDateProposed {
+            payment_id: u64,
+            due_at: BlockNumberFor<T>,
+        },
+        /// Proposed due block accepted by the payee; the payment now ages against it
+        DueDateAccepted {
+            payment_id: u64,
+            due_at: BlockNumberFor<T>,
+        },
         /// Payment disputed
         PaymentDisputed {
             payment_id: u64,
             disputer: T::AccountId,
             reason: Vec<u8>,
         },
-        /// Payment dispute resolved
+        /// Payment dispute resolved by the arbiter
         PaymentDisputeResolved {
             payment_id: u64,
+            outcome: DisputeOutcome,
             resolution: Vec<u8>,
         },
+        /// Dispute evidence submitted by payer or payee
+        DisputeEvidenceSubmitted {
+            payment_id: u64,
+            who: T::AccountId,
+            evidence_hash: T::Hash,
+        },
+        /// Dispute resolved by default judgment after the evidence period
+        DisputeDefaultJudgment {
+            payment_id: u64,
+            outcome: DisputeOutcome,
+        },
+        /// Lender accredited
+        LenderAdded {
+            lender: T::AccountId,
+        },
+        /// Lender removed
+        LenderRemoved {
+            lender: T::AccountId,
+        },
+        /// Loan registered by the lender, awaiting the borrower's acceptance
+        LoanRegistered {
+            loan_id: u64,
+            lender: T::AccountId,
+            borrower: T::AccountId,
+            principal: u128,
+            installments: u32,
+        },
+        /// Loan accepted by the borrower; its installment schedule was generated
+        LoanAccepted {
+            loan_id: u64,
+        },
+        /// Payment created for an installment
+        InstallmentPaymentCreated {
+            loan_id: u64,
+            index: u32,
+            payment_id: u64,
+        },
+        /// Installment paid
+        InstallmentPaid {
+            loan_id: u64,
+            index: u32,
+            payment_id: u64,
+            on_time: bool,
+        },
+        /// Installment passed its due block unpaid
+        InstallmentMissed {
+            loan_id: u64,
+            index: u32,
+        },
+        /// Every installment of the loan has been paid
+        LoanRepaid {
+            loan_id: u64,
+        },
+        /// Recurring obligation registered by the creditor
+        ObligationRegistered {
+            obligation_id: u64,
+            creditor: T::AccountId,
+            payer: T::AccountId,
+            kind: ObligationKind,
+            amount: u128,
+        },
+        /// Recurring obligation accepted by the payer
+        ObligationAccepted {
+            obligation_id: u64,
+        },
+        /// Recurring obligation cancelled
+        ObligationCancelled {
+            obligation_id: u64,
+            who: T::AccountId,
+        },
+        /// Occurrence created for a new cycle
+        OccurrenceCreated {
+            obligation_id: u64,
+            cycle: u32,
+            due: BlockNumberFor<T>,
+        },
+        /// Payment created for an occurrence
+        OccurrencePaymentCreated {
+            obligation_id: u64,
+            cycle: u32,
+            payment_id: u64,
+        },
+        /// Occurrence paid
+        OccurrencePaid {
+            obligation_id: u64,
+            cycle: u32,
+            payment_id: u64,
+            on_time: bool,
+        },
+        /// Occurrence passed its due block unpaid
+        OccurrenceMissed {
+            obligation_id: u64,
+            cycle: u32,
+        },
+        /// Payer's on-time streak reset by a late or missed occurrence
+        OnTimeStreakBroken {
+            payer: T::AccountId,
+            length: u32,
+        },
+        /// Payment moved to another delinquency bucket
+        DelinquencyBucketChanged {
+            payment_id: u64,
+            payer: T::AccountId,
+            from: DelinquencyBucket,
+            to: DelinquencyBucket,
+            days_past_due: u32,
+        },
+        /// Payment funds held in escrow
+        EscrowHeld {
+            payment_id: u64,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Released funds could not be held again from the payee; the dispute proceeds
+        /// without escrow
+        EscrowNotHeld {
+            payment_id: u64,
+            who: T::AccountId,
+        },
+        /// Escrowed funds released to the payee
+        EscrowReleased {
+            payment_id: u64,
+            payee: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Escrowed funds returned to the payer
+        EscrowRefunded {
+            payment_id: u64,
+            payer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Payment verifier registered
+        VerifierAdded {
+            verifier: T::AccountId,
+        },
+        /// Payment verifier removed
+        VerifierRemoved {
+            verifier: T::AccountId,
+        },
+        /// Payment processor key registered
+        ProcessorAdded {
+            key: ProcessorKey,
+        },
+        /// Payment processor key removed
+        ProcessorRemoved {
+            key: ProcessorKey,
+        },
     }
 
     #[pallet::error]
@@ -177,22 +1057,996 @@ pub mod pallet {
         InsufficientPermissions,
         /// Invalid transaction hash
         InvalidTransactionHash,
+        /// Account is already a registered verifier
+        VerifierAlreadyExists,
+        /// Account is not a registered verifier
+        VerifierNotFound,
+        /// Payment was already disputed once
+        AlreadyDisputed,
+        /// Dispute not found
+        DisputeNotFound,
+        /// Evidence period of the dispute has ended
+        EvidencePeriodClosed,
+        /// Refund exceeds the payment amount
+        InvalidRefundAmount,
+        /// Account is already an accredited lender
+        LenderAlreadyExists,
+        /// Account is not an accredited lender
+        LenderNotFound,
+        /// Loan not found
+        LoanNotFound,
+        /// Installment not found
+        InstallmentNotFound,
+        /// Installment count, due block or interval is invalid
+        InvalidLoanSchedule,
+        /// Installment is already paid or has a payment in progress
+        InstallmentNotPayable,
+        /// Loan status does not allow the operation
+        InvalidLoanStatus,
+        /// Recurring obligation not found
+        ObligationNotFound,
+        /// Occurrence not found
+        OccurrenceNotFound,
+        /// Period or first due block of the obligation is invalid
+        InvalidObligationSchedule,
+        /// Obligation status does not allow the operation
+        InvalidObligationStatus,
+        /// Occurrence is already paid or has a payment in progress
+        OccurrenceNotPayable,
+        /// Key is already a registered payment processor
+        ProcessorAlreadyExists,
+        /// Key is not a registered payment processor
+        ProcessorNotFound,
+        /// Receipt signature does not match the processor key
+        InvalidReceiptSignature,
+        /// Receipt amount or currency differs from the payment
+        ReceiptAmountMismatch,
+        /// Receipt payment ID, payer or payee differs from the payment
+        ReceiptReferenceMismatch,
+        /// Payer and payee are the same account
+        CannotPaySelf,
+        /// Payment amount does not fit the escrow currency's balance type
+        EscrowAmountOverflow,
+        /// No loan IDs are left
+        LoanIdOverflow,
+        /// No obligation IDs are left
+        ObligationIdOverflow,
+        /// Due block is not in the future
+        InvalidDueDate,
+        /// Payment has no proposed due block
+        NoProposedDueDate,
     }
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Create a new payment
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(0)]
-        pub fn create_payment(
-            origin: OriginFor<T>,
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new payment, optionally proposing block `due_at` as its due date.
+        ///
+        /// The payment only ages against the due date once the payee accepts it.
+        #[pallet::weight(T::WeightInfo::create_payment(T::MaxPaymentsPerUser::get()))]
+        #[pallet::call_index(0)]
+        pub fn create_payment(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            amount: u128,
+            currency: Vec<u8>,
+            description: Vec<u8>,
+            metadata: Vec<u8>,
+            due_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+            Self::ensure_future_due(due_at)?;
+            let payment_id =
+                Self::do_create_payment(payer, payee, amount, currency, description, metadata, None, false)?;
+            Self::propose_due_date(payment_id, due_at);
+            Ok(())
+        }
+
+        /// Create a new payment whose funds are held from the payer until it settles.
+        ///
+        /// The funds are released to the payee on completion, returned to the payer on
+        /// failure and stay held while the payment is disputed. A proposed `due_at` needs
+        /// the payee's acceptance, as for `create_payment`.
+        #[pallet::weight(T::WeightInfo::create_escrowed_payment(T::MaxPaymentsPerUser::get()))]
+        #[pallet::call_index(6)]
+        pub fn create_escrowed_payment(
+            origin: OriginFor<T>,
+            payee: T::AccountId,
+            amount: u128,
+            currency: Vec<u8>,
+            description: Vec<u8>,
+            metadata: Vec<u8>,
+            due_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+            Self::ensure_future_due(due_at)?;
+            let payment_id =
+                Self::do_create_payment(payer, payee, amount, currency, description, metadata, None, true)?;
+            Self::propose_due_date(payment_id, due_at);
+            Ok(())
+        }
+
+        /// Accept the due block the payer proposed for a payment to the caller.
+        ///
+        /// The payment must still be pending or verified and the due block in the future;
+        /// from then on the payment ages against it.
+        #[pallet::weight(T::WeightInfo::accept_due_date())]
+        #[pallet::call_index(21)]
+        pub fn accept_due_date(
+            origin: OriginFor<T>,
+            payment_id: u64,
+        ) -> DispatchResult {
+            let payee = ensure_signed(origin)?;
+
+            let mut payment = Payments::<T>::get(payment_id).ok_or(Error::<T>::PaymentNotFound)?;
+            ensure!(payment.payee == payee, Error::<T>::InsufficientPermissions);
+            ensure!(
+                matches!(payment.status, PaymentStatus::Pending | PaymentStatus::Verified),
+                Error::<T>::InvalidPaymentStatus
+            );
+            let due_at = ProposedDueDates::<T>::get(payment_id).ok_or(Error::<T>::NoProposedDueDate)?;
+            Self::ensure_future_due(Some(due_at))?;
+
+            ProposedDueDates::<T>::remove(payment_id);
+            payment.due_at = Some(due_at.saturated_into());
+            Self::update_delinquency(&mut payment);
+            Payments::<T>::insert(payment_id, &payment);
+
+            Self::deposit_event(Event::DueDateAccepted { payment_id, due_at });
+
+            Ok(())
+        }
+
+        /// Register a payment verifier
+        #[pallet::weight(T::WeightInfo::add_verifier())]
+        #[pallet::call_index(7)]
+        pub fn add_verifier(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !PaymentVerifiers::<T>::contains_key(&verifier),
+                Error::<T>::VerifierAlreadyExists
+            );
+
+            PaymentVerifiers::<T>::insert(&verifier, <frame_system::Pallet<T>>::block_number());
+
+            Self::deposit_event(Event::VerifierAdded { verifier });
+
+            Ok(())
+        }
+
+        /// Remove a payment verifier
+        #[pallet::weight(T::WeightInfo::remove_verifier())]
+        #[pallet::call_index(8)]
+        pub fn remove_verifier(
+            origin: OriginFor<T>,
+            verifier: T::AccountId,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                PaymentVerifiers::<T>::contains_key(&verifier),
+                Error::<T>::VerifierNotFound
+            );
+
+            PaymentVerifiers::<T>::remove(&verifier);
+
+            Self::deposit_event(Event::VerifierRemoved { verifier });
+
+            Ok(())
+        }
+
+        /// Verify a payment with a receipt signed by a registered payment processor.
+        ///
+        /// The receipt must name this payment, its payer and payee, and match its amount
+        /// and currency.
+        #[pallet::weight(T::WeightInfo::verify_payment())]
+        #[pallet::call_index(1)]
+        pub fn verify_payment(
+            origin: OriginFor<T>,
+            payment_id: u64,
+            receipt: PaymentReceipt<T::AccountId>,
+            signature: ReceiptSignature,
+        ) -> DispatchResult {
+            // Get payment
+            let mut payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+
+            // Check payment status and caller role
+            let verifier = Self::ensure_transition(origin, &payment, &PaymentStatus::Verified)?
+                .ok_or(DispatchError::BadOrigin)?;
+
+            // Check the receipt against the payment
+            Self::ensure_valid_receipt(&payment, &receipt, &signature)?;
+            let processor = receipt.processor.clone();
+
+            // Update payment
+            payment.status = PaymentStatus::Verified;
+            payment.verified_at = Some(<frame_system::Pallet<T>>::block_number().saturated_into());
+            payment.transaction_hash = Some(receipt.transaction_hash.clone());
+
+            // Store updated payment and its receipt
+            Payments::<T>::insert(payment_id, &payment);
+            PaymentReceipts::<T>::insert(payment_id, (receipt, signature));
+
+            // Remove from verification queue
+            Self::dequeue_verification(payment_id);
+
+            Self::deposit_event(Event::PaymentVerified {
+                payment_id,
+                verifier,
+                processor,
+            });
+
+            Ok(())
+        }
+
+        /// Complete a payment
+        #[pallet::weight(T::WeightInfo::complete_payment())]
+        #[pallet::call_index(2)]
+        pub fn complete_payment(
+            origin: OriginFor<T>,
+            payment_id: u64,
+        ) -> DispatchResult {
+            // Get payment
+            let mut payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+
+            // Check payment status and caller role
+            Self::ensure_transition(origin, &payment, &PaymentStatus::Completed)?;
+
+            // Pay out escrowed funds
+            Self::settle_escrow(&mut payment, 0)?;
+
+            // A due date the payee never accepted no longer applies
+            ProposedDueDates::<T>::remove(payment_id);
+
+            // Update payment
+            payment.status = PaymentStatus::Completed;
+            payment.completed_at = Some(<frame_system::Pallet<T>>::block_number().saturated_into());
+            Self::update_delinquency(&mut payment);
+
+            // Store updated payment
+            Payments::<T>::insert(payment_id, &payment);
+
+            // Update statistics
+            let mut stats = PaymentStats::<T>::get();
+            stats.successful_payments += 1;
+            PaymentStats::<T>::put(stats);
+
+            Self::note_status_change(&payment.payer, &PaymentStatus::Verified, &PaymentStatus::Completed);
+            Self::note_installment_payment(&payment);
+            Self::note_occurrence_payment(&payment);
+
+            Self::deposit_event(Event::PaymentCompleted {
+                payment_id,
+                transaction_hash: payment.transaction_hash.unwrap_or_default(),
+            });
+
+            Ok(())
+        }
+
+        /// Mark payment as failed
+        #[pallet::weight(T::WeightInfo::fail_payment())]
+        #[pallet::call_index(3)]
+        pub fn fail_payment(
+            origin: OriginFor<T>,
+            payment_id: u64,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            // Get payment
+            let mut payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+
+            // Check payment status and caller role
+            Self::ensure_transition(origin, &payment, &PaymentStatus::Failed)?;
+
+            Self::do_fail_payment(&mut payment)?;
+
+            Self::deposit_event(Event::PaymentFailed {
+                payment_id,
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Dispute a payment
+        #[pallet::weight(T::WeightInfo::dispute_payment())]
+        #[pallet::call_index(4)]
+        pub fn dispute_payment(
+            origin: OriginFor<T>,
+            payment_id: u64,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            // Get payment
+            let mut payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+
+            // Check payment status and that the disputer is payer or payee
+            let disputer = Self::ensure_transition(origin, &payment, &PaymentStatus::Disputed)?
+                .ok_or(DispatchError::BadOrigin)?;
+
+            // A payment can only be disputed once
+            ensure!(!Disputes::<T>::contains_key(payment_id), Error::<T>::AlreadyDisputed);
+
+            // Funds already released to the payee are held again until the dispute is resolved.
+            // If the payee no longer has them, the dispute proceeds without escrow.
+            if payment.escrow == EscrowState::Released {
+                let payee = payment.payee.clone();
+                let held = frame_support::storage::with_storage_layer(|| {
+                    Self::hold_escrow(&mut payment, payee.clone())
+                });
+                if held.is_err() {
+                    Self::deposit_event(Event::EscrowNotHeld { payment_id, who: payee });
+                }
+            }
+
+            // A due date the payee never accepted no longer applies
+            ProposedDueDates::<T>::remove(payment_id);
+
+            // Update payment
+            let previous_status = payment.status.clone();
+            payment.status = PaymentStatus::Disputed;
+
+            // Store updated payment
+            Payments::<T>::insert(payment_id, &payment);
+
+            // Open the dispute and schedule its evidence deadline
+            let now = <frame_system::Pallet<T>>::block_number();
+            let evidence_deadline = Self::evidence_deadline(now);
+            Disputes::<T>::insert(payment_id, DisputeRecord {
+                opened_by: disputer.clone(),
+                opened_at: now,
+                evidence_deadline,
+                payer_evidence: None,
+                payee_evidence: None,
+                outcome: None,
+            });
+            DisputeDeadlines::<T>::insert(evidence_deadline, payment_id, ());
+
+            // Update statistics
+            let mut stats = PaymentStats::<T>::get();
+            stats.disputed_payments += 1;
+            if previous_status == PaymentStatus::Completed {
+                stats.successful_payments = stats.successful_payments.saturating_sub(1);
+            }
+            PaymentStats::<T>::put(stats);
+
+            Self::note_status_change(&payment.payer, &previous_status, &PaymentStatus::Disputed);
+
+            Self::deposit_event(Event::PaymentDisputed {
+                payment_id,
+                disputer,
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve payment dispute (`ArbiterOrigin` only)
+        #[pallet::weight(T::WeightInfo::resolve_dispute())]
+        #[pallet::call_index(5)]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            payment_id: u64,
+            outcome: DisputeOutcome,
+            resolution: Vec<u8>,
+        ) -> DispatchResult {
+            // Get payment
+            let payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+
+            // Check payment status and caller role
+            Self::ensure_transition(origin, &payment, &Self::outcome_status(&outcome, &payment))?;
+
+            Self::do_resolve_dispute(payment, &outcome)?;
+
+            Self::deposit_event(Event::PaymentDisputeResolved {
+                payment_id,
+                outcome,
+                resolution,
+            });
+
+            Ok(())
+        }
+
+        /// Submit evidence for an open dispute (payer or payee, within the evidence period)
+        #[pallet::weight(T::WeightInfo::submit_dispute_evidence())]
+        #[pallet::call_index(9)]
+        pub fn submit_dispute_evidence(
+            origin: OriginFor<T>,
+            payment_id: u64,
+            evidence_hash: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let payment = Payments::<T>::get(payment_id)
+                .ok_or(Error::<T>::PaymentNotFound)?;
+            ensure!(payment.status == PaymentStatus::Disputed, Error::<T>::InvalidPaymentStatus);
+
+            let mut dispute = Disputes::<T>::get(payment_id)
+                .ok_or(Error::<T>::DisputeNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < dispute.evidence_deadline,
+                Error::<T>::EvidencePeriodClosed
+            );
+
+            // Later submissions replace earlier ones from the same party
+            if who == payment.payer {
+                dispute.payer_evidence = Some(evidence_hash);
+            } else if who == payment.payee {
+                dispute.payee_evidence = Some(evidence_hash);
+            } else {
+                return Err(Error::<T>::InsufficientPermissions.into());
+            }
+
+            Disputes::<T>::insert(payment_id, dispute);
+
+            Self::deposit_event(Event::DisputeEvidenceSubmitted {
+                payment_id,
+                who,
+                evidence_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Accredit a lender
+        #[pallet::weight(T::WeightInfo::add_lender())]
+        #[pallet::call_index(10)]
+        pub fn add_lender(
+            origin: OriginFor<T>,
+            lender: T::AccountId,
+        ) -> DispatchResult {
+            T::LenderOrigin::ensure_origin(origin)?;
+
+            ensure!(!Lenders::<T>::contains_key(&lender), Error::<T>::LenderAlreadyExists);
+
+            Lenders::<T>::insert(&lender, <frame_system::Pallet<T>>::block_number());
+
+            Self::deposit_event(Event::LenderAdded { lender });
+
+            Ok(())
+        }
+
+        /// Remove an accredited lender; its existing loans are unaffected
+        #[pallet::weight(T::WeightInfo::remove_lender())]
+        #[pallet::call_index(11)]
+        pub fn remove_lender(
+            origin: OriginFor<T>,
+            lender: T::AccountId,
+        ) -> DispatchResult {
+            T::LenderOrigin::ensure_origin(origin)?;
+
+            ensure!(Lenders::<T>::contains_key(&lender), Error::<T>::LenderNotFound);
+
+            Lenders::<T>::remove(&lender);
+
+            Self::deposit_event(Event::LenderRemoved { lender });
+
+            Ok(())
+        }
+
+        /// Register a loan of `installments` equal installments owed by `borrower` to the
+        /// caller, the first due at `first_due` and the rest every `interval` blocks. The
+        /// installment schedule is generated once the borrower accepts it.
+        #[pallet::weight(T::WeightInfo::register_loan())]
+        #[pallet::call_index(12)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn register_loan(
+            origin: OriginFor<T>,
+            borrower: T::AccountId,
+            principal: u128,
+            currency: Vec<u8>,
+            installment_amount: u128,
+            installments: u32,
+            first_due: BlockNumberFor<T>,
+            interval: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let lender = ensure_signed(origin)?;
+            ensure!(Lenders::<T>::contains_key(&lender), Error::<T>::InsufficientPermissions);
+            ensure!(lender != borrower, Error::<T>::InsufficientPermissions);
+
+            // Validate schedule
+            ensure!(
+                installments > 0 && installments <= T::MaxInstallments::get(),
+                Error::<T>::InvalidLoanSchedule
+            );
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(first_due > now, Error::<T>::InvalidLoanSchedule);
+            ensure!(installments == 1 || !interval.is_zero(), Error::<T>::InvalidLoanSchedule);
+            ensure!(installment_amount >= T::MinPaymentAmount::get(), Error::<T>::AmountTooLow);
+            ensure!(installment_amount <= T::MaxPaymentAmount::get(), Error::<T>::AmountTooHigh);
+
+            // Generate loan ID
+            let loan_id = LoanCounter::<T>::get().checked_add(1).ok_or(Error::<T>::LoanIdOverflow)?;
+            LoanCounter::<T>::put(loan_id);
+
+            Loans::<T>::insert(loan_id, LoanRecord {
+                id: loan_id,
+                lender: lender.clone(),
+                borrower: borrower.clone(),
+                principal,
+                currency,
+                installment_amount,
+                installment_count: installments,
+                first_due,
+                interval,
+                created_at: now,
+                status: LoanStatus::Proposed,
+                paid_on_time: 0,
+                paid_late: 0,
+                missed: 0,
+            });
+
+            Self::deposit_event(Event::LoanRegistered {
+                loan_id,
+                lender,
+                borrower,
+                principal,
+                installments,
+            });
+
+            Ok(())
+        }
+
+        /// Accept a loan registered against the caller and generate its installment schedule
+        #[pallet::weight(T::WeightInfo::accept_loan(T::MaxInstallments::get()))]
+        #[pallet::call_index(20)]
+        pub fn accept_loan(
+            origin: OriginFor<T>,
+            loan_id: u64,
+        ) -> DispatchResult {
+            let borrower = ensure_signed(origin)?;
+
+            let mut loan = Loans::<T>::get(loan_id).ok_or(Error::<T>::LoanNotFound)?;
+            ensure!(loan.borrower == borrower, Error::<T>::InsufficientPermissions);
+            ensure!(loan.status == LoanStatus::Proposed, Error::<T>::InvalidLoanStatus);
+            ensure!(
+                loan.first_due > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::InvalidLoanSchedule
+            );
+
+            // Generate installments
+            let mut due = loan.first_due;
+            for index in 0..loan.installment_count {
+                Installments::<T>::insert(loan_id, index, Installment {
+                    amount: loan.installment_amount,
+                    due,
+                    status: InstallmentStatus::Scheduled,
+                    payment_id: None,
+                });
+                InstallmentSchedule::<T>::insert(due.saturating_add(One::one()), (loan_id, index), ());
+                due = due.saturating_add(loan.interval);
+            }
+
+            loan.status = LoanStatus::Active;
+            Loans::<T>::insert(loan_id, loan);
+
+            Self::deposit_event(Event::LoanAccepted { loan_id });
+
+            Ok(())
+        }
+
+        /// Create a payment from the borrower to the lender for an installment.
+        ///
+        /// The payment is due at the installment's due block. The installment counts as paid
+        /// once the payment completes, on time if that happens on or before the due block.
+        #[pallet::weight(T::WeightInfo::pay_installment(T::MaxPaymentsPerUser::get()))]
+        #[pallet::call_index(13)]
+        pub fn pay_installment(
+            origin: OriginFor<T>,
+            loan_id: u64,
+            index: u32,
+            escrowed: bool,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let borrower = ensure_signed(origin)?;
+
+            let loan = Loans::<T>::get(loan_id).ok_or(Error::<T>::LoanNotFound)?;
+            ensure!(loan.borrower == borrower, Error::<T>::InsufficientPermissions);
+
+            let mut installment = Installments::<T>::get(loan_id, index)
+                .ok_or(Error::<T>::InstallmentNotFound)?;
+            ensure!(
+                installment.payment_id.is_none()
+                    && matches!(installment.status, InstallmentStatus::Scheduled | InstallmentStatus::Missed),
+                Error::<T>::InstallmentNotPayable
+            );
+
+            let payment_id = Self::do_create_payment(
+                borrower,
+                loan.lender,
+                installment.amount,
+                loan.currency,
+                Vec::new(),
+                metadata,
+                Some(installment.due),
+                escrowed,
+            )?;
+
+            // Link payment and installment
+            Payments::<T>::mutate(payment_id, |payment| {
+                if let Some(payment) = payment {
+                    payment.installment = Some((loan_id, index));
+                }
+            });
+            installment.payment_id = Some(payment_id);
+            Installments::<T>::insert(loan_id, index, installment);
+
+            Self::deposit_event(Event::InstallmentPaymentCreated {
+                loan_id,
+                index,
+                payment_id,
+            });
+
+            Ok(())
+        }
+
+        /// Register a recurring bill owed by `payer` to the caller, due every `period` blocks
+        /// starting at `first_due`. Occurrences are created once the payer accepts it.
+        #[pallet::weight(T::WeightInfo::register_obligation())]
+        #[pallet::call_index(14)]
+        pub fn register_obligation(
+            origin: OriginFor<T>,
+            payer: T::AccountId,
+            kind: ObligationKind,
+            amount: u128,
+            currency: Vec<u8>,
+            first_due: BlockNumberFor<T>,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let creditor = ensure_signed(origin)?;
+            ensure!(creditor != payer, Error::<T>::InsufficientPermissions);
+
+            // Validate schedule
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(first_due > now, Error::<T>::InvalidObligationSchedule);
+            ensure!(
+                !period.is_zero() && period >= T::MinObligationPeriod::get().into(),
+                Error::<T>::InvalidObligationSchedule
+            );
+            ensure!(amount >= T::MinPaymentAmount::get(), Error::<T>::AmountTooLow);
+            ensure!(amount <= T::MaxPaymentAmount::get(), Error::<T>::AmountTooHigh);
+
+            // Generate obligation ID
+            let obligation_id = ObligationCounter::<T>::get()
+                .checked_add(1)
+                .ok_or(Error::<T>::ObligationIdOverflow)?;
+            ObligationCounter::<T>::put(obligation_id);
+
+            Obligations::<T>::insert(obligation_id, RecurringObligation {
+                id: obligation_id,
+                creditor: creditor.clone(),
+                payer: payer.clone(),
+                kind: kind.clone(),
+                amount,
+                currency,
+                period,
+                first_due,
+                created_at: now,
+                status: ObligationStatus::Proposed,
+                cycles: 0,
+                paid_on_time: 0,
+                paid_late: 0,
+                missed: 0,
+            });
+
+            Self::deposit_event(Event::ObligationRegistered {
+                obligation_id,
+                creditor,
+                payer,
+                kind,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Accept a recurring obligation registered against the caller and create its
+        /// first occurrence
+        #[pallet::weight(T::WeightInfo::accept_obligation())]
+        #[pallet::call_index(15)]
+        pub fn accept_obligation(
+            origin: OriginFor<T>,
+            obligation_id: u64,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            let mut obligation = Obligations::<T>::get(obligation_id)
+                .ok_or(Error::<T>::ObligationNotFound)?;
+            ensure!(obligation.payer == payer, Error::<T>::InsufficientPermissions);
+            ensure!(obligation.status == ObligationStatus::Proposed, Error::<T>::InvalidObligationStatus);
+            ensure!(
+                obligation.first_due > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::InvalidObligationSchedule
+            );
+
+            obligation.status = ObligationStatus::Active;
+            let first_due = obligation.first_due;
+            Self::open_cycle(&mut obligation, first_due);
+            Obligations::<T>::insert(obligation_id, obligation);
+
+            Self::deposit_event(Event::ObligationAccepted { obligation_id });
+
+            Ok(())
+        }
+
+        /// Cancel a recurring obligation as its creditor or payer.
+        ///
+        /// No further occurrences are created; the latest one still counts as missed if its
+        /// due block passes unpaid.
+        #[pallet::weight(T::WeightInfo::cancel_obligation())]
+        #[pallet::call_index(16)]
+        pub fn cancel_obligation(
+            origin: OriginFor<T>,
+            obligation_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut obligation = Obligations::<T>::get(obligation_id)
+                .ok_or(Error::<T>::ObligationNotFound)?;
+            ensure!(
+                obligation.creditor == who || obligation.payer == who,
+                Error::<T>::InsufficientPermissions
+            );
+            ensure!(obligation.status != ObligationStatus::Cancelled, Error::<T>::InvalidObligationStatus);
+
+            obligation.status = ObligationStatus::Cancelled;
+            Obligations::<T>::insert(obligation_id, obligation);
+
+            Self::deposit_event(Event::ObligationCancelled { obligation_id, who });
+
+            Ok(())
+        }
+
+        /// Create a payment from the payer to the creditor for an occurrence.
+        ///
+        /// The payment is due at the occurrence's due block. The occurrence counts as paid
+        /// once the payment completes, on time if that happens on or before the due block.
+        #[pallet::weight(T::WeightInfo::pay_obligation(T::MaxPaymentsPerUser::get()))]
+        #[pallet::call_index(17)]
+        pub fn pay_obligation(
+            origin: OriginFor<T>,
+            obligation_id: u64,
+            cycle: u32,
+            escrowed: bool,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let payer = ensure_signed(origin)?;
+
+            let obligation = Obligations::<T>::get(obligation_id)
+                .ok_or(Error::<T>::ObligationNotFound)?;
+            ensure!(obligation.payer == payer, Error::<T>::InsufficientPermissions);
+
+            let mut occurrence = Occurrences::<T>::get(obligation_id, cycle)
+                .ok_or(Error::<T>::OccurrenceNotFound)?;
+            ensure!(
+                occurrence.payment_id.is_none()
+                    && matches!(occurrence.status, InstallmentStatus::Scheduled | InstallmentStatus::Missed),
+                Error::<T>::OccurrenceNotPayable
+            );
+
+            let payment_id = Self::do_create_payment(
+                payer,
+                obligation.creditor,
+                obligation.amount,
+                obligation.currency,
+                Vec::new(),
+                metadata,
+                Some(occurrence.due),
+                escrowed,
+            )?;
+
+            // Link payment and occurrence
+            ObligationPayments::<T>::insert(payment_id, (obligation_id, cycle));
+            occurrence.payment_id = Some(payment_id);
+            Occurrences::<T>::insert(obligation_id, cycle, occurrence);
+
+            Self::deposit_event(Event::OccurrencePaymentCreated {
+                obligation_id,
+                cycle,
+                payment_id,
+            });
+
+            Ok(())
+        }
+
+        /// Register the key of a payment processor or bank allowed to sign receipts
+        #[pallet::weight(T::WeightInfo::add_processor())]
+        #[pallet::call_index(18)]
+        pub fn add_processor(
+            origin: OriginFor<T>,
+            key: ProcessorKey,
+        ) -> DispatchResult {
+            T::ProcessorOrigin::ensure_origin(origin)?;
+
+            ensure!(!PaymentProcessors::<T>::contains_key(&key), Error::<T>::ProcessorAlreadyExists);
+
+            PaymentProcessors::<T>::insert(&key, <frame_system::Pallet<T>>::block_number());
+
+            Self::deposit_event(Event::ProcessorAdded { key });
+
+            Ok(())
+        }
+
+        /// Remove a payment processor key; receipts it already signed remain on record
+        #[pallet::weight(T::WeightInfo::remove_processor())]
+        #[pallet::call_index(19)]
+        pub fn remove_processor(
+            origin: OriginFor<T>,
+            key: ProcessorKey,
+        ) -> DispatchResult {
+            T::ProcessorOrigin::ensure_origin(origin)?;
+
+            ensure!(PaymentProcessors::<T>::contains_key(&key), Error::<T>::ProcessorNotFound);
+
+            PaymentProcessors::<T>::remove(&key);
+
+            Self::deposit_event(Event::ProcessorRemoved { key });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            // Process verification queue, expired evidence periods, due installments, overdue
+            // payments and recurring obligation cycles
+            Self::process_verification_queue(n)
+                .saturating_add(Self::process_dispute_deadlines(n))
+                .saturating_add(Self::process_due_installments(n))
+                .saturating_add(Self::process_delinquency_checks(n))
+                .saturating_add(Self::process_obligation_cycles(n))
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Transition table: the roles allowed to move a payment from `from` to `to`.
+        ///
+        /// An empty slice means the transition is not allowed at all. Expiry of
+        /// unverified payments and default judgments by `on_initialize` bypass the table.
+        pub fn transition_roles(from: &PaymentStatus, to: &PaymentStatus) -> &'static [PaymentRole] {
+            use PaymentRole::*;
+            use PaymentStatus::*;
+
+            match (from, to) {
+                (Pending, Verified) => &[Verifier],
+                (Pending, Failed) => &[Payer, Payee, Verifier],
+                (Verified, Completed) => &[Payer, Verifier],
+                (Verified, Failed) => &[Payee, Verifier],
+                (Verified, Disputed) | (Completed, Disputed) => &[Payer, Payee],
+                (Disputed, Completed) | (Disputed, Refunded) | (Disputed, Annulled) | (Disputed, Unrefunded) => {
+                    &[Arbiter]
+                }
+                _ => &[],
+            }
+        }
+
+        /// Ensure `origin` holds a role allowed to move `payment` to `to`.
+        ///
+        /// Returns the signing account, if any; the arbiter origin need not be signed.
+        fn ensure_transition(
+            origin: OriginFor<T>,
+            payment: &PaymentRecord<T>,
+            to: &PaymentStatus,
+        ) -> Result<Option<T::AccountId>, DispatchError> {
+            let allowed = Self::transition_roles(&payment.status, to);
+            ensure!(!allowed.is_empty(), Error::<T>::InvalidPaymentStatus);
+
+            let is_arbiter = T::ArbiterOrigin::ensure_origin(origin.clone()).is_ok();
+            let who = ensure_signed(origin).ok();
+            ensure!(is_arbiter || who.is_some(), DispatchError::BadOrigin);
+
+            let permitted = allowed.iter().any(|role| match role {
+                PaymentRole::Payer => who.as_ref() == Some(&payment.payer),
+                PaymentRole::Payee => who.as_ref() == Some(&payment.payee),
+                PaymentRole::Verifier => who
+                    .as_ref()
+                    .is_some_and(PaymentVerifiers::<T>::contains_key),
+                PaymentRole::Arbiter => is_arbiter,
+            });
+            ensure!(permitted, Error::<T>::InsufficientPermissions);
+
+            Ok(who)
+        }
+
+        /// Ensure `receipt` is signed by a registered processor and matches `payment`
+        fn ensure_valid_receipt(
+            payment: &PaymentRecord<T>,
+            receipt: &PaymentReceipt<T::AccountId>,
+            signature: &ReceiptSignature,
+        ) -> DispatchResult {
+            ensure!(
+                PaymentProcessors::<T>::contains_key(&receipt.processor),
+                Error::<T>::ProcessorNotFound
+            );
+            ensure!(
+                receipt.processor.verify(&receipt.signing_payload(), signature),
+                Error::<T>::InvalidReceiptSignature
+            );
+            ensure!(
+                receipt.amount == payment.amount && receipt.currency == payment.currency,
+                Error::<T>::ReceiptAmountMismatch
+            );
+            ensure!(
+                receipt.payment_id == payment.id
+                    && receipt.payer == payment.payer
+                    && receipt.payee == payment.payee,
+                Error::<T>::ReceiptReferenceMismatch
+            );
+            ensure!(!receipt.transaction_hash.is_empty(), Error::<T>::InvalidTransactionHash);
+
+            Ok(())
+        }
+
+        /// Mark `payment` failed, returning any escrowed funds to the payer
+        fn do_fail_payment(payment: &mut PaymentRecord<T>) -> DispatchResult {
+            // Return escrowed funds
+            let refund = payment.amount;
+            Self::settle_escrow(payment, refund)?;
+
+            // Update payment
+            let previous_status = payment.status.clone();
+            payment.status = PaymentStatus::Failed;
+            Self::update_delinquency(payment);
+
+            // Store updated payment
+            Payments::<T>::insert(payment.id, &*payment);
+
+            // A failed payment is no longer awaiting verification or a due date
+            Self::dequeue_verification(payment.id);
+            ProposedDueDates::<T>::remove(payment.id);
+
+            // Update statistics
+            let mut stats = PaymentStats::<T>::get();
+            stats.failed_payments += 1;
+            PaymentStats::<T>::put(stats);
+
+            Self::note_status_change(&payment.payer, &previous_status, &PaymentStatus::Failed);
+            Self::note_installment_payment(payment);
+            Self::note_occurrence_payment(payment);
+
+            Ok(())
+        }
+
+        /// Ensure a due block set by the caller is in the future
+        fn ensure_future_due(due_at: Option<BlockNumberFor<T>>) -> DispatchResult {
+            if let Some(due) = due_at {
+                ensure!(due > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidDueDate);
+            }
+            Ok(())
+        }
+
+        /// Record the due block a payer proposed for `payment_id` until the payee accepts it
+        fn propose_due_date(payment_id: u64, due_at: Option<BlockNumberFor<T>>) {
+            if let Some(due_at) = due_at {
+                ProposedDueDates::<T>::insert(payment_id, due_at);
+                Self::deposit_event(Event::DueDateProposed { payment_id, due_at });
+            }
+        }
+
+        /// Validate and store a new payment, optionally holding its funds in escrow.
+        /// Returns the new payment ID.
+        ///
+        /// `due_at` must be a due block both parties already agreed on, such as that of a loan
+        /// installment or obligation occurrence.
+        #[allow(clippy::too_many_arguments)]
+        fn do_create_payment(
+            payer: T::AccountId,
             payee: T::AccountId,
             amount: u128,
             currency: Vec<u8>,
             description: Vec<u8>,
             metadata: Vec<u8>,
-        ) -> DispatchResult {
-            let payer = ensure_signed(origin)?;
+            due_at: Option<BlockNumberFor<T>>,
+            escrowed: bool,
+        ) -> Result<u64, DispatchError> {
+            ensure!(payer != payee, Error::<T>::CannotPaySelf);
 
             // Validate amount
             ensure!(amount >= T::MinPaymentAmount::get(), Error::<T>::AmountTooLow);
@@ -207,7 +2061,7 @@ pub mod pallet {
             PaymentCounter::<T>::put(payment_id);
 
             // Create payment record
-            let payment = PaymentRecord {
+            let mut payment = PaymentRecord {
                 id: payment_id,
                 payer: payer.clone(),
                 payee: payee.clone(),
@@ -220,14 +2074,26 @@ pub mod pallet {
                 completed_at: None,
                 transaction_hash: None,
                 metadata,
+                escrow: EscrowState::NotEscrowed,
+                installment: None,
+                due_at: due_at.map(|due| due.saturated_into()),
+                delinquency: None,
             };
 
+            // Hold the payer's funds in escrow
+            if escrowed {
+                Self::hold_escrow(&mut payment, payer.clone())?;
+            }
+
+            // Start tracking lateness against the due date
+            Self::update_delinquency(&mut payment);
+
             // Store payment
             Payments::<T>::insert(payment_id, &payment);
             UserPayments::<T>::insert(&payer, payment_id, ());
 
             // Add to verification queue
-            VerificationQueue::<T>::insert(payment_id, <frame_system::Pallet<T>>::block_number().saturated_into());
+            Self::enqueue_verification(payment_id);
 
             // Update statistics
             let mut stats = PaymentStats::<T>::get();
@@ -242,281 +2108,790 @@ pub mod pallet {
                 amount,
             });
 
+            Ok(payment_id)
+        }
+
+        /// Hold the payment amount on `who` and mark where the escrowed funds sit
+        fn hold_escrow(payment: &mut PaymentRecord<T>, who: T::AccountId) -> DispatchResult {
+            let amount = Self::escrow_balance(payment.amount)?;
+            T::Currency::hold(&HoldReason::PaymentEscrow.into(), &who, amount)?;
+
+            payment.escrow = if who == payment.payer {
+                EscrowState::HeldFromPayer
+            } else {
+                EscrowState::HeldFromPayee
+            };
+
+            Self::deposit_event(Event::EscrowHeld {
+                payment_id: payment.id,
+                who,
+                amount,
+            });
+
             Ok(())
         }
 
-        /// Verify a payment
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(1)]
-        pub fn verify_payment(
-            origin: OriginFor<T>,
-            payment_id: u64,
-            transaction_hash: Vec<u8>,
-        ) -> DispatchResult {
-            let verifier = ensure_signed(origin)?;
+        /// Convert a payment amount to the escrow currency's balance type
+        fn escrow_balance(amount: u128) -> Result<BalanceOf<T>, DispatchError> {
+            amount.try_into().map_err(|_| Error::<T>::EscrowAmountOverflow.into())
+        }
 
-            // Get payment
-            let mut payment = Payments::<T>::get(payment_id)
-                .ok_or(Error::<T>::PaymentNotFound)?;
+        /// Settle escrowed funds wherever they are held: `refund` goes back to the payer
+        /// and the rest of the amount to the payee. Returns the amount returned to the payer.
+        fn settle_escrow(payment: &mut PaymentRecord<T>, refund: u128) -> Result<u128, DispatchError> {
+            let holder = match payment.escrow {
+                EscrowState::HeldFromPayer => payment.payer.clone(),
+                EscrowState::HeldFromPayee => payment.payee.clone(),
+                _ => return Ok(0),
+            };
 
-            // Check payment status
-            ensure!(payment.status == PaymentStatus::Pending, Error::<T>::InvalidPaymentStatus);
+            let reason = HoldReason::PaymentEscrow.into();
+            let refund = refund.min(payment.amount);
+            let to_payer = Self::escrow_balance(refund)?;
+            let to_payee = Self::escrow_balance(payment.amount.saturating_sub(refund))?;
 
-            // Update payment
-            payment.status = PaymentStatus::Verified;
-            payment.verified_at = Some(<frame_system::Pallet<T>>::block_number().saturated_into());
-            payment.transaction_hash = Some(transaction_hash);
+            for (dest, amount) in [(&payment.payer, to_payer), (&payment.payee, to_payee)] {
+                if amount.is_zero() {
+                    continue;
+                }
+                if *dest == holder {
+                    T::Currency::release(&reason, &holder, amount, Precision::Exact)?;
+                } else {
+                    T::Currency::transfer_on_hold(
+                        &reason,
+                        &holder,
+                        dest,
+                        amount,
+                        Precision::Exact,
+                        Restriction::Free,
+                        Fortitude::Polite,
+                    )?;
+                }
+            }
 
-            // Store updated payment
-            Payments::<T>::insert(payment_id, &payment);
+            payment.escrow = if to_payee.is_zero() {
+                EscrowState::Refunded
+            } else {
+                EscrowState::Released
+            };
 
-            // Remove from verification queue
-            VerificationQueue::<T>::remove(payment_id);
+            if !to_payer.is_zero() {
+                Self::deposit_event(Event::EscrowRefunded {
+                    payment_id: payment.id,
+                    payer: payment.payer.clone(),
+                    amount: to_payer,
+                });
+            }
+            if !to_payee.is_zero() {
+                Self::deposit_event(Event::EscrowReleased {
+                    payment_id: payment.id,
+                    payee: payment.payee.clone(),
+                    amount: to_payee,
+                });
+            }
 
-            Self::deposit_event(Event::PaymentVerified {
-                payment_id,
-                verifier,
-            });
+            Ok(refund)
+        }
 
-            Ok(())
+        /// Amount a dispute outcome returns to the payer of `payment`
+        fn outcome_refund(outcome: &DisputeOutcome, payment: &PaymentRecord<T>) -> u128 {
+            match outcome {
+                DisputeOutcome::UpheldForPayer | DisputeOutcome::Annulled => payment.amount,
+                DisputeOutcome::UpheldForPayee => 0,
+                DisputeOutcome::PartialRefund { amount } => *amount,
+            }
         }
 
-        /// Complete a payment
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(2)]
-        pub fn complete_payment(
-            origin: OriginFor<T>,
-            payment_id: u64,
-        ) -> DispatchResult {
-            let _completer = ensure_signed(origin)?;
+        /// Payment status reached by a dispute outcome. An outcome that refunds the payer of a
+        /// payment whose funds the pallet does not hold (never escrowed, or released and not
+        /// held again) refunds nothing.
+        pub fn outcome_status(outcome: &DisputeOutcome, payment: &PaymentRecord<T>) -> PaymentStatus {
+            let funds_held = matches!(payment.escrow, EscrowState::HeldFromPayer | EscrowState::HeldFromPayee);
+            if !funds_held && Self::outcome_refund(outcome, payment) > 0 {
+                return PaymentStatus::Unrefunded;
+            }
+            match outcome {
+                DisputeOutcome::UpheldForPayer => PaymentStatus::Refunded,
+                DisputeOutcome::UpheldForPayee | DisputeOutcome::PartialRefund { .. } => PaymentStatus::Completed,
+                DisputeOutcome::Annulled => PaymentStatus::Annulled,
+            }
+        }
 
-            // Get payment
-            let mut payment = Payments::<T>::get(payment_id)
-                .ok_or(Error::<T>::PaymentNotFound)?;
+        /// Apply a dispute outcome: settle escrow, close the dispute and update statistics
+        fn do_resolve_dispute(mut payment: PaymentRecord<T>, outcome: &DisputeOutcome) -> DispatchResult {
+            let mut dispute = Disputes::<T>::get(payment.id)
+                .ok_or(Error::<T>::DisputeNotFound)?;
 
-            // Check payment status
-            ensure!(payment.status == PaymentStatus::Verified, Error::<T>::InvalidPaymentStatus);
+            let refund = Self::outcome_refund(outcome, &payment);
+            ensure!(refund <= payment.amount, Error::<T>::InvalidRefundAmount);
+            let status = Self::outcome_status(outcome, &payment);
 
-            // Update payment
-            payment.status = PaymentStatus::Completed;
-            payment.completed_at = Some(<frame_system::Pallet<T>>::block_number().saturated_into());
+            // Only funds the pallet still holds are refunded
+            let refunded = Self::settle_escrow(&mut payment, refund)?;
 
-            // Store updated payment
-            Payments::<T>::insert(payment_id, &payment);
+            payment.status = status.clone();
+            if status == PaymentStatus::Completed && payment.completed_at.is_none() {
+                payment.completed_at = Some(<frame_system::Pallet<T>>::block_number().saturated_into());
+            }
+            Self::update_delinquency(&mut payment);
+            Payments::<T>::insert(payment.id, &payment);
+
+            // Close the dispute; an arbiter ruling before the deadline cancels the default judgment
+            DisputeDeadlines::<T>::remove(dispute.evidence_deadline, payment.id);
+            dispute.outcome = Some(outcome.clone());
+            Disputes::<T>::insert(payment.id, dispute);
 
             // Update statistics
             let mut stats = PaymentStats::<T>::get();
-            stats.successful_payments += 1;
+            stats.disputed_payments = stats.disputed_payments.saturating_sub(1);
+            stats.resolved_disputes = stats.resolved_disputes.saturating_add(1);
+            stats.total_volume = stats.total_volume.saturating_sub(refunded);
+            match status {
+                PaymentStatus::Completed => stats.successful_payments = stats.successful_payments.saturating_add(1),
+                PaymentStatus::Refunded => stats.refunded_payments = stats.refunded_payments.saturating_add(1),
+                PaymentStatus::Annulled => stats.annulled_payments = stats.annulled_payments.saturating_add(1),
+                _ => {}
+            }
             PaymentStats::<T>::put(stats);
 
-            Self::deposit_event(Event::PaymentCompleted {
-                payment_id,
-                transaction_hash: payment.transaction_hash.unwrap_or_default(),
-            });
+            Self::note_status_change(&payment.payer, &PaymentStatus::Disputed, &status);
+            Self::note_installment_payment(&payment);
+            Self::note_occurrence_payment(&payment);
 
             Ok(())
         }
 
-        /// Mark payment as failed
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(3)]
-        pub fn fail_payment(
-            origin: OriginFor<T>,
-            payment_id: u64,
-            reason: Vec<u8>,
-        ) -> DispatchResult {
-            let _filer = ensure_signed(origin)?;
+        /// Default judgment for a dispute whose evidence period ended.
+        ///
+        /// A party that submitted evidence wins over one that did not. Without evidence
+        /// from either side the dispute goes against whoever opened it. With evidence
+        /// from both sides the dispute is left to the arbiter.
+        pub fn default_outcome(payment: &PaymentRecord<T>, dispute: &DisputeRecord<T>) -> Option<DisputeOutcome> {
+            match (dispute.payer_evidence.is_some(), dispute.payee_evidence.is_some()) {
+                (true, false) => Some(DisputeOutcome::UpheldForPayer),
+                (false, true) => Some(DisputeOutcome::UpheldForPayee),
+                (false, false) if dispute.opened_by == payment.payer => Some(DisputeOutcome::UpheldForPayee),
+                (false, false) => Some(DisputeOutcome::UpheldForPayer),
+                (true, true) => None,
+            }
+        }
 
-            // Get payment
-            let mut payment = Payments::<T>::get(payment_id)
-                .ok_or(Error::<T>::PaymentNotFound)?;
+        /// Block at which the evidence period of a dispute opened at `opened_at` ends.
+        /// A zero period ends in the next block, since the current block's deadlines
+        /// have already been processed.
+        pub(crate) fn evidence_deadline(opened_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            opened_at.saturating_add(T::DisputeEvidencePeriod::get().max(1).into())
+        }
 
-            // Check payment status
-            ensure!(payment.status == PaymentStatus::Pending || payment.status == PaymentStatus::Verified, 
-                Error::<T>::InvalidPaymentStatus);
+        /// Issue default judgments for disputes whose evidence period has ended, at most
+        /// `MaxDefaultJudgmentsPerBlock` per block.
+        fn process_dispute_deadlines(now: BlockNumberFor<T>) -> Weight {
+            process_schedule::<DisputeDeadlines<T>, DisputeCursor<T>, _, _>(
+                now,
+                T::MaxDefaultJudgmentsPerBlock::get(),
+                T::DbWeight::get(),
+                |payment_id: u64| {
+                    let judgment = match (Payments::<T>::get(payment_id), Disputes::<T>::get(payment_id)) {
+                        (Some(payment), Some(dispute)) if payment.status == PaymentStatus::Disputed => {
+                            Self::default_outcome(&payment, &dispute).map(|outcome| (payment, outcome))
+                        }
+                        _ => None,
+                    };
+
+                    if let Some((payment, outcome)) = judgment {
+                        // Settlement failures leave the dispute to the arbiter
+                        let result = frame_support::storage::with_storage_layer(|| {
+                            Self::do_resolve_dispute(payment, &outcome)
+                        });
+                        if result.is_ok() {
+                            Self::deposit_event(Event::DisputeDefaultJudgment { payment_id, outcome });
+                        }
+                    }
+                    T::WeightInfo::resolve_dispute()
+                },
+            )
+        }
 
-            // Update payment
-            payment.status = PaymentStatus::Failed;
+        /// Update the installment paid by `payment` after the payment reached a final status.
+        ///
+        /// A completed payment marks the installment paid, on time if the payment completed
+        /// on or before the due block. A failed, refunded or annulled payment unlinks it again,
+        /// reverting an earlier payment and marking it missed if the due block has passed.
+        fn note_installment_payment(payment: &PaymentRecord<T>) {
+            let (loan_id, index) = match payment.installment {
+                Some(link) => link,
+                None => return,
+            };
+            let (mut loan, mut installment) =
+                match (Loans::<T>::get(loan_id), Installments::<T>::get(loan_id, index)) {
+                    (Some(loan), Some(installment)) if installment.payment_id == Some(payment.id) => {
+                        (loan, installment)
+                    }
+                    _ => return,
+                };
+
+            let was_paid = matches!(
+                installment.status,
+                InstallmentStatus::PaidOnTime | InstallmentStatus::PaidLate
+            );
+
+            match payment.status {
+                // Already counted, e.g. a dispute upheld for the payee
+                PaymentStatus::Completed if was_paid => return,
+                PaymentStatus::Completed => {
+                    let completed_at: BlockNumberFor<T> = payment
+                        .completed_at
+                        .map(|at| at.saturated_into())
+                        .unwrap_or_else(<frame_system::Pallet<T>>::block_number);
+                    let on_time = completed_at <= installment.due;
+
+                    if installment.status == InstallmentStatus::Missed {
+                        loan.missed = loan.missed.saturating_sub(1);
+                    }
+                    if on_time {
+                        installment.status = InstallmentStatus::PaidOnTime;
+                        loan.paid_on_time += 1;
+                    } else {
+                        installment.status = InstallmentStatus::PaidLate;
+                        loan.paid_late += 1;
+                    }
 
-            // Store updated payment
-            Payments::<T>::insert(payment_id, &payment);
+                    Self::deposit_event(Event::InstallmentPaid {
+                        loan_id,
+                        index,
+                        payment_id: payment.id,
+                        on_time,
+                    });
 
-            // Update statistics
-            let mut stats = PaymentStats::<T>::get();
-            stats.failed_payments += 1;
-            PaymentStats::<T>::put(stats);
+                    if loan.paid_on_time + loan.paid_late == loan.installment_count {
+                        loan.status = LoanStatus::Repaid;
+                        Self::deposit_event(Event::LoanRepaid { loan_id });
+                    }
+                }
+                PaymentStatus::Failed
+                | PaymentStatus::Refunded
+                | PaymentStatus::Annulled
+                | PaymentStatus::Unrefunded => {
+                    match installment.status {
+                        InstallmentStatus::PaidOnTime => loan.paid_on_time = loan.paid_on_time.saturating_sub(1),
+                        InstallmentStatus::PaidLate => loan.paid_late = loan.paid_late.saturating_sub(1),
+                        _ => {}
+                    }
+                    loan.status = LoanStatus::Active;
+                    installment.payment_id = None;
+
+                    let now = <frame_system::Pallet<T>>::block_number();
+                    if now > installment.due {
+                        if installment.status != InstallmentStatus::Missed {
+                            loan.missed += 1;
+                            Self::deposit_event(Event::InstallmentMissed { loan_id, index });
+                        }
+                        installment.status = InstallmentStatus::Missed;
+                    } else {
+                        installment.status = InstallmentStatus::Scheduled;
+                    }
+                }
+                _ => return,
+            }
 
-            Self::deposit_event(Event::PaymentFailed {
-                payment_id,
-                reason,
-            });
+            Installments::<T>::insert(loan_id, index, installment);
+            Loans::<T>::insert(loan_id, loan);
+        }
 
-            Ok(())
+        /// Mark installments missed once their due block has passed without a payment, at
+        /// most `MaxDueInstallmentsPerBlock` per block. Installments with a payment still in
+        /// progress are settled when that payment reaches a final status.
+        fn process_due_installments(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<InstallmentSchedule<T>, InstallmentCursor<T>, _, _>(
+                now,
+                T::MaxDueInstallmentsPerBlock::get(),
+                db_weight,
+                |(loan_id, index): (u64, u32)| {
+                    if let Some(mut installment) = Installments::<T>::get(loan_id, index) {
+                        if installment.status == InstallmentStatus::Scheduled && installment.payment_id.is_none() {
+                            installment.status = InstallmentStatus::Missed;
+                            Installments::<T>::insert(loan_id, index, installment);
+                            Loans::<T>::mutate(loan_id, |loan| {
+                                if let Some(loan) = loan {
+                                    loan.missed += 1;
+                                }
+                            });
+                            Self::deposit_event(Event::InstallmentMissed { loan_id, index });
+                        }
+                    }
+                    db_weight.reads_writes(2, 3)
+                },
+            )
         }
 
-        /// Dispute a payment
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(4)]
-        pub fn dispute_payment(
-            origin: OriginFor<T>,
-            payment_id: u64,
-            reason: Vec<u8>,
-        ) -> DispatchResult {
-            let disputer = ensure_signed(origin)?;
+        /// Create the occurrence of the obligation's next cycle, due at `due`, and schedule
+        /// the check that follows its due block. The caller stores the obligation.
+        fn open_cycle(obligation: &mut RecurringObligation<T>, due: BlockNumberFor<T>) {
+            let cycle = obligation.cycles;
+            Occurrences::<T>::insert(obligation.id, cycle, Occurrence {
+                due,
+                status: InstallmentStatus::Scheduled,
+                payment_id: None,
+            });
 
-            // Get payment
-            let mut payment = Payments::<T>::get(payment_id)
-                .ok_or(Error::<T>::PaymentNotFound)?;
+            // Never schedule behind the cursor, even if processing fell a period behind
+            let now = <frame_system::Pallet<T>>::block_number();
+            let at = due.max(now).saturating_add(One::one());
+            ObligationSchedule::<T>::insert(at, obligation.id, ());
+            obligation.cycles += 1;
 
-            // Check if disputer is payer or payee
-            ensure!(payment.payer == disputer || payment.payee == disputer, 
-                Error::<T>::InsufficientPermissions);
+            Self::deposit_event(Event::OccurrenceCreated {
+                obligation_id: obligation.id,
+                cycle,
+                due,
+            });
+        }
 
-            // Check payment status
-            ensure!(payment.status == PaymentStatus::Verified || payment.status == PaymentStatus::Completed, 
-                Error::<T>::InvalidPaymentStatus);
+        /// Extend the payer's on-time streak by one occurrence
+        fn extend_streak(payer: &T::AccountId) {
+            OnTimeStreaks::<T>::mutate(payer, |streak| {
+                streak.current = streak.current.saturating_add(1);
+                streak.longest = streak.longest.max(streak.current);
+            });
+        }
 
-            // Update payment
-            payment.status = PaymentStatus::Disputed;
+        /// Reset the payer's current on-time streak
+        fn break_streak(payer: &T::AccountId) {
+            let length = OnTimeStreaks::<T>::mutate(payer, |streak| {
+                sp_std::mem::take(&mut streak.current)
+            });
+            if length > 0 {
+                Self::deposit_event(Event::OnTimeStreakBroken {
+                    payer: payer.clone(),
+                    length,
+                });
+            }
+        }
 
-            // Store updated payment
-            Payments::<T>::insert(payment_id, &payment);
+        /// Fold one more occurrence, in due order, into `streak`
+        fn fold_streak(streak: &mut OnTimeStreak, on_time: bool) {
+            streak.current = if on_time { streak.current.saturating_add(1) } else { 0 };
+            streak.longest = streak.longest.max(streak.current);
+        }
 
-            // Update statistics
-            let mut stats = PaymentStats::<T>::get();
-            stats.disputed_payments += 1;
-            PaymentStats::<T>::put(stats);
+        /// Record the outcome of an occurrence in the payer's streak history, keeping the
+        /// latest `MaxStreakHistory` occurrences in due order and folding older ones into the
+        /// base streak. A payer's first record starts from their current streak, so call this
+        /// before extending or breaking it.
+        fn note_streak_entry(payer: &T::AccountId, entry: StreakEntry<T>) {
+            let mut history = StreakHistories::<T>::get(payer).unwrap_or_else(|| StreakHistory {
+                base: OnTimeStreaks::<T>::get(payer),
+                recent: Vec::new(),
+            });
 
-            Self::deposit_event(Event::PaymentDisputed {
-                payment_id,
-                disputer,
-                reason,
+            let key = (entry.obligation_id, entry.cycle);
+            history.recent.retain(|recorded| (recorded.obligation_id, recorded.cycle) != key);
+            let at = history.recent.partition_point(|recorded| {
+                (recorded.due, recorded.obligation_id, recorded.cycle) < (entry.due, entry.obligation_id, entry.cycle)
             });
+            history.recent.insert(at, entry);
 
-            Ok(())
+            let excess = history.recent.len().saturating_sub(T::MaxStreakHistory::get().max(1) as usize);
+            for oldest in history.recent.drain(..excess) {
+                Self::fold_streak(&mut history.base, oldest.on_time);
+            }
+
+            StreakHistories::<T>::insert(payer, history);
         }
 
-        /// Resolve payment dispute
-        #[pallet::weight(10_000)]
-        #[pallet::call_index(5)]
-        pub fn resolve_dispute(
-            origin: OriginFor<T>,
-            payment_id: u64,
-            resolution: Vec<u8>,
-        ) -> DispatchResult {
-            let _resolver = ensure_signed(origin)?;
+        /// Recompute the payer's on-time streak from their streak history after the outcome of
+        /// occurrence (`obligation_id`, `cycle`) was reverted: a newly missed occurrence is
+        /// recorded as late, and one scheduled again leaves the history. An occurrence
+        /// scheduled again after it was folded into the base streak leaves the streak as is.
+        /// Returns the streak it replaced.
+        fn revert_streak_entry(
+            payer: &T::AccountId,
+            obligation_id: u64,
+            cycle: u32,
+            due: BlockNumberFor<T>,
+            missed: bool,
+        ) -> OnTimeStreak {
+            if missed {
+                Self::note_streak_entry(payer, StreakEntry { due, obligation_id, cycle, on_time: false });
+            } else {
+                StreakHistories::<T>::mutate_extant(payer, |history| {
+                    history.recent.retain(|recorded| (recorded.obligation_id, recorded.cycle) != (obligation_id, cycle));
+                });
+            }
 
-            // Get payment
-            let mut payment = Payments::<T>::get(payment_id)
-                .ok_or(Error::<T>::PaymentNotFound)?;
+            let history = match StreakHistories::<T>::get(payer) {
+                Some(history) => history,
+                None => return OnTimeStreaks::<T>::get(payer),
+            };
+            let mut streak = history.base;
+            for entry in history.recent {
+                Self::fold_streak(&mut streak, entry.on_time);
+            }
+            OnTimeStreaks::<T>::mutate(payer, |previous| sp_std::mem::replace(previous, streak))
+        }
 
-            // Check payment status
-            ensure!(payment.status == PaymentStatus::Disputed, Error::<T>::InvalidPaymentStatus);
+        /// Update the occurrence paid by `payment` after the payment reached a final status.
+        ///
+        /// Mirrors `note_installment_payment`, and keeps the payer's on-time streak: an on-time
+        /// payment extends it, and an occurrence breaks it the first time it turns out late or
+        /// missed. Reverting an on-time payment, or an occurrence turning out missed only after
+        /// later ones were paid, recomputes the streak from the payer's streak history.
+        fn note_occurrence_payment(payment: &PaymentRecord<T>) {
+            let (obligation_id, cycle) = match ObligationPayments::<T>::get(payment.id) {
+                Some(link) => link,
+                None => return,
+            };
+            let (mut obligation, mut occurrence) =
+                match (Obligations::<T>::get(obligation_id), Occurrences::<T>::get(obligation_id, cycle)) {
+                    (Some(obligation), Some(occurrence)) if occurrence.payment_id == Some(payment.id) => {
+                        (obligation, occurrence)
+                    }
+                    _ => return,
+                };
+
+            let was_paid = matches!(
+                occurrence.status,
+                InstallmentStatus::PaidOnTime | InstallmentStatus::PaidLate
+            );
+            // Whether a reverted payment leaves the streak to recompute, and whether it
+            // newly missed the occurrence
+            let mut recompute = None;
+
+            match payment.status {
+                // Already counted, e.g. a dispute upheld for the payee
+                PaymentStatus::Completed if was_paid => return,
+                PaymentStatus::Completed => {
+                    let completed_at: BlockNumberFor<T> = payment
+                        .completed_at
+                        .map(|at| at.saturated_into())
+                        .unwrap_or_else(<frame_system::Pallet<T>>::block_number);
+                    let on_time = completed_at <= occurrence.due;
+
+                    Self::note_streak_entry(&obligation.payer, StreakEntry {
+                        due: occurrence.due,
+                        obligation_id,
+                        cycle,
+                        on_time,
+                    });
+                    if on_time {
+                        obligation.paid_on_time += 1;
+                        Self::extend_streak(&obligation.payer);
+                    } else {
+                        obligation.paid_late += 1;
+                        // A missed occurrence already broke the streak
+                        if occurrence.status == InstallmentStatus::Scheduled {
+                            Self::break_streak(&obligation.payer);
+                        }
+                    }
+                    if occurrence.status == InstallmentStatus::Missed {
+                        obligation.missed = obligation.missed.saturating_sub(1);
+                    }
+                    occurrence.status = if on_time {
+                        InstallmentStatus::PaidOnTime
+                    } else {
+                        InstallmentStatus::PaidLate
+                    };
+
+                    Self::deposit_event(Event::OccurrencePaid {
+                        obligation_id,
+                        cycle,
+                        payment_id: payment.id,
+                        on_time,
+                    });
+                }
+                PaymentStatus::Failed
+                | PaymentStatus::Refunded
+                | PaymentStatus::Annulled
+                | PaymentStatus::Unrefunded => {
+                    let previous = occurrence.status.clone();
+                    match previous {
+                        InstallmentStatus::PaidOnTime => {
+                            obligation.paid_on_time = obligation.paid_on_time.saturating_sub(1);
+                        }
+                        InstallmentStatus::PaidLate => obligation.paid_late = obligation.paid_late.saturating_sub(1),
+                        _ => {}
+                    }
+                    occurrence.payment_id = None;
 
-            // Update payment based on resolution
-            // This would need more complex logic based on resolution type
-            payment.status = PaymentStatus::Completed;
+                    let now = <frame_system::Pallet<T>>::block_number();
+                    let newly_missed = now > occurrence.due && previous != InstallmentStatus::Missed;
+                    if newly_missed {
+                        obligation.missed += 1;
+                        Self::deposit_event(Event::OccurrenceMissed { obligation_id, cycle });
+                    }
+                    occurrence.status = if now > occurrence.due {
+                        InstallmentStatus::Missed
+                    } else {
+                        InstallmentStatus::Scheduled
+                    };
+                    if previous == InstallmentStatus::PaidOnTime || newly_missed {
+                        recompute = Some(newly_missed);
+                    }
+                }
+                _ => return,
+            }
 
-            // Store updated payment
-            Payments::<T>::insert(payment_id, &payment);
+            let due = occurrence.due;
+            Occurrences::<T>::insert(obligation_id, cycle, occurrence);
+
+            // Later occurrences may already be paid, so the streak is recomputed rather than cut
+            if let Some(newly_missed) = recompute {
+                let previous = Self::revert_streak_entry(&obligation.payer, obligation_id, cycle, due, newly_missed);
+                let current = OnTimeStreaks::<T>::get(&obligation.payer).current;
+                if newly_missed && current < previous.current {
+                    Self::deposit_event(Event::OnTimeStreakBroken {
+                        payer: obligation.payer.clone(),
+                        length: previous.current,
+                    });
+                }
+            }
 
-            Self::deposit_event(Event::PaymentDisputeResolved {
-                payment_id,
-                resolution,
+            Obligations::<T>::insert(obligation_id, obligation);
+        }
+
+        /// Check the latest occurrence of obligations whose due block has passed, marking it
+        /// missed if unpaid, and create the next occurrence of active obligations, at most
+        /// `MaxOccurrencesPerBlock` per block.
+        fn process_obligation_cycles(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<ObligationSchedule<T>, ObligationCursor<T>, _, _>(
+                now,
+                T::MaxOccurrencesPerBlock::get(),
+                db_weight,
+                |obligation_id: u64| {
+                    if let Some(mut obligation) = Obligations::<T>::get(obligation_id) {
+                        let cycle = obligation.cycles.saturating_sub(1);
+                        if let Some(mut occurrence) = Occurrences::<T>::get(obligation_id, cycle) {
+                            if occurrence.status == InstallmentStatus::Scheduled && occurrence.payment_id.is_none() {
+                                occurrence.status = InstallmentStatus::Missed;
+                                obligation.missed += 1;
+                                Self::note_streak_entry(&obligation.payer, StreakEntry {
+                                    due: occurrence.due,
+                                    obligation_id,
+                                    cycle,
+                                    on_time: false,
+                                });
+                                Self::break_streak(&obligation.payer);
+                                Self::deposit_event(Event::OccurrenceMissed { obligation_id, cycle });
+                            }
+
+                            let next_due = occurrence.due.saturating_add(obligation.period);
+                            Occurrences::<T>::insert(obligation_id, cycle, occurrence);
+
+                            if obligation.status == ObligationStatus::Active {
+                                Self::open_cycle(&mut obligation, next_due);
+                            }
+                            Obligations::<T>::insert(obligation_id, obligation);
+                        }
+                    }
+                    db_weight.reads_writes(4, 6)
+                },
+            )
+        }
+
+        /// Days past due at block `at` for a payment due at block `due`; a payment is one day
+        /// past due from the block after its due block
+        pub fn days_past_due(due: u64, at: u64) -> u32 {
+            let blocks_per_day = (T::BlocksPerDay::get() as u64).max(1);
+            (at.saturating_sub(due).saturating_add(blocks_per_day - 1) / blocks_per_day).saturated_into()
+        }
+
+        /// Block at which a payment due at `due` leaves `bucket`, if it ever does
+        fn next_delinquency_check(due: u64, bucket: DelinquencyBucket) -> Option<BlockNumberFor<T>> {
+            bucket.next_threshold(T::DefaultAfterDays::get()).map(|days| {
+                due.saturating_add((days as u64 - 1).saturating_mul(T::BlocksPerDay::get() as u64))
+                    .saturating_add(1)
+                    .saturated_into()
+            })
+        }
+
+        /// Recompute the delinquency bucket of `payment` after it was created, aged or
+        /// changed status, keeping the payer's counts and the aging schedule in sync.
+        ///
+        /// Completed payments keep the bucket they were completed in and failed payments the
+        /// past-due bucket they failed in, so failing an overdue payment does not clear it.
+        /// Refunded and annulled payments, and failed ones not yet past due, stop being
+        /// tracked. The caller stores the payment.
+        pub(crate) fn update_delinquency(payment: &mut PaymentRecord<T>) {
+            let due = match payment.due_at {
+                Some(due) => due,
+                None => return,
+            };
+            let now: u64 = <frame_system::Pallet<T>>::block_number().saturated_into();
+
+            // Drop the pending check for the current bucket
+            if let Some(at) = payment.delinquency.and_then(|bucket| Self::next_delinquency_check(due, bucket)) {
+                DelinquencySchedule::<T>::remove(at, payment.id);
+            }
+
+            let reference = payment.completed_at.unwrap_or(now);
+            let days = Self::days_past_due(due, reference);
+            let bucket = match payment.status {
+                PaymentStatus::Failed if days > 0 => {
+                    Some(DelinquencyBucket::from_days(days, T::DefaultAfterDays::get()))
+                }
+                PaymentStatus::Failed
+                | PaymentStatus::Refunded
+                | PaymentStatus::Annulled
+                | PaymentStatus::Unrefunded => None,
+                _ => Some(DelinquencyBucket::from_days(days, T::DefaultAfterDays::get())),
+            };
+
+            // Outstanding payments keep aging
+            if let Some(bucket) = bucket {
+                if payment.completed_at.is_none() && payment.status != PaymentStatus::Failed {
+                    if let Some(at) = Self::next_delinquency_check(due, bucket) {
+                        DelinquencySchedule::<T>::insert(at, payment.id, ());
+                    }
+                }
+            }
+
+            if bucket == payment.delinquency {
+                return;
+            }
+
+            PayerDelinquency::<T>::mutate(&payment.payer, |counts| {
+                if let Some(previous) = payment.delinquency {
+                    let count = counts.count_mut(previous);
+                    *count = count.saturating_sub(1);
+                }
+                if let Some(bucket) = bucket {
+                    *counts.count_mut(bucket) += 1;
+                }
             });
 
-            Ok(())
+            if let (Some(from), Some(to)) = (payment.delinquency, bucket) {
+                Self::deposit_event(Event::DelinquencyBucketChanged {
+                    payment_id: payment.id,
+                    payer: payment.payer.clone(),
+                    from,
+                    to,
+                    days_past_due: days,
+                });
+            }
+
+            payment.delinquency = bucket;
         }
-    }
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-            // Process verification queue
-            Self::process_verification_queue()
+        /// Move overdue payments to their next delinquency bucket, at most
+        /// `MaxDelinquencyChecksPerBlock` per block.
+        fn process_delinquency_checks(now: BlockNumberFor<T>) -> Weight {
+            let db_weight = T::DbWeight::get();
+
+            process_schedule::<DelinquencySchedule<T>, DelinquencyCursor<T>, _, _>(
+                now,
+                T::MaxDelinquencyChecksPerBlock::get(),
+                db_weight,
+                |payment_id: u64| {
+                    if let Some(mut payment) = Payments::<T>::get(payment_id) {
+                        Self::update_delinquency(&mut payment);
+                        Payments::<T>::insert(payment_id, &payment);
+                    }
+                    db_weight.reads_writes(2, 3)
+                },
+            )
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Process verification queue
-        fn process_verification_queue() -> Weight {
-            let current_block = <frame_system::Pallet<T>>::block_number().saturated_into();
-            let verification_period = T::PaymentVerificationPeriod::get();
+        /// Update the payer summary for a status transition and notify listeners
+        fn note_status_change(payer: &T::AccountId, from: &PaymentStatus, to: &PaymentStatus) {
+            PayerSummaries::<T>::mutate(payer, |summary| {
+                match from {
+                    PaymentStatus::Completed => summary.completed = summary.completed.saturating_sub(1),
+                    PaymentStatus::Failed => summary.failed = summary.failed.saturating_sub(1),
+                    PaymentStatus::Disputed => summary.disputed = summary.disputed.saturating_sub(1),
+                    _ => {}
+                }
+                match to {
+                    PaymentStatus::Completed => summary.completed = summary.completed.saturating_add(1),
+                    PaymentStatus::Failed => summary.failed = summary.failed.saturating_add(1),
+                    PaymentStatus::Disputed => summary.disputed = summary.disputed.saturating_add(1),
+                    _ => {}
+                }
+            });
+
+            T::OnPaymentStateChange::on_payment_state_change(payer);
+        }
+
+        /// Block at which the verification period of a payment queued at `queued_at` expires
+        pub(crate) fn verification_due(queued_at: u64) -> BlockNumberFor<T> {
+            queued_at
+                .saturating_add(T::PaymentVerificationPeriod::get() as u64)
+                .saturating_add(1)
+                .saturated_into()
+        }
+
+        /// Queue a new payment to expire if it is still unverified when the verification
+        /// period ends
+        fn enqueue_verification(payment_id: u64) {
+            let queued_at: u64 = <frame_system::Pallet<T>>::block_number().saturated_into();
+            VerificationQueue::<T>::insert(payment_id, queued_at);
+            VerificationSchedule::<T>::insert(Self::verification_due(queued_at), payment_id, ());
+        }
 
-            let mut processed = 0;
-            let mut weight = 0;
+        /// Remove a payment from the verification queue and its schedule
+        fn dequeue_verification(payment_id: u64) {
+            if let Some(queued_at) = VerificationQueue::<T>::take(payment_id) {
+                VerificationSchedule::<T>::remove(Self::verification_due(queued_at), payment_id);
+            }
+        }
 
-            for (payment_id, queued_at) in VerificationQueue::<T>::iter() {
-                if current_block - queued_at > verification_period {
-                    // Auto-verify payment after verification period
+        /// Process verification queue: fail payments still unverified when their verification
+        /// period ended, at most `MaxVerificationsPerBlock` per block.
+        ///
+        /// Only `verify_payment` with a processor receipt moves a payment to `Verified`.
+        fn process_verification_queue(now: BlockNumberFor<T>) -> Weight {
+            process_schedule::<VerificationSchedule<T>, VerificationCursor<T>, _, _>(
+                now,
+                T::MaxVerificationsPerBlock::get(),
+                T::DbWeight::get(),
+                |payment_id: u64| {
+                    VerificationQueue::<T>::remove(payment_id);
                     if let Some(mut payment) = Payments::<T>::get(payment_id) {
                         if payment.status == PaymentStatus::Pending {
-                            payment.status = PaymentStatus::Verified;
-                            payment.verified_at = Some(current_block);
-                            Payments::<T>::insert(payment_id, &payment);
-                            VerificationQueue::<T>::remove(payment_id);
-                            processed += 1;
+                            // Settlement failures leave the payment pending
+                            let result = frame_support::storage::with_storage_layer(|| {
+                                Self::do_fail_payment(&mut payment)
+                            });
+                            if result.is_ok() {
+                                Self::deposit_event(Event::PaymentVerificationExpired { payment_id });
+                            }
                         }
                     }
-                }
-                weight += 1;
-            }
+                    T::WeightInfo::fail_payment()
+                },
+            )
+        }
+    }
+
+    impl<T: Config> PaymentHistoryProvider<T::AccountId> for Pallet<T> {
+        fn payment_summary(who: &T::AccountId) -> PaymentSummary {
+            PayerSummaries::<T>::get(who)
+        }
+    }
 
-            Weight::from_parts(weight, 0)
+    impl<T: Config> OnTimeStreakProvider<T::AccountId> for Pallet<T> {
+        fn on_time_streak(who: &T::AccountId) -> OnTimeStreak {
+            OnTimeStreaks::<T>::get(who)
         }
     }
 
     #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
         pub payment_counter: u64,
         pub payment_stats: PaymentStatistics,
-    }
-
-    #[cfg(feature = "std")]
-    impl<T: Config> Default for GenesisConfig<T> {
-        fn default() -> Self {
-            Self {
-                payment_counter: 0,
-                payment_stats: PaymentStatistics::default(),
-            }
-        }
+        #[serde(skip)]
+        pub _config: PhantomData<T>,
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             PaymentCounter::<T>::put(self.payment_counter);
             PaymentStats::<T>::put(&self.payment_stats);
         }
     }
-
-    pub trait WeightInfo {
-        fn create_payment() -> Weight;
-        fn verify_payment() -> Weight;
-        fn complete_payment() -> Weight;
-        fn fail_payment() -> Weight;
-        fn dispute_payment() -> Weight;
-        fn resolve_dispute() -> Weight;
-    }
-
-    impl WeightInfo for () {
-        fn create_payment() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn verify_payment() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn complete_payment() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn fail_payment() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn dispute_payment() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-        fn resolve_dispute() -> Weight {
-            Weight::from_parts(10_000, 0)
-        }
-    }
 }
@@ -0,0 +1,654 @@
+//! Storage migrations of the payment registry: payer summaries (v1), the verification
+//! schedule (v2), escrow (v3), disputes (v4), installments (v5), delinquency (v6) and
+//! obligation cycles (v7).
+//!
+//! Payment records are accessed through `Payments` aliases typed with the layout of the
+//! version being migrated, so earlier migrations keep working as the record grows.
+
+use crate::pallet::*;
+use credchain_primitives::PaymentSummary;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Version 1: per-payer outcome summaries (`PayerSummaries`).
+pub mod v1 {
+    use super::*;
+
+    /// Backfill `PayerSummaries` from the statuses of existing payments.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return db_weight.reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut summaries: BTreeMap<T::AccountId, PaymentSummary> = BTreeMap::new();
+
+            for (_, payment) in v3::v2::Payments::<T>::iter() {
+                reads += 1;
+                let summary = summaries.entry(payment.payer).or_default();
+                match payment.status {
+                    PaymentStatus::Completed => summary.completed = summary.completed.saturating_add(1),
+                    PaymentStatus::Failed => summary.failed = summary.failed.saturating_add(1),
+                    PaymentStatus::Disputed => summary.disputed = summary.disputed.saturating_add(1),
+                    _ => {}
+                }
+            }
+
+            let writes = summaries.len() as u64 + 1;
+            for (payer, summary) in summaries {
+                PayerSummaries::<T>::insert(payer, summary);
+            }
+
+            StorageVersion::new(1).put::<Pallet<T>>();
+            log::info!(target: "runtime::payment-registry", "backfilled {} payer summaries", writes - 1);
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let payments = Payments::<T>::iter_keys().count() as u64;
+            Ok(payments.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let payments: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 1,
+                "storage version not updated"
+            );
+            ensure!(
+                Payments::<T>::iter_keys().count() as u64 == payments,
+                "payments changed during migration"
+            );
+
+            // Every recorded outcome must be reflected in its payer's summary
+            let mut expected: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+            for (_, payment) in v3::v2::Payments::<T>::iter() {
+                if matches!(
+                    payment.status,
+                    PaymentStatus::Completed | PaymentStatus::Failed | PaymentStatus::Disputed
+                ) {
+                    *expected.entry(payment.payer).or_default() += 1;
+                }
+            }
+            for (payer, outcomes) in expected {
+                let summary = PayerSummaries::<T>::get(&payer);
+                ensure!(
+                    summary.completed + summary.failed + summary.disputed == outcomes,
+                    "payer summary does not match payments"
+                );
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 2: block-indexed verification schedule processed from `VerificationCursor`.
+pub mod v2 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Schedule every queued payment, dropping entries that no longer await verification.
+    pub struct MigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 1 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads = 1u64;
+            let mut writes = 2u64;
+
+            VerificationQueue::<T>::translate::<u64, _>(|payment_id, queued_at| {
+                reads += 2;
+                writes += 1;
+
+                let pending = v3::v2::Payments::<T>::get(payment_id)
+                    .is_some_and(|payment| payment.status == PaymentStatus::Pending);
+                if !pending {
+                    return None;
+                }
+
+                // Overdue payments are processed starting from the upgrade block
+                let due: BlockNumberFor<T> = Pallet::<T>::verification_due(queued_at).max(now);
+                writes += 1;
+                VerificationSchedule::<T>::insert(due, payment_id, ());
+                Some(queued_at)
+            });
+
+            VerificationCursor::<T>::put(now);
+            StorageVersion::new(2).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let pending = VerificationQueue::<T>::iter_keys()
+                .filter(|payment_id| {
+                    v3::v2::Payments::<T>::get(payment_id)
+                        .is_some_and(|payment| payment.status == PaymentStatus::Pending)
+                })
+                .count() as u64;
+            Ok(pending.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let pending: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 2,
+                "storage version not updated"
+            );
+            ensure!(
+                VerificationQueue::<T>::iter_keys().count() as u64 == pending,
+                "queue still holds payments that are not pending"
+            );
+            ensure!(
+                VerificationSchedule::<T>::iter_keys().count() as u64 == pending,
+                "queued payments missing from the verification schedule"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 3: escrow state on payment records.
+pub mod v3 {
+    use super::*;
+    use sp_std::vec::Vec;
+
+    /// Payment records as stored up to version 2.
+    pub mod v2 {
+        use super::*;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+        pub struct PaymentRecord<AccountId> {
+            pub id: u64,
+            pub payer: AccountId,
+            pub payee: AccountId,
+            pub amount: u128,
+            pub currency: Vec<u8>,
+            pub description: Vec<u8>,
+            pub status: PaymentStatus,
+            pub created_at: u64,
+            pub verified_at: Option<u64>,
+            pub completed_at: Option<u64>,
+            pub transaction_hash: Option<Vec<u8>>,
+            pub metadata: Vec<u8>,
+        }
+
+        /// `Payments` holding records in this layout
+        #[frame_support::storage_alias]
+        pub type Payments<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            u64,
+            PaymentRecord<<T as frame_system::Config>::AccountId>,
+        >;
+    }
+
+    /// Mark every existing payment as not escrowed; no funds were held before this version.
+    pub struct MigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 2 {
+                return db_weight.reads(1);
+            }
+
+            let mut translated = 0u64;
+            v5::v4::Payments::<T>::translate::<v2::PaymentRecord<T::AccountId>, _>(|_, old| {
+                translated += 1;
+                Some(v5::v4::PaymentRecord {
+                    id: old.id,
+                    payer: old.payer,
+                    payee: old.payee,
+                    amount: old.amount,
+                    currency: old.currency,
+                    description: old.description,
+                    status: old.status,
+                    created_at: old.created_at,
+                    verified_at: old.verified_at,
+                    completed_at: old.completed_at,
+                    transaction_hash: old.transaction_hash,
+                    metadata: old.metadata,
+                    escrow: EscrowState::NotEscrowed,
+                })
+            });
+
+            StorageVersion::new(3).put::<Pallet<T>>();
+            log::info!(target: "runtime::payment-registry", "translated {} payment records", translated);
+
+            db_weight.reads_writes(translated + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let payments = Payments::<T>::iter_keys().count() as u64;
+            Ok(payments.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let payments: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "storage version not updated"
+            );
+            ensure!(
+                v5::v4::Payments::<T>::iter_values().count() as u64 == payments,
+                "payment records failed to decode after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 4: dispute records with evidence deadlines, and statistics that track the
+/// current status of each payment.
+pub mod v4 {
+    use super::*;
+
+    /// Payment statistics as stored up to version 3.
+    pub mod v3 {
+        use super::*;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+        pub struct PaymentStatistics {
+            pub total_payments: u64,
+            pub total_volume: u128,
+            pub successful_payments: u64,
+            pub failed_payments: u64,
+            pub disputed_payments: u64,
+        }
+    }
+
+    /// Recompute status counters from `Payments` and open a dispute record for every
+    /// disputed payment, with its evidence period starting at the upgrade block.
+    ///
+    /// The account that opened a pre-existing dispute was not stored; it is recorded as
+    /// the payer.
+    pub struct MigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 3 {
+                return db_weight.reads(1);
+            }
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let evidence_deadline = Pallet::<T>::evidence_deadline(now);
+            let mut reads = 2u64;
+            let mut writes = 3u64;
+
+            let mut stats = PaymentStatistics::default();
+
+            for (payment_id, payment) in v5::v4::Payments::<T>::iter() {
+                reads += 1;
+                match payment.status {
+                    PaymentStatus::Completed => stats.successful_payments += 1,
+                    PaymentStatus::Failed => stats.failed_payments += 1,
+                    PaymentStatus::Disputed => {
+                        stats.disputed_payments += 1;
+                        writes += 2;
+                        Disputes::<T>::insert(payment_id, DisputeRecord {
+                            opened_by: payment.payer.clone(),
+                            opened_at: now,
+                            evidence_deadline,
+                            payer_evidence: None,
+                            payee_evidence: None,
+                            outcome: None,
+                        });
+                        DisputeDeadlines::<T>::insert(evidence_deadline, payment_id, ());
+                    }
+                    _ => {}
+                }
+            }
+
+            // Payment totals carry over; status counters come from the payments themselves
+            let _ = PaymentStats::<T>::translate::<v3::PaymentStatistics, _>(|old| {
+                let old = old.unwrap_or_default();
+                Some(PaymentStatistics {
+                    total_payments: old.total_payments,
+                    total_volume: old.total_volume,
+                    ..stats
+                })
+            });
+            DisputeCursor::<T>::put(now);
+            StorageVersion::new(4).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let disputed = v5::v4::Payments::<T>::iter_values()
+                .filter(|payment| payment.status == PaymentStatus::Disputed)
+                .count() as u64;
+            Ok(disputed.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let disputed: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "storage version not updated"
+            );
+            ensure!(
+                PaymentStats::<T>::get().disputed_payments == disputed,
+                "open disputes not reflected in statistics"
+            );
+            ensure!(
+                Disputes::<T>::iter_keys().count() as u64 == disputed,
+                "disputed payments missing a dispute record"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 5: payment records link to the loan installment they pay.
+pub mod v5 {
+    use super::*;
+    use sp_std::vec::Vec;
+
+    /// Payment records as stored up to version 4.
+    pub mod v4 {
+        use super::*;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+        pub struct PaymentRecord<AccountId> {
+            pub id: u64,
+            pub payer: AccountId,
+            pub payee: AccountId,
+            pub amount: u128,
+            pub currency: Vec<u8>,
+            pub description: Vec<u8>,
+            pub status: PaymentStatus,
+            pub created_at: u64,
+            pub verified_at: Option<u64>,
+            pub completed_at: Option<u64>,
+            pub transaction_hash: Option<Vec<u8>>,
+            pub metadata: Vec<u8>,
+            pub escrow: EscrowState,
+        }
+
+        /// `Payments` holding records in this layout
+        #[frame_support::storage_alias]
+        pub type Payments<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            u64,
+            PaymentRecord<<T as frame_system::Config>::AccountId>,
+        >;
+    }
+
+    /// Mark every existing payment as not paying an installment; loans did not exist
+    /// before this version.
+    pub struct MigrateToV5<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 4 {
+                return db_weight.reads(1);
+            }
+
+            let mut translated = 0u64;
+            v6::v5::Payments::<T>::translate::<v4::PaymentRecord<T::AccountId>, _>(|_, old| {
+                translated += 1;
+                Some(v6::v5::PaymentRecord {
+                    id: old.id,
+                    payer: old.payer,
+                    payee: old.payee,
+                    amount: old.amount,
+                    currency: old.currency,
+                    description: old.description,
+                    status: old.status,
+                    created_at: old.created_at,
+                    verified_at: old.verified_at,
+                    completed_at: old.completed_at,
+                    transaction_hash: old.transaction_hash,
+                    metadata: old.metadata,
+                    escrow: old.escrow,
+                    installment: None,
+                })
+            });
+
+            InstallmentCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+            StorageVersion::new(5).put::<Pallet<T>>();
+            log::info!(target: "runtime::payment-registry", "translated {} payment records", translated);
+
+            db_weight.reads_writes(translated + 1, translated + 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let payments = Payments::<T>::iter_keys().count() as u64;
+            Ok(payments.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let payments: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 5,
+                "storage version not updated"
+            );
+            ensure!(
+                v6::v5::Payments::<T>::iter_values().count() as u64 == payments,
+                "payment records failed to decode after migration"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 6: due dates and delinquency buckets on payment records.
+pub mod v6 {
+    use super::*;
+    use sp_runtime::SaturatedConversion;
+    use sp_std::vec::Vec;
+
+    /// Payment records as stored up to version 5.
+    pub mod v5 {
+        use super::*;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+        pub struct PaymentRecord<AccountId> {
+            pub id: u64,
+            pub payer: AccountId,
+            pub payee: AccountId,
+            pub amount: u128,
+            pub currency: Vec<u8>,
+            pub description: Vec<u8>,
+            pub status: PaymentStatus,
+            pub created_at: u64,
+            pub verified_at: Option<u64>,
+            pub completed_at: Option<u64>,
+            pub transaction_hash: Option<Vec<u8>>,
+            pub metadata: Vec<u8>,
+            pub escrow: EscrowState,
+            pub installment: Option<(u64, u32)>,
+        }
+
+        /// `Payments` holding records in this layout
+        #[frame_support::storage_alias]
+        pub type Payments<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            u64,
+            PaymentRecord<<T as frame_system::Config>::AccountId>,
+        >;
+    }
+
+    /// Translate payment records, taking the due date of installment payments from their
+    /// installment. Other existing payments have no due date and are not tracked.
+    pub struct MigrateToV6<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 5 {
+                return db_weight.reads(1);
+            }
+
+            let mut reads = 1u64;
+            let mut writes = 2u64;
+            let mut tracked: Vec<u64> = Vec::new();
+
+            Payments::<T>::translate::<v5::PaymentRecord<T::AccountId>, _>(|payment_id, old| {
+                reads += 1;
+                writes += 1;
+
+                let due_at = old.installment.and_then(|(loan_id, index)| {
+                    reads += 1;
+                    Installments::<T>::get(loan_id, index)
+                        .map(|installment| installment.due.saturated_into::<u64>())
+                });
+                if due_at.is_some() {
+                    tracked.push(payment_id);
+                }
+
+                Some(PaymentRecord {
+                    id: old.id,
+                    payer: old.payer,
+                    payee: old.payee,
+                    amount: old.amount,
+                    currency: old.currency,
+                    description: old.description,
+                    status: old.status,
+                    created_at: old.created_at,
+                    verified_at: old.verified_at,
+                    completed_at: old.completed_at,
+                    transaction_hash: old.transaction_hash,
+                    metadata: old.metadata,
+                    escrow: old.escrow,
+                    installment: old.installment,
+                    due_at,
+                    delinquency: None,
+                })
+            });
+
+            // Bucket the installment payments and schedule the outstanding ones
+            for payment_id in tracked {
+                if let Some(mut payment) = Payments::<T>::get(payment_id) {
+                    reads += 2;
+                    writes += 3;
+                    Pallet::<T>::update_delinquency(&mut payment);
+                    Payments::<T>::insert(payment_id, payment);
+                }
+            }
+
+            DelinquencyCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+            StorageVersion::new(6).put::<Pallet<T>>();
+
+            db_weight.reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let payments = Payments::<T>::iter_keys().count() as u64;
+            Ok(payments.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let payments: u64 =
+                Decode::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 6,
+                "storage version not updated"
+            );
+            ensure!(
+                Payments::<T>::iter_values().count() as u64 == payments,
+                "payment records failed to decode after migration"
+            );
+            ensure!(
+                Payments::<T>::iter_values().all(|payment| payment.due_at.is_none()
+                    || payment.delinquency.is_some()
+                    || matches!(
+                        payment.status,
+                        PaymentStatus::Failed | PaymentStatus::Refunded | PaymentStatus::Annulled
+                    )),
+                "payment with a due date missing its delinquency bucket"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 7: recurring obligation cycles processed from `ObligationCursor`.
+pub mod v7 {
+    use super::*;
+
+    /// Start the obligation cursor at the upgrade block. No obligations exist before this
+    /// version, so there is nothing earlier to process.
+    pub struct MigrateToV7<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let db_weight = T::DbWeight::get();
+            if Pallet::<T>::on_chain_storage_version() != 6 {
+                return db_weight.reads(1);
+            }
+
+            ObligationCursor::<T>::put(frame_system::Pallet::<T>::block_number());
+            StorageVersion::new(7).put::<Pallet<T>>();
+
+            db_weight.reads_writes(1, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() == 7,
+                "storage version not updated"
+            );
+            ensure!(
+                ObligationCursor::<T>::get() == frame_system::Pallet::<T>::block_number(),
+                "obligation cursor not set to the upgrade block"
+            );
+
+            Ok(())
+        }
+    }
+}
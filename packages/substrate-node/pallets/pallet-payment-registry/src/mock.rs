@@ -0,0 +1,149 @@
+//! Runtime de teste para o pallet de registro de pagamentos
+
+use crate as pallet_payment_registry;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Everything},
+};
+use frame_system::EnsureRoot;
+use sp_core::{Pair, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        PaymentRegistry: pallet_payment_registry,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u128>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+}
+
+parameter_types! {
+    pub const MaxPaymentAmount: u128 = 1_000_000_000;
+    pub storage DisputeEvidencePeriod: u32 = 10;
+}
+
+impl pallet_payment_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxPaymentsPerUser = ConstU32<1000>;
+    type MaxPaymentAmount = MaxPaymentAmount;
+    type MinPaymentAmount = ConstU128<1>;
+    type PaymentVerificationPeriod = ConstU32<10>;
+    type MaxVerificationsPerBlock = ConstU32<2>;
+    type OnPaymentStateChange = NotifiedPayers;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type VerifierOrigin = EnsureRoot<u64>;
+    type ArbiterOrigin = EnsureRoot<u64>;
+    type ProcessorOrigin = EnsureRoot<u64>;
+    type DisputeEvidencePeriod = DisputeEvidencePeriod;
+    type MaxDefaultJudgmentsPerBlock = ConstU32<2>;
+    type LenderOrigin = EnsureRoot<u64>;
+    type MaxInstallments = ConstU32<12>;
+    type MaxDueInstallmentsPerBlock = ConstU32<2>;
+    type BlocksPerDay = ConstU32<10>;
+    type DefaultAfterDays = ConstU32<120>;
+    type MaxDelinquencyChecksPerBlock = ConstU32<2>;
+    type MinObligationPeriod = ConstU32<10>;
+    type MaxOccurrencesPerBlock = ConstU32<2>;
+    type MaxStreakHistory = ConstU32<3>;
+}
+
+thread_local! {
+    static NOTIFIED: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Registra os pagadores notificados de mudanças de estado
+pub struct NotifiedPayers;
+
+impl credchain_primitives::OnPaymentStateChange<u64> for NotifiedPayers {
+    fn on_payment_state_change(payer: &u64) {
+        NOTIFIED.with(|notified| notified.borrow_mut().push(*payer));
+    }
+}
+
+pub fn notified_payers() -> Vec<u64> {
+    NOTIFIED.with(|notified| notified.borrow().clone())
+}
+
+/// Saldo inicial das contas 1 a 5
+pub const INITIAL_BALANCE: u128 = 1_000_000;
+
+// Helper para criar extrinsics
+pub fn new_test_ext() -> TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|account| (account, INITIAL_BALANCE)).collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = TestExternalities::new(storage);
+    // Conta 3 atua como verificadora registrada e `processor_pair` como processadora
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        pallet_payment_registry::PaymentVerifiers::<Test>::insert(3, 0);
+        pallet_payment_registry::PaymentProcessors::<Test>::insert(
+            pallet_payment_registry::ProcessorKey::Ed25519(processor_pair().public()),
+            0,
+        );
+    });
+    ext
+}
+
+/// Chave ed25519 da processadora de pagamentos registrada nos testes
+pub fn processor_pair() -> sp_core::ed25519::Pair {
+    sp_core::ed25519::Pair::from_seed(&[7u8; 32])
+}
@@ -0,0 +1,1688 @@
+/*!
+ * Unit Tests for Payment Registry Pallet
+ * 
+ * Testes unitários para o pallet de registro de pagamentos
+ */
+
+use crate::{mock::*, *};
+use frame_support::{
+    assert_ok, assert_noop,
+    traits::{Get, OnInitialize},
+};
+use sp_core::Pair;
+
+/// Assina `receipt` com `pair`
+fn sign_receipt(
+    pair: &sp_core::ed25519::Pair,
+    receipt: PaymentReceipt<u64>,
+) -> (PaymentReceipt<u64>, ReceiptSignature) {
+    let signature = ReceiptSignature::Ed25519(pair.sign(&receipt.signing_payload()));
+    (receipt, signature)
+}
+
+/// Comprovante que confere com o pagamento `payment_id`
+fn test_receipt(payment_id: u64) -> PaymentReceipt<u64> {
+    let payment = PaymentRegistry::payments(payment_id).unwrap();
+    PaymentReceipt {
+        payment_id,
+        processor: ProcessorKey::Ed25519(processor_pair().public()),
+        payer: payment.payer,
+        payee: payment.payee,
+        amount: payment.amount,
+        currency: payment.currency,
+        transaction_hash: b"0xabc".to_vec(),
+        timestamp: 1_700_000_000_000,
+    }
+}
+
+/// Verifica o pagamento como `verifier` com um comprovante válido da processadora
+fn verify_with_receipt(verifier: u64, payment_id: u64) -> frame_support::dispatch::DispatchResult {
+    let (receipt, signature) = sign_receipt(&processor_pair(), test_receipt(payment_id));
+    PaymentRegistry::verify_payment(RuntimeOrigin::signed(verifier), payment_id, receipt, signature)
+}
+
+/// Cria um pagamento de `amount` BRL da conta `payer` para a conta 2
+fn create_test_payment(payer: u64, amount: u128) -> frame_support::dispatch::DispatchResult {
+    PaymentRegistry::create_payment(
+        RuntimeOrigin::signed(payer), 2, amount, b"BRL".to_vec(), vec![], vec![], None
+    )
+}
+
+/// Cria um pagamento de 1000 BRL da conta 1 para a conta 2 com vencimento em `due`,
+/// já aceito pelo recebedor
+fn create_due_payment(due: u64) -> u64 {
+    assert_ok!(PaymentRegistry::create_payment(
+        RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], Some(due)
+    ));
+    let payment_id = PaymentRegistry::payment_counter();
+    assert_ok!(PaymentRegistry::accept_due_date(RuntimeOrigin::signed(2), payment_id));
+    payment_id
+}
+
+#[test]
+fn test_payment_registry_initialization() {
+    new_test_ext().execute_with(|| {
+        // Verificar se o pallet foi inicializado corretamente
+        assert_eq!(PaymentRegistry::payments(1), None);
+        assert_eq!(PaymentRegistry::payment_counter(), 0);
+    });
+}
+
+#[test]
+fn test_record_payment_success() {
+    new_test_ext().execute_with(|| {
+        let amount = 1000;
+
+        // Registrar pagamento
+        assert_ok!(create_test_payment(1, amount));
+
+        // Verificar se foi armazenado corretamente
+        let stored_payment = PaymentRegistry::payments(1);
+        assert!(stored_payment.is_some());
+
+        let payment_data = stored_payment.unwrap();
+        assert_eq!(payment_data.payer, 1);
+        assert_eq!(payment_data.payee, 2);
+        assert_eq!(payment_data.amount, amount);
+        assert_eq!(payment_data.currency, b"BRL".to_vec());
+        assert_eq!(payment_data.status, PaymentStatus::Pending);
+        assert!(PaymentRegistry::user_payments(1, 1).is_some());
+
+        // Verificar contador
+        assert_eq!(PaymentRegistry::payment_counter(), 1);
+        System::assert_last_event(
+            Event::PaymentCreated { payment_id: 1, payer: 1, payee: 2, amount }.into()
+        );
+    });
+}
+
+#[test]
+fn test_record_payment_unauthorized() {
+    new_test_ext().execute_with(|| {
+        // Tentar registrar pagamento sem conta pagadora
+        assert_noop!(
+            PaymentRegistry::create_payment(
+                RuntimeOrigin::root(),
+                2,
+                1000,
+                b"BRL".to_vec(),
+                vec![],
+                vec![],
+                None
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_record_payment_invalid_amount() {
+    new_test_ext().execute_with(|| {
+        // Valor zero
+        assert_noop!(create_test_payment(1, 0), Error::<Test>::AmountTooLow);
+
+        // Valor acima do máximo
+        assert_noop!(
+            create_test_payment(1, MaxPaymentAmount::get() + 1),
+            Error::<Test>::AmountTooHigh
+        );
+    });
+}
+
+#[test]
+fn test_record_payment_invalid_date() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(50);
+        assert_ok!(PaymentRegistry::add_lender(RuntimeOrigin::root(), 5));
+
+        // Primeiro vencimento no passado ou no bloco atual
+        for first_due in [0, 10, 50] {
+            assert_noop!(
+                PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, first_due, 10),
+                Error::<Test>::InvalidLoanSchedule
+            );
+            assert_noop!(
+                PaymentRegistry::register_obligation(
+                    RuntimeOrigin::signed(5), 1, ObligationKind::Rent, 1000, b"BRL".to_vec(), first_due, 10
+                ),
+                Error::<Test>::InvalidObligationSchedule
+            );
+        }
+
+        // Vencimento no bloco seguinte é aceito
+        assert_ok!(PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 51, 10));
+        assert_ok!(PaymentRegistry::register_obligation(
+            RuntimeOrigin::signed(5), 1, ObligationKind::Rent, 1000, b"BRL".to_vec(), 51, 10
+        ));
+    });
+}
+
+#[test]
+fn test_update_payment_status() {
+    new_test_ext().execute_with(|| {
+        // Registrar pagamento
+        assert_ok!(create_test_payment(1, 1000));
+
+        // Atualizar status para verificado
+        assert_ok!(verify_with_receipt(3, 1));
+
+        // Verificar se foi atualizado
+        let stored_payment = PaymentRegistry::payments(1).unwrap();
+        assert_eq!(stored_payment.status, PaymentStatus::Verified);
+        assert_eq!(stored_payment.transaction_hash, Some(b"0xabc".to_vec()));
+    });
+}
+
+#[test]
+fn test_update_payment_status_not_found() {
+    new_test_ext().execute_with(|| {
+        // Tentar atualizar status de pagamento inexistente
+        assert_noop!(
+            PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 999),
+            Error::<Test>::PaymentNotFound
+        );
+    });
+}
+
+#[test]
+fn test_get_payment_not_found() {
+    new_test_ext().execute_with(|| {
+        // Verificar que não existe pagamento
+        let stored_payment = PaymentRegistry::payments(999);
+        assert!(stored_payment.is_none());
+    });
+}
+
+#[test]
+fn test_get_payment() {
+    new_test_ext().execute_with(|| {
+        // Registrar pagamento com vencimento
+        create_due_payment(100);
+
+        // Verificar se pode ser recuperado
+        let payment_data = PaymentRegistry::payments(1).unwrap();
+        assert_eq!(payment_data.amount, 1000);
+        assert_eq!(payment_data.due_at, Some(100));
+        assert_eq!(payment_data.currency, b"BRL".to_vec());
+    });
+}
+
+#[test]
+fn test_payment_types() {
+    new_test_ext().execute_with(|| {
+        // Testar diferentes moedas de pagamento
+        let currencies: [&[u8]; 4] = [b"BRL", b"USD", b"EUR", b"BTC"];
+
+        for (i, currency) in currencies.iter().enumerate() {
+            assert_ok!(PaymentRegistry::create_payment(
+                RuntimeOrigin::signed(1 + i as u64), 5, 1000, currency.to_vec(), vec![], vec![], None
+            ));
+            assert_eq!(PaymentRegistry::payments(1 + i as u64).unwrap().currency, currency.to_vec());
+        }
+
+        // Verificar contador
+        assert_eq!(PaymentRegistry::payment_counter(), 4);
+    });
+}
+
+#[test]
+fn test_payment_concurrent_updates() {
+    new_test_ext().execute_with(|| {
+        // Dois pagamentos seguidos do mesmo pagador
+        assert_ok!(create_test_payment(1, 1000));
+        assert_ok!(create_test_payment(1, 2000));
+
+        // Cada um recebe seu próprio id e nenhum sobrescreve o outro
+        assert_eq!(PaymentRegistry::payments(1).unwrap().amount, 1000);
+        assert_eq!(PaymentRegistry::payments(2).unwrap().amount, 2000);
+        assert!(PaymentRegistry::user_payments(1, 1).is_some());
+        assert!(PaymentRegistry::user_payments(1, 2).is_some());
+    });
+}
+
+#[test]
+fn test_payment_count() {
+    new_test_ext().execute_with(|| {
+        // Contador inicial
+        assert_eq!(PaymentRegistry::payment_counter(), 0);
+
+        // Adicionar primeiro pagamento
+        assert_ok!(create_test_payment(1, 1000));
+        assert_eq!(PaymentRegistry::payment_counter(), 1);
+
+        // Adicionar segundo pagamento
+        assert_ok!(create_test_payment(4, 2000));
+        assert_eq!(PaymentRegistry::payment_counter(), 2);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.total_payments, 2);
+        assert_eq!(stats.total_volume, 3000);
+    });
+}
+
+#[test]
+fn test_payment_statuses() {
+    new_test_ext().execute_with(|| {
+        // Registrar pagamento
+        assert_ok!(create_test_payment(1, 1000));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Pending);
+
+        // Percorrer os estados até a disputa
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Verified);
+
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Completed);
+
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), 1, b"cobranca".to_vec()));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Disputed);
+    });
+}
+
+#[test]
+fn test_payment_storage_limits() {
+    new_test_ext().execute_with(|| {
+        // Testar limite máximo de pagamentos por usuário
+        for _ in 0..<Test as Config>::MaxPaymentsPerUser::get() {
+            assert_ok!(create_test_payment(1, 1000));
+        }
+        assert_noop!(create_test_payment(1, 1000), Error::<Test>::TooManyPayments);
+
+        // Verificar contador
+        assert_eq!(PaymentRegistry::payment_counter(), 1000);
+    });
+}
+
+#[test]
+fn test_payment_edge_cases() {
+    new_test_ext().execute_with(|| {
+        // Valor mínimo
+        assert_ok!(create_test_payment(1, 1));
+
+        // Valor máximo
+        assert_ok!(create_test_payment(1, MaxPaymentAmount::get()));
+        assert_eq!(PaymentRegistry::payments(2).unwrap().amount, MaxPaymentAmount::get());
+    });
+}
+
+#[test]
+fn test_payment_performance() {
+    new_test_ext().execute_with(|| {
+        // Testar performance com múltiplas operações
+        for _ in 1..=100 {
+            assert_ok!(create_test_payment(1, 1000));
+        }
+
+        // Verificar contador
+        assert_eq!(PaymentRegistry::payment_counter(), 100);
+
+        // Verificar se todos os pagamentos foram armazenados
+        for i in 1..=100 {
+            let stored_payment = PaymentRegistry::payments(i);
+            assert!(stored_payment.is_some());
+            assert_eq!(stored_payment.unwrap().amount, 1000);
+        }
+    });
+}
+
+#[test]
+fn test_payment_validation() {
+    new_test_ext().execute_with(|| {
+        // Testar validação de valores
+        let valid_amounts = vec![1, 100, 1000, 10000, 100000];
+        for amount in valid_amounts {
+            assert_ok!(create_test_payment(1, amount));
+        }
+
+        // Testar valores inválidos
+        assert_noop!(create_test_payment(1, 0), Error::<Test>::AmountTooLow);
+        assert_noop!(create_test_payment(1, u128::MAX), Error::<Test>::AmountTooHigh);
+    });
+}
+
+#[test]
+fn test_payment_date_validation() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(50);
+
+        // Sem vencimento, o pagamento não entra nas faixas de atraso
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, None);
+
+        // Vencimento passado ou no bloco atual é rejeitado
+        for due in [10, 50] {
+            assert_noop!(
+                PaymentRegistry::create_payment(
+                    RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], Some(due)
+                ),
+                Error::<Test>::InvalidDueDate
+            );
+            assert_noop!(
+                PaymentRegistry::create_escrowed_payment(
+                    RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], Some(due)
+                ),
+                Error::<Test>::InvalidDueDate
+            );
+        }
+
+        // Vencimento futuro proposto pelo pagador só vale depois do aceite do recebedor
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], Some(60)
+        ));
+        System::assert_has_event(Event::DueDateProposed { payment_id: 2, due_at: 60 }.into());
+        assert_eq!(PaymentRegistry::proposed_due_date(2), Some(60));
+        assert_eq!(PaymentRegistry::payments(2).unwrap().due_at, None);
+        assert_eq!(PaymentRegistry::payments(2).unwrap().delinquency, None);
+
+        assert_noop!(
+            PaymentRegistry::accept_due_date(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_noop!(
+            PaymentRegistry::accept_due_date(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::NoProposedDueDate
+        );
+        assert_ok!(PaymentRegistry::accept_due_date(RuntimeOrigin::signed(2), 2));
+        System::assert_has_event(Event::DueDateAccepted { payment_id: 2, due_at: 60 }.into());
+        assert_eq!(PaymentRegistry::proposed_due_date(2), None);
+        assert_eq!(PaymentRegistry::payments(2).unwrap().due_at, Some(60));
+        assert_eq!(PaymentRegistry::payments(2).unwrap().delinquency, Some(DelinquencyBucket::Current));
+        assert_eq!(PaymentRegistry::payer_delinquency(1).current, 1);
+
+        // Proposta que venceu antes do aceite não pode mais ser aceita
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], Some(55)
+        ));
+        frame_system::Pallet::<Test>::set_block_number(55);
+        assert_noop!(
+            PaymentRegistry::accept_due_date(RuntimeOrigin::signed(2), 3),
+            Error::<Test>::InvalidDueDate
+        );
+
+        // Pagamento encerrado descarta a proposta pendente
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(1), 3, b"desisto".to_vec()));
+        assert_eq!(PaymentRegistry::proposed_due_date(3), None);
+    });
+}
+
+#[test]
+fn test_payment_cleanup() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_due_payment(10);
+        assert!(PaymentRegistry::verification_queue(1).is_some());
+        assert_eq!(VerificationSchedule::<Test>::iter().count(), 1);
+        assert_eq!(DelinquencySchedule::<Test>::iter().count(), 1);
+
+        // A verificação retira o pagamento da fila
+        assert_ok!(verify_with_receipt(3, 1));
+        assert!(PaymentRegistry::verification_queue(1).is_none());
+        assert_eq!(VerificationSchedule::<Test>::iter().count(), 0);
+
+        // A conclusão encerra o acompanhamento de atraso; o registro permanece
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+        assert_eq!(DelinquencySchedule::<Test>::iter().count(), 0);
+        assert!(PaymentRegistry::payments(1).is_some());
+    });
+}
+
+#[test]
+fn test_payer_summary_tracks_payment_outcomes() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::PaymentHistoryProvider;
+
+        // Pagamento concluído
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+
+        // Pagamento com falha
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), 2, b"saldo".to_vec()));
+
+        let summary = PaymentRegistry::payment_summary(&1);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.failed, 1);
+
+        // Disputa move o pagamento concluído para disputado
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), 1, b"cobranca".to_vec()));
+        let summary = PaymentRegistry::payment_summary(&1);
+        assert_eq!(summary.completed, 0);
+        assert_eq!(summary.disputed, 1);
+    });
+}
+
+#[test]
+fn test_complete_payment_updates_summary_and_notifies() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::PaymentHistoryProvider;
+
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_eq!(PaymentRegistry::payment_summary(&1).completed, 0);
+
+        // Conclusão atualiza o resumo do pagador e notifica o pallet de score
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+        assert_eq!(PaymentRegistry::payment_summary(&1).completed, 1);
+        assert_eq!(notified_payers(), vec![1]);
+    });
+}
+
+#[test]
+fn test_migration_v0_to_v1_backfills_payer_summaries() {
+    use crate::migrations::v1::MigrateToV1;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), 2, b"saldo".to_vec()));
+
+        // Estado da versão 0: pagamentos sem resumo por pagador
+        let _ = PayerSummaries::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<PaymentRegistry>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(PaymentRegistry::on_chain_storage_version(), 1);
+        let summary = PaymentRegistry::payer_summary(1);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.disputed, 0);
+    });
+}
+
+#[test]
+fn test_verification_queue_expires_unverified_payments() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..3 {
+            assert_ok!(create_test_payment(1, 1000));
+        }
+        // Pagamento verificado com comprovante sai da fila
+        assert_ok!(verify_with_receipt(3, 3));
+
+        let due = PaymentRegistry::verification_due(1);
+        run_to_block(due - 1);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Pending);
+
+        // Sem comprovante, o pagamento falha ao fim do período de verificação
+        run_to_block(due + 1);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().status, PaymentStatus::Failed);
+        assert_eq!(PaymentRegistry::payments(2).unwrap().status, PaymentStatus::Failed);
+        assert_eq!(PaymentRegistry::payments(3).unwrap().status, PaymentStatus::Verified);
+        assert_eq!(PaymentRegistry::verification_queue(1), None);
+        assert!(PaymentRegistry::verification_cursor() > due);
+        System::assert_has_event(Event::PaymentVerificationExpired { payment_id: 1 }.into());
+
+        let summary = PaymentRegistry::payer_summary(1);
+        assert_eq!(summary.failed, 2);
+        assert_eq!(PaymentRegistry::payment_stats().failed_payments, 2);
+    });
+}
+
+#[test]
+fn test_expired_escrowed_payment_refunds_payer() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{Inspect, InspectHold};
+
+        let reason = RuntimeHoldReason::from(HoldReason::PaymentEscrow);
+        assert_ok!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_eq!(Balances::balance(&1), INITIAL_BALANCE - 1000);
+
+        run_to_block(PaymentRegistry::verification_due(1) + 1);
+
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+        assert_eq!(Balances::balance(&1), INITIAL_BALANCE);
+        let payment = PaymentRegistry::payments(1).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Failed);
+        assert_eq!(payment.escrow, EscrowState::Refunded);
+    });
+}
+
+#[test]
+fn test_verification_backlog_carries_over() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..5 {
+            assert_ok!(create_test_payment(1, 1000));
+        }
+
+        let due = PaymentRegistry::verification_due(1);
+        let expired = || {
+            (1..=5u64)
+                .filter(|id| PaymentRegistry::payments(id).unwrap().status == PaymentStatus::Failed)
+                .count()
+        };
+
+        // O cursor alcança o bloco de vencimento e processa no máximo 2 pagamentos por bloco
+        run_to_block(due);
+        let first = expired();
+        assert!(first <= 2);
+
+        let mut block = due;
+        while expired() < 5 {
+            assert!(block < due + 10);
+            block += 1;
+            frame_system::Pallet::<Test>::set_block_number(block);
+            let before = expired();
+            PaymentRegistry::on_initialize(block);
+            assert!(expired() - before <= 2);
+        }
+        assert_eq!(PaymentRegistry::verification_queue(5), None);
+    });
+}
+
+#[test]
+fn test_payment_to_self_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PaymentRegistry::create_payment(
+                RuntimeOrigin::signed(1), 1, 1000, b"BRL".to_vec(), vec![], vec![], None
+            ),
+            Error::<Test>::CannotPaySelf
+        );
+        assert_noop!(
+            PaymentRegistry::create_escrowed_payment(
+                RuntimeOrigin::signed(2), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+            ),
+            Error::<Test>::CannotPaySelf
+        );
+    });
+}
+
+#[test]
+fn test_escrowed_payment_releases_funds_to_payee() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{Inspect, InspectHold};
+
+        let reason = RuntimeHoldReason::from(HoldReason::PaymentEscrow);
+        let payee_before = Balances::balance(&2);
+
+        assert_ok!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 1000);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().escrow, EscrowState::HeldFromPayer);
+
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+
+        // Fundos saem da retenção do pagador e vão para o recebedor
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+        assert_eq!(Balances::balance(&2), payee_before + 1000);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().escrow, EscrowState::Released);
+    });
+}
+
+#[test]
+fn test_escrowed_payment_refunded_on_failure() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{Inspect, InspectHold};
+
+        let reason = RuntimeHoldReason::from(HoldReason::PaymentEscrow);
+        let payer_before = Balances::balance(&1);
+
+        assert_ok!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_eq!(Balances::balance(&1), payer_before - 1000);
+
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), 1, b"saldo".to_vec()));
+
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+        assert_eq!(Balances::balance(&1), payer_before);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().escrow, EscrowState::Refunded);
+    });
+}
+
+#[test]
+fn test_escrowed_funds_frozen_while_disputed() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::InspectHold;
+
+        let reason = RuntimeHoldReason::from(HoldReason::PaymentEscrow);
+
+        assert_ok!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+
+        // Disputa retém novamente os fundos já liberados ao recebedor
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), 1, b"cobranca".to_vec()));
+        assert_eq!(Balances::balance_on_hold(&reason, &2), 1000);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().escrow, EscrowState::HeldFromPayee);
+
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), 1, DisputeOutcome::UpheldForPayee, b"improcedente".to_vec()
+        ));
+        assert_eq!(Balances::balance_on_hold(&reason, &2), 0);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().escrow, EscrowState::Released);
+    });
+}
+
+#[test]
+fn test_escrowed_payment_requires_funds() {
+    new_test_ext().execute_with(|| {
+        // Conta 9 não possui saldo para reter
+        assert!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(9), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        )
+        .is_err());
+        assert_eq!(PaymentRegistry::payments(1), None);
+    });
+}
+
+#[test]
+fn test_transition_requires_role() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+
+        // Estranho e partes não verificam o pagamento
+        assert_noop!(
+            verify_with_receipt(7, 1),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_noop!(
+            verify_with_receipt(2, 1),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_noop!(
+            PaymentRegistry::fail_payment(RuntimeOrigin::signed(7), 1, b"saldo".to_vec()),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(verify_with_receipt(3, 1));
+
+        // Recebedor não conclui o próprio pagamento; pagador pode
+        assert_noop!(
+            PaymentRegistry::complete_payment(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(1), 1));
+
+        // Verificador não abre disputa
+        assert_noop!(
+            PaymentRegistry::dispute_payment(RuntimeOrigin::signed(3), 1, b"cobranca".to_vec()),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(2), 1, b"cobranca".to_vec()));
+
+        // Apenas o árbitro resolve a disputa
+        assert_noop!(
+            PaymentRegistry::resolve_dispute(
+                RuntimeOrigin::signed(3), 1, DisputeOutcome::UpheldForPayee, b"improcedente".to_vec()
+            ),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), 1, DisputeOutcome::UpheldForPayee, b"improcedente".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn test_transition_table_rejects_invalid_status() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+
+        // Pendente não pode ser concluído nem disputado
+        assert_noop!(
+            PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::InvalidPaymentStatus
+        );
+        assert_noop!(
+            PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), 1, b"cobranca".to_vec()),
+            Error::<Test>::InvalidPaymentStatus
+        );
+        assert!(PaymentRegistry::transition_roles(&PaymentStatus::Failed, &PaymentStatus::Completed).is_empty());
+    });
+}
+
+#[test]
+fn test_verifier_registration() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PaymentRegistry::add_verifier(RuntimeOrigin::signed(1), 5),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(PaymentRegistry::add_verifier(RuntimeOrigin::root(), 5));
+        assert_noop!(
+            PaymentRegistry::add_verifier(RuntimeOrigin::root(), 5),
+            Error::<Test>::VerifierAlreadyExists
+        );
+        assert!(PaymentRegistry::payment_verifiers(5).is_some());
+
+        assert_ok!(PaymentRegistry::remove_verifier(RuntimeOrigin::root(), 5));
+        assert_noop!(
+            PaymentRegistry::remove_verifier(RuntimeOrigin::root(), 5),
+            Error::<Test>::VerifierNotFound
+        );
+    });
+}
+
+/// Cria um pagamento com custódia, verificado, concluído e disputado pelo pagador
+fn disputed_escrowed_payment() -> u64 {
+    assert_ok!(PaymentRegistry::create_escrowed_payment(
+        RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+    ));
+    let payment_id = PaymentRegistry::payment_counter();
+    assert_ok!(verify_with_receipt(3, payment_id));
+    assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+    assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+    payment_id
+}
+
+#[test]
+fn test_dispute_upheld_for_payer_refunds_payer() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Inspect;
+
+        let payer_before = Balances::balance(&1);
+        let payment_id = disputed_escrowed_payment();
+
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::UpheldForPayer, b"procedente".to_vec()
+        ));
+
+        let payment = PaymentRegistry::payments(payment_id).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Refunded);
+        assert_eq!(payment.escrow, EscrowState::Refunded);
+        assert_eq!(Balances::balance(&1), payer_before);
+        assert_eq!(
+            PaymentRegistry::disputes(payment_id).unwrap().outcome,
+            Some(DisputeOutcome::UpheldForPayer)
+        );
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.successful_payments, 0);
+        assert_eq!(stats.disputed_payments, 0);
+        assert_eq!(stats.refunded_payments, 1);
+        assert_eq!(stats.resolved_disputes, 1);
+        assert_eq!(stats.total_volume, 0);
+    });
+}
+
+#[test]
+fn test_dispute_without_payee_funds_opens_without_escrow() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::{Inspect, Mutate};
+
+        assert_ok!(PaymentRegistry::create_escrowed_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        let payment_id = PaymentRegistry::payment_counter();
+        assert_ok!(verify_with_receipt(3, payment_id));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+
+        // Recebedor já gastou os fundos liberados
+        assert_eq!(Balances::set_balance(&2, 1), 1);
+        let payer_before = Balances::balance(&1);
+
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+        System::assert_has_event(Event::EscrowNotHeld { payment_id, who: 2 }.into());
+
+        let payment = PaymentRegistry::payments(payment_id).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Disputed);
+        assert_eq!(payment.escrow, EscrowState::Released);
+        assert!(PaymentRegistry::disputes(payment_id).is_some());
+
+        // Disputa procedente sem escrow não movimenta fundos nem conta como reembolso
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::UpheldForPayer, b"procedente".to_vec()
+        ));
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().status, PaymentStatus::Unrefunded);
+        assert_eq!(Balances::balance(&1), payer_before);
+        assert_eq!(Balances::balance(&2), 1);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.refunded_payments, 0);
+        assert_eq!(stats.total_volume, 1000);
+        assert_eq!(stats.resolved_disputes, 1);
+    });
+}
+
+/// Payment disputed after its escrow was released to a payee that has since spent the funds
+fn disputed_unrecoverable_payment() -> u64 {
+    use frame_support::traits::fungible::Mutate;
+
+    assert_ok!(PaymentRegistry::create_escrowed_payment(
+        RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+    ));
+    let payment_id = PaymentRegistry::payment_counter();
+    assert_ok!(verify_with_receipt(3, payment_id));
+    assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+    assert_eq!(Balances::set_balance(&2, 1), 1);
+    assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+    assert_eq!(PaymentRegistry::payments(payment_id).unwrap().escrow, EscrowState::Released);
+    payment_id
+}
+
+#[test]
+fn test_partial_refund_without_escrow_is_unrefunded() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Inspect;
+
+        let payment_id = disputed_unrecoverable_payment();
+        let payer_before = Balances::balance(&1);
+
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::PartialRefund { amount: 400 }, vec![]
+        ));
+
+        // Nada foi reembolsado, então o pagamento não conta como concluído
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().status, PaymentStatus::Unrefunded);
+        assert_eq!(Balances::balance(&1), payer_before);
+        assert_eq!(Balances::balance(&2), 1);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.successful_payments, 0);
+        assert_eq!(stats.total_volume, 1000);
+        assert_eq!(stats.resolved_disputes, 1);
+    });
+}
+
+#[test]
+fn test_annulled_without_escrow_is_unrefunded() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Inspect;
+
+        let payment_id = disputed_unrecoverable_payment();
+        let payer_before = Balances::balance(&1);
+
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::Annulled, vec![]
+        ));
+
+        // O volume continua contando os fundos que ficaram com o recebedor
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().status, PaymentStatus::Unrefunded);
+        assert_eq!(Balances::balance(&1), payer_before);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.annulled_payments, 0);
+        assert_eq!(stats.total_volume, 1000);
+    });
+}
+
+#[test]
+fn test_dispute_of_non_escrowed_payment_is_unrefunded() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Inspect;
+
+        // Pagamento apenas registrado: nenhum fundo passa pelo pallet
+        for outcome in [DisputeOutcome::UpheldForPayer, DisputeOutcome::Annulled] {
+            assert_ok!(PaymentRegistry::create_payment(
+                RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+            ));
+            let payment_id = PaymentRegistry::payment_counter();
+            assert_ok!(verify_with_receipt(3, payment_id));
+            assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+            assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+
+            let payer_before = Balances::balance(&1);
+            let payee_before = Balances::balance(&2);
+            assert_ok!(PaymentRegistry::resolve_dispute(RuntimeOrigin::root(), payment_id, outcome, vec![]));
+
+            let payment = PaymentRegistry::payments(payment_id).unwrap();
+            assert_eq!(payment.status, PaymentStatus::Unrefunded);
+            assert_eq!(payment.escrow, EscrowState::NotEscrowed);
+            assert_eq!(Balances::balance(&1), payer_before);
+            assert_eq!(Balances::balance(&2), payee_before);
+        }
+
+        // Nada foi devolvido, então o volume não diminui
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.refunded_payments, 0);
+        assert_eq!(stats.annulled_payments, 0);
+        assert_eq!(stats.total_volume, 2000);
+        assert_eq!(stats.resolved_disputes, 2);
+    });
+}
+
+#[test]
+fn test_dispute_partial_refund_splits_escrow() {
+    new_test_ext().execute_with(|| {
+        use frame_support::traits::fungible::Inspect;
+
+        let payer_before = Balances::balance(&1);
+        let payee_before = Balances::balance(&2);
+        let payment_id = disputed_escrowed_payment();
+
+        assert_noop!(
+            PaymentRegistry::resolve_dispute(
+                RuntimeOrigin::root(), payment_id, DisputeOutcome::PartialRefund { amount: 1001 }, vec![]
+            ),
+            Error::<Test>::InvalidRefundAmount
+        );
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::PartialRefund { amount: 400 }, vec![]
+        ));
+
+        // 400 voltam ao pagador e 600 ficam com o recebedor
+        assert_eq!(Balances::balance(&1), payer_before - 600);
+        assert_eq!(Balances::balance(&2), payee_before + 600);
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().status, PaymentStatus::Completed);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.successful_payments, 1);
+        assert_eq!(stats.total_volume, 600);
+        assert_eq!(PaymentRegistry::payer_summary(1).completed, 1);
+    });
+}
+
+#[test]
+fn test_dispute_annulled() {
+    new_test_ext().execute_with(|| {
+        let payment_id = disputed_escrowed_payment();
+
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::Annulled, vec![]
+        ));
+
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().status, PaymentStatus::Annulled);
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.annulled_payments, 1);
+        assert_eq!(stats.disputed_payments, 0);
+
+        // Pagamento anulado não pode ser disputado novamente
+        assert_noop!(
+            PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, vec![]),
+            Error::<Test>::InvalidPaymentStatus
+        );
+    });
+}
+
+#[test]
+fn test_dispute_evidence_deadline() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        let payment_id = disputed_escrowed_payment();
+        let evidence = sp_core::H256::repeat_byte(1);
+
+        assert_noop!(
+            PaymentRegistry::submit_dispute_evidence(RuntimeOrigin::signed(7), payment_id, evidence),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::submit_dispute_evidence(RuntimeOrigin::signed(2), payment_id, evidence));
+        assert_eq!(PaymentRegistry::disputes(payment_id).unwrap().payee_evidence, Some(evidence));
+
+        // Após o prazo de 10 blocos não se aceita nova evidência
+        frame_system::Pallet::<Test>::set_block_number(11);
+        assert_noop!(
+            PaymentRegistry::submit_dispute_evidence(RuntimeOrigin::signed(1), payment_id, evidence),
+            Error::<Test>::EvidencePeriodClosed
+        );
+    });
+}
+
+#[test]
+fn test_zero_evidence_period_still_judged_by_default() {
+    new_test_ext().execute_with(|| {
+        DisputeEvidencePeriod::set(&0);
+        for block in 1..=5 {
+            frame_system::Pallet::<Test>::set_block_number(block);
+            PaymentRegistry::on_initialize(block);
+        }
+        let payment_id = disputed_escrowed_payment();
+
+        // Período nulo vira um bloco, processado no bloco seguinte
+        assert_eq!(PaymentRegistry::disputes(payment_id).unwrap().evidence_deadline, 6);
+        frame_system::Pallet::<Test>::set_block_number(6);
+        PaymentRegistry::on_initialize(6);
+        assert_eq!(
+            PaymentRegistry::disputes(payment_id).unwrap().outcome,
+            Some(DisputeOutcome::UpheldForPayee)
+        );
+    });
+}
+
+#[test]
+fn test_default_judgment_on_timeout() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+
+        // Só o recebedor apresenta evidência: julgamento a favor do recebedor
+        let with_payee_evidence = disputed_escrowed_payment();
+        assert_ok!(PaymentRegistry::submit_dispute_evidence(
+            RuntimeOrigin::signed(2), with_payee_evidence, sp_core::H256::repeat_byte(1)
+        ));
+
+        // Ninguém apresenta evidência: julgamento contra quem abriu a disputa (pagador)
+        let without_evidence = disputed_escrowed_payment();
+
+        // Ambos apresentam evidência: fica com o árbitro
+        let with_both = disputed_escrowed_payment();
+        assert_ok!(PaymentRegistry::submit_dispute_evidence(
+            RuntimeOrigin::signed(1), with_both, sp_core::H256::repeat_byte(2)
+        ));
+        assert_ok!(PaymentRegistry::submit_dispute_evidence(
+            RuntimeOrigin::signed(2), with_both, sp_core::H256::repeat_byte(3)
+        ));
+
+        let deadline = PaymentRegistry::disputes(with_both).unwrap().evidence_deadline;
+        for block in 2..=deadline + 2 {
+            frame_system::Pallet::<Test>::set_block_number(block);
+            PaymentRegistry::on_initialize(block);
+        }
+
+        assert_eq!(
+            PaymentRegistry::disputes(with_payee_evidence).unwrap().outcome,
+            Some(DisputeOutcome::UpheldForPayee)
+        );
+        assert_eq!(
+            PaymentRegistry::disputes(without_evidence).unwrap().outcome,
+            Some(DisputeOutcome::UpheldForPayee)
+        );
+        assert_eq!(PaymentRegistry::disputes(with_both).unwrap().outcome, None);
+        assert_eq!(PaymentRegistry::payments(with_both).unwrap().status, PaymentStatus::Disputed);
+
+        let stats = PaymentRegistry::payment_stats();
+        assert_eq!(stats.disputed_payments, 1);
+        assert_eq!(stats.resolved_disputes, 2);
+        assert_eq!(stats.successful_payments, 2);
+    });
+}
+
+/// Credencia o credor 5 e registra um empréstimo de 3 parcelas para o tomador 1,
+/// vencendo nos blocos 10, 20 e 30, aceito pelo tomador
+fn register_test_loan() -> u64 {
+    frame_system::Pallet::<Test>::set_block_number(1);
+    assert_ok!(PaymentRegistry::add_lender(RuntimeOrigin::root(), 5));
+    assert_ok!(PaymentRegistry::register_loan(
+        RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 10, 10
+    ));
+    let loan_id = PaymentRegistry::loan_counter();
+    assert_ok!(PaymentRegistry::accept_loan(RuntimeOrigin::signed(1), loan_id));
+    loan_id
+}
+
+/// Avança os blocos executando `on_initialize`
+fn run_to_block(to: u64) {
+    let mut block = frame_system::Pallet::<Test>::block_number();
+    while block < to {
+        block += 1;
+        frame_system::Pallet::<Test>::set_block_number(block);
+        PaymentRegistry::on_initialize(block);
+    }
+}
+
+#[test]
+fn test_register_loan_generates_installments() {
+    new_test_ext().execute_with(|| {
+        // Apenas credores credenciados registram empréstimos
+        assert_noop!(
+            PaymentRegistry::register_loan(RuntimeOrigin::signed(7), 1, 3000, b"BRL".to_vec(), 1000, 3, 10, 10),
+            Error::<Test>::InsufficientPermissions
+        );
+
+        let loan_id = register_test_loan();
+        let loan = PaymentRegistry::loans(loan_id).unwrap();
+        assert_eq!(loan.installment_count, 3);
+        assert_eq!(loan.status, LoanStatus::Active);
+
+        let dues: Vec<u64> = (0..3).map(|i| PaymentRegistry::installments(loan_id, i).unwrap().due).collect();
+        assert_eq!(dues, vec![10, 20, 30]);
+
+        // Cronograma inválido
+        assert_noop!(
+            PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 13, 10, 10),
+            Error::<Test>::InvalidLoanSchedule
+        );
+        assert_noop!(
+            PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 1, 10),
+            Error::<Test>::InvalidLoanSchedule
+        );
+
+        // IDs de empréstimo esgotados
+        crate::LoanCounter::<Test>::put(u64::MAX);
+        assert_noop!(
+            PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 10, 10),
+            Error::<Test>::LoanIdOverflow
+        );
+    });
+}
+
+#[test]
+fn test_loan_requires_borrower_acceptance() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        assert_ok!(PaymentRegistry::add_lender(RuntimeOrigin::root(), 5));
+
+        // O credor não pode emprestar a si mesmo
+        assert_noop!(
+            PaymentRegistry::register_loan(RuntimeOrigin::signed(5), 5, 3000, b"BRL".to_vec(), 1000, 3, 10, 10),
+            Error::<Test>::InsufficientPermissions
+        );
+
+        // Sem aceite não há parcelas nem pagamento possível
+        assert_ok!(PaymentRegistry::register_loan(
+            RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 10, 10
+        ));
+        let loan_id = PaymentRegistry::loan_counter();
+        assert_eq!(PaymentRegistry::loans(loan_id).unwrap().status, LoanStatus::Proposed);
+        assert!(PaymentRegistry::installments(loan_id, 0).is_none());
+        assert_noop!(
+            PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, 0, false, vec![]),
+            Error::<Test>::InstallmentNotFound
+        );
+
+        // Apenas o tomador aceita, uma única vez
+        assert_noop!(
+            PaymentRegistry::accept_loan(RuntimeOrigin::signed(5), loan_id),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::accept_loan(RuntimeOrigin::signed(1), loan_id));
+        System::assert_last_event(Event::LoanAccepted { loan_id }.into());
+        assert_noop!(
+            PaymentRegistry::accept_loan(RuntimeOrigin::signed(1), loan_id),
+            Error::<Test>::InvalidLoanStatus
+        );
+
+        // Aceite após o primeiro vencimento não é possível
+        assert_ok!(PaymentRegistry::register_loan(
+            RuntimeOrigin::signed(5), 1, 3000, b"BRL".to_vec(), 1000, 3, 10, 10
+        ));
+        frame_system::Pallet::<Test>::set_block_number(10);
+        assert_noop!(
+            PaymentRegistry::accept_loan(RuntimeOrigin::signed(1), loan_id + 1),
+            Error::<Test>::InvalidLoanSchedule
+        );
+    });
+}
+
+#[test]
+fn test_installments_track_on_time_late_and_missed() {
+    new_test_ext().execute_with(|| {
+        let loan_id = register_test_loan();
+
+        // Parcela 0 paga antes do vencimento
+        assert_ok!(PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, 0, false, vec![]));
+        let payment_id = PaymentRegistry::installments(loan_id, 0).unwrap().payment_id.unwrap();
+        assert_eq!(PaymentRegistry::payments(payment_id).unwrap().installment, Some((loan_id, 0)));
+        assert_noop!(
+            PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, 0, false, vec![]),
+            Error::<Test>::InstallmentNotPayable
+        );
+        assert_ok!(verify_with_receipt(3, payment_id));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+        assert_eq!(PaymentRegistry::installments(loan_id, 0).unwrap().status, InstallmentStatus::PaidOnTime);
+
+        // Parcela 1 vence sem pagamento
+        run_to_block(21);
+        assert_eq!(PaymentRegistry::installments(loan_id, 1).unwrap().status, InstallmentStatus::Missed);
+        assert_eq!(PaymentRegistry::loans(loan_id).unwrap().missed, 1);
+
+        // Pagamento posterior conta como atrasado
+        assert_ok!(PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, 1, false, vec![]));
+        let payment_id = PaymentRegistry::installments(loan_id, 1).unwrap().payment_id.unwrap();
+        assert_ok!(verify_with_receipt(3, payment_id));
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+
+        let loan = PaymentRegistry::loans(loan_id).unwrap();
+        assert_eq!(loan.paid_on_time, 1);
+        assert_eq!(loan.paid_late, 1);
+        assert_eq!(loan.missed, 0);
+        assert_eq!(PaymentRegistry::installments(loan_id, 1).unwrap().status, InstallmentStatus::PaidLate);
+    });
+}
+
+#[test]
+fn test_failed_installment_payment_after_due_is_missed() {
+    new_test_ext().execute_with(|| {
+        let loan_id = register_test_loan();
+
+        // Pagamento em andamento no vencimento não marca a parcela como perdida
+        assert_ok!(PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, 0, false, vec![]));
+        let payment_id = PaymentRegistry::installments(loan_id, 0).unwrap().payment_id.unwrap();
+        run_to_block(11);
+        assert_eq!(PaymentRegistry::installments(loan_id, 0).unwrap().status, InstallmentStatus::Scheduled);
+
+        // Falha após o vencimento
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), payment_id, b"saldo".to_vec()));
+        let installment = PaymentRegistry::installments(loan_id, 0).unwrap();
+        assert_eq!(installment.status, InstallmentStatus::Missed);
+        assert_eq!(installment.payment_id, None);
+        assert_eq!(PaymentRegistry::loans(loan_id).unwrap().missed, 1);
+    });
+}
+
+#[test]
+fn test_loan_repaid_after_last_installment() {
+    new_test_ext().execute_with(|| {
+        let loan_id = register_test_loan();
+
+        for index in 0..3 {
+            assert_ok!(PaymentRegistry::pay_installment(RuntimeOrigin::signed(1), loan_id, index, false, vec![]));
+            let payment_id = PaymentRegistry::installments(loan_id, index).unwrap().payment_id.unwrap();
+            assert_ok!(verify_with_receipt(3, payment_id));
+            assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+        }
+
+        let loan = PaymentRegistry::loans(loan_id).unwrap();
+        assert_eq!(loan.status, LoanStatus::Repaid);
+        assert_eq!(loan.paid_on_time, 3);
+    });
+}
+
+#[test]
+fn test_delinquency_buckets_from_days() {
+    assert_eq!(DelinquencyBucket::from_days(0, 120), DelinquencyBucket::Current);
+    assert_eq!(DelinquencyBucket::from_days(1, 120), DelinquencyBucket::Days1To30);
+    assert_eq!(DelinquencyBucket::from_days(30, 120), DelinquencyBucket::Days1To30);
+    assert_eq!(DelinquencyBucket::from_days(31, 120), DelinquencyBucket::Days31To60);
+    assert_eq!(DelinquencyBucket::from_days(90, 120), DelinquencyBucket::Days61To90);
+    assert_eq!(DelinquencyBucket::from_days(91, 120), DelinquencyBucket::Over90);
+    assert_eq!(DelinquencyBucket::from_days(120, 120), DelinquencyBucket::Defaulted);
+
+    // Com 10 blocos por dia, um bloco de atraso já conta como um dia
+    assert_eq!(PaymentRegistry::days_past_due(100, 100), 0);
+    assert_eq!(PaymentRegistry::days_past_due(100, 101), 1);
+    assert_eq!(PaymentRegistry::days_past_due(100, 110), 1);
+    assert_eq!(PaymentRegistry::days_past_due(100, 111), 2);
+}
+
+#[test]
+fn test_outstanding_payment_ages_through_buckets() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_due_payment(10);
+        assert_ok!(verify_with_receipt(3, 1));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Current));
+        assert_eq!(PaymentRegistry::payer_delinquency(1).current, 1);
+
+        // Dia 1 de atraso a partir do bloco 11; dia 31 a partir do bloco 311
+        run_to_block(11);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Days1To30));
+        run_to_block(311);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Days31To60));
+
+        let counts = PaymentRegistry::payer_delinquency(1);
+        assert_eq!(counts.current, 0);
+        assert_eq!(counts.days_31_to_60, 1);
+        assert_eq!(counts.worst(), Some(DelinquencyBucket::Days31To60));
+
+        // Conclusão congela a faixa
+        assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), 1));
+        run_to_block(1300);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Days31To60));
+    });
+}
+
+#[test]
+fn test_outstanding_payment_reaches_default() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_due_payment(10);
+        // Verificado mas ainda não concluído: o atraso continua contando
+        assert_ok!(verify_with_receipt(3, 1));
+
+        // 120 dias de atraso a partir do bloco 10 + 119 * 10 + 1
+        run_to_block(1200);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Over90));
+        run_to_block(1201);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Defaulted));
+        assert_eq!(PaymentRegistry::payer_delinquency(1).defaulted, 1);
+    });
+}
+
+#[test]
+fn test_failed_payment_leaves_delinquency_counts() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_due_payment(10);
+        run_to_block(11);
+        assert_eq!(PaymentRegistry::payer_delinquency(1).days_1_to_30, 1);
+
+        // Falha após o vencimento congela a faixa em atraso
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), 1, b"saldo".to_vec()));
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Days1To30));
+        assert_eq!(PaymentRegistry::payer_delinquency(1).days_1_to_30, 1);
+        run_to_block(400);
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Days1To30));
+
+        // Falha antes do vencimento deixa de ser acompanhada
+        create_due_payment(500);
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(3), 2, b"saldo".to_vec()));
+        assert_eq!(PaymentRegistry::payments(2).unwrap().delinquency, None);
+        assert_eq!(PaymentRegistry::payer_delinquency(1).current, 0);
+
+        // Pagamento sem vencimento não é acompanhado
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_eq!(PaymentRegistry::payments(3).unwrap().delinquency, None);
+    });
+}
+
+#[test]
+fn test_payer_cannot_clear_own_delinquency_by_failing() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+        create_due_payment(10);
+
+        // Mais de 90 dias de atraso, ainda pendente
+        frame_system::Pallet::<Test>::set_block_number(1000);
+        assert_ok!(PaymentRegistry::fail_payment(RuntimeOrigin::signed(1), 1, b"desisto".to_vec()));
+
+        assert_eq!(PaymentRegistry::payments(1).unwrap().delinquency, Some(DelinquencyBucket::Over90));
+        let counts = PaymentRegistry::payer_delinquency(1);
+        assert_eq!(counts.current, 0);
+        assert_eq!(counts.over_90, 1);
+        assert_eq!(counts.worst(), Some(DelinquencyBucket::Over90));
+    });
+}
+
+/// Registra um aluguel de 1000 do pagador 1 ao credor 5, com vencimentos a cada 10 blocos
+/// a partir do bloco 10, já aceito pelo pagador
+fn register_test_obligation() -> u64 {
+    frame_system::Pallet::<Test>::set_block_number(1);
+    assert_ok!(PaymentRegistry::register_obligation(
+        RuntimeOrigin::signed(5), 1, ObligationKind::Rent, 1000, b"BRL".to_vec(), 10, 10
+    ));
+    let obligation_id = PaymentRegistry::obligation_counter();
+    assert_ok!(PaymentRegistry::accept_obligation(RuntimeOrigin::signed(1), obligation_id));
+    obligation_id
+}
+
+/// Paga e conclui a ocorrência `cycle` no bloco atual
+fn pay_test_occurrence(obligation_id: u64, cycle: u32) {
+    assert_ok!(PaymentRegistry::pay_obligation(RuntimeOrigin::signed(1), obligation_id, cycle, false, vec![]));
+    let payment_id = PaymentRegistry::occurrences(obligation_id, cycle).unwrap().payment_id.unwrap();
+    assert_ok!(verify_with_receipt(3, payment_id));
+    assert_ok!(PaymentRegistry::complete_payment(RuntimeOrigin::signed(3), payment_id));
+}
+
+#[test]
+fn test_obligation_creates_occurrences_each_cycle() {
+    new_test_ext().execute_with(|| {
+        frame_system::Pallet::<Test>::set_block_number(1);
+
+        // Período abaixo do mínimo
+        assert_noop!(
+            PaymentRegistry::register_obligation(
+                RuntimeOrigin::signed(5), 1, ObligationKind::Utility, 1000, b"BRL".to_vec(), 10, 5
+            ),
+            Error::<Test>::InvalidObligationSchedule
+        );
+
+        // Sem aceite do pagador não há ocorrências
+        assert_ok!(PaymentRegistry::register_obligation(
+            RuntimeOrigin::signed(5), 1, ObligationKind::Rent, 1000, b"BRL".to_vec(), 10, 10
+        ));
+        assert_eq!(PaymentRegistry::occurrences(1, 0), None);
+        assert_noop!(
+            PaymentRegistry::accept_obligation(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::accept_obligation(RuntimeOrigin::signed(1), 1));
+        assert_eq!(PaymentRegistry::occurrences(1, 0).unwrap().due, 10);
+
+        // Cada vencimento gera a ocorrência do ciclo seguinte
+        run_to_block(21);
+        assert_eq!(PaymentRegistry::occurrences(1, 0).unwrap().status, InstallmentStatus::Missed);
+        assert_eq!(PaymentRegistry::occurrences(1, 1).unwrap().due, 20);
+        assert_eq!(PaymentRegistry::occurrences(1, 2).unwrap().due, 30);
+        let obligation = PaymentRegistry::obligations(1).unwrap();
+        assert_eq!(obligation.cycles, 3);
+        assert_eq!(obligation.missed, 2);
+    });
+}
+
+#[test]
+fn test_on_time_streak_tracks_consecutive_occurrences() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::OnTimeStreakProvider;
+
+        let obligation_id = register_test_obligation();
+
+        // Dois ciclos pagos em dia
+        pay_test_occurrence(obligation_id, 0);
+        run_to_block(11);
+        pay_test_occurrence(obligation_id, 1);
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (2, 2));
+
+        // Ciclo 2 vence sem pagamento e zera a sequência atual
+        run_to_block(31);
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (0, 2));
+
+        // Pagar depois do vencimento não volta a contar
+        pay_test_occurrence(obligation_id, 2);
+        assert_eq!(PaymentRegistry::occurrences(obligation_id, 2).unwrap().status, InstallmentStatus::PaidLate);
+        assert_eq!(PaymentRegistry::on_time_streak(&1).current, 0);
+        assert_eq!(PaymentRegistry::obligations(obligation_id).unwrap().paid_on_time, 2);
+    });
+}
+
+#[test]
+fn test_refunded_earlier_occurrence_keeps_later_streak() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::OnTimeStreakProvider;
+
+        let obligation_id = register_test_obligation();
+
+        // Três ciclos pagos em dia
+        pay_test_occurrence(obligation_id, 0);
+        run_to_block(11);
+        pay_test_occurrence(obligation_id, 1);
+        run_to_block(21);
+        pay_test_occurrence(obligation_id, 2);
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (3, 3));
+
+        // O primeiro ciclo é reembolsado depois do vencimento
+        run_to_block(35);
+        let payment_id = ObligationPayments::<Test>::iter()
+            .find(|(_, link)| *link == (obligation_id, 0))
+            .map(|(payment_id, _)| payment_id)
+            .unwrap();
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::UpheldForPayer, vec![]
+        ));
+        assert_eq!(PaymentRegistry::occurrences(obligation_id, 0).unwrap().status, InstallmentStatus::Missed);
+
+        // Os ciclos pagos em dia depois dele continuam contando, e a maior sequência encolhe
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (2, 2));
+        assert_eq!(PaymentRegistry::obligations(obligation_id).unwrap().paid_on_time, 2);
+    });
+}
+
+#[test]
+fn test_streak_history_keeps_latest_occurrences() {
+    new_test_ext().execute_with(|| {
+        use credchain_primitives::OnTimeStreakProvider;
+
+        let obligation_id = register_test_obligation();
+
+        // Quatro ciclos pagos em dia; o histórico guarda só os três últimos
+        pay_test_occurrence(obligation_id, 0);
+        for cycle in 1..4 {
+            run_to_block(cycle as u64 * 10 + 1);
+            pay_test_occurrence(obligation_id, cycle);
+        }
+        let history = PaymentRegistry::streak_histories(1).unwrap();
+        assert_eq!(history.recent.iter().map(|entry| entry.cycle).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((history.base.current, history.base.longest), (1, 1));
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (4, 4));
+
+        // O último ciclo é reembolsado depois do vencimento: recalculado só a partir do histórico
+        run_to_block(45);
+        let payment_id = PaymentRegistry::occurrences(obligation_id, 3).unwrap().payment_id.unwrap();
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::UpheldForPayer, vec![]
+        ));
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (0, 3));
+        System::assert_has_event(Event::OnTimeStreakBroken { payer: 1, length: 4 }.into());
+
+        // Um ciclo já incorporado à base que passa a perdido interrompe a sequência da base
+        let payment_id = ObligationPayments::<Test>::iter()
+            .find(|(_, link)| *link == (obligation_id, 0))
+            .map(|(payment_id, _)| payment_id)
+            .unwrap();
+        assert_ok!(PaymentRegistry::dispute_payment(RuntimeOrigin::signed(1), payment_id, b"cobranca".to_vec()));
+        assert_ok!(PaymentRegistry::resolve_dispute(
+            RuntimeOrigin::root(), payment_id, DisputeOutcome::UpheldForPayer, vec![]
+        ));
+        let history = PaymentRegistry::streak_histories(1).unwrap();
+        assert_eq!(history.recent.len(), 3);
+        assert_eq!((history.base.current, history.base.longest), (0, 1));
+        let streak = PaymentRegistry::on_time_streak(&1);
+        assert_eq!((streak.current, streak.longest), (0, 2));
+    });
+}
+
+#[test]
+fn test_cancelled_obligation_stops_cycles() {
+    new_test_ext().execute_with(|| {
+        let obligation_id = register_test_obligation();
+
+        // Apenas credor e pagador cancelam
+        assert_noop!(
+            PaymentRegistry::cancel_obligation(RuntimeOrigin::signed(2), obligation_id),
+            Error::<Test>::InsufficientPermissions
+        );
+        assert_ok!(PaymentRegistry::cancel_obligation(RuntimeOrigin::signed(1), obligation_id));
+
+        // A ocorrência em aberto ainda conta como perdida, mas nenhuma nova é criada
+        run_to_block(30);
+        assert_eq!(PaymentRegistry::occurrences(obligation_id, 0).unwrap().status, InstallmentStatus::Missed);
+        assert_eq!(PaymentRegistry::occurrences(obligation_id, 1), None);
+        assert_eq!(PaymentRegistry::obligations(obligation_id).unwrap().cycles, 1);
+    });
+}
+
+#[test]
+fn test_migration_v6_to_v7_starts_obligation_cursor() {
+    use crate::migrations::v7::MigrateToV7;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // Estado da versão 6: cursor de obrigações ausente
+        StorageVersion::new(6).put::<PaymentRegistry>();
+        frame_system::Pallet::<Test>::set_block_number(500);
+
+        MigrateToV7::<Test>::on_runtime_upgrade();
+        assert_eq!(PaymentRegistry::on_chain_storage_version(), 7);
+        assert_eq!(PaymentRegistry::obligation_cursor(), 500);
+
+        // Reexecutar não altera nada
+        frame_system::Pallet::<Test>::set_block_number(600);
+        MigrateToV7::<Test>::on_runtime_upgrade();
+        assert_eq!(PaymentRegistry::obligation_cursor(), 500);
+
+        // O primeiro vencimento após o upgrade é processado no próprio bloco
+        frame_system::Pallet::<Test>::set_block_number(500);
+        assert_ok!(PaymentRegistry::register_obligation(
+            RuntimeOrigin::signed(5), 1, ObligationKind::Rent, 1000, b"BRL".to_vec(), 510, 10
+        ));
+        assert_ok!(PaymentRegistry::accept_obligation(RuntimeOrigin::signed(1), 1));
+        run_to_block(511);
+        assert_eq!(PaymentRegistry::occurrences(1, 0).unwrap().status, InstallmentStatus::Missed);
+        assert_eq!(PaymentRegistry::occurrences(1, 1).unwrap().due, 520);
+    });
+}
+
+#[test]
+fn test_verify_payment_requires_valid_receipt() {
+    new_test_ext().execute_with(|| {
+        use sp_core::Pair;
+
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        let verify = |(receipt, signature): (PaymentReceipt<u64>, ReceiptSignature)| {
+            PaymentRegistry::verify_payment(RuntimeOrigin::signed(3), 1, receipt, signature)
+        };
+        let other = sp_core::ed25519::Pair::from_seed(&[9u8; 32]);
+
+        // Chave não registrada como processadora
+        let receipt = PaymentReceipt {
+            processor: ProcessorKey::Ed25519(other.public()),
+            ..test_receipt(1)
+        };
+        assert_noop!(verify(sign_receipt(&other, receipt)), Error::<Test>::ProcessorNotFound);
+
+        // Assinatura de outra chave
+        assert_noop!(
+            verify(sign_receipt(&other, test_receipt(1))),
+            Error::<Test>::InvalidReceiptSignature
+        );
+
+        // Comprovante alterado após a assinatura
+        let (mut receipt, signature) = sign_receipt(&processor_pair(), test_receipt(1));
+        receipt.amount = 2000;
+        assert_noop!(verify((receipt, signature)), Error::<Test>::InvalidReceiptSignature);
+
+        // Valor, moeda ou referências divergentes do pagamento
+        let receipt = PaymentReceipt { amount: 999, ..test_receipt(1) };
+        assert_noop!(verify(sign_receipt(&processor_pair(), receipt)), Error::<Test>::ReceiptAmountMismatch);
+        let receipt = PaymentReceipt { currency: b"USD".to_vec(), ..test_receipt(1) };
+        assert_noop!(verify(sign_receipt(&processor_pair(), receipt)), Error::<Test>::ReceiptAmountMismatch);
+        let receipt = PaymentReceipt { payee: 4, ..test_receipt(1) };
+        assert_noop!(verify(sign_receipt(&processor_pair(), receipt)), Error::<Test>::ReceiptReferenceMismatch);
+        let receipt = PaymentReceipt { payment_id: 2, ..test_receipt(1) };
+        assert_noop!(verify(sign_receipt(&processor_pair(), receipt)), Error::<Test>::ReceiptReferenceMismatch);
+        let receipt = PaymentReceipt { transaction_hash: vec![], ..test_receipt(1) };
+        assert_noop!(verify(sign_receipt(&processor_pair(), receipt)), Error::<Test>::InvalidTransactionHash);
+
+        // Comprovante válido verifica o pagamento e fica registrado
+        assert_ok!(verify(sign_receipt(&processor_pair(), test_receipt(1))));
+        let payment = PaymentRegistry::payments(1).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Verified);
+        assert_eq!(payment.transaction_hash, Some(b"0xabc".to_vec()));
+        assert_eq!(PaymentRegistry::payment_receipts(1).unwrap().0, test_receipt(1));
+    });
+}
+
+#[test]
+fn test_processor_registration() {
+    new_test_ext().execute_with(|| {
+        use sp_core::Pair;
+
+        let key = ProcessorKey::Ed25519(processor_pair().public());
+
+        // Apenas a origem configurada gerencia processadoras
+        assert_noop!(
+            PaymentRegistry::remove_processor(RuntimeOrigin::signed(1), key.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PaymentRegistry::add_processor(RuntimeOrigin::root(), key.clone()),
+            Error::<Test>::ProcessorAlreadyExists
+        );
+        assert_ok!(PaymentRegistry::remove_processor(RuntimeOrigin::root(), key.clone()));
+
+        // Comprovantes da chave removida deixam de ser aceitos
+        assert_ok!(PaymentRegistry::create_payment(
+            RuntimeOrigin::signed(1), 2, 1000, b"BRL".to_vec(), vec![], vec![], None
+        ));
+        assert_noop!(verify_with_receipt(3, 1), Error::<Test>::ProcessorNotFound);
+
+        assert_ok!(PaymentRegistry::add_processor(RuntimeOrigin::root(), key));
+        assert_ok!(verify_with_receipt(3, 1));
+    });
+}
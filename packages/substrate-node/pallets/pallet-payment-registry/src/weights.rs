@@ -0,0 +1,294 @@
+//! Weights for the payment registry pallet.
+//!
+//! Storage accesses reflect the worst case of each benchmark in `benchmarking.rs`.
+//! Execution times are initial estimates and must be replaced with the output of the
+//! command below, run on reference hardware, whenever an extrinsic or its benchmark
+//! changes:
+//!
+//! ```text
+//! ./target/release/credchain-node benchmark pallet \
+//!     --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet_payment_registry --extrinsic='*' \
+//!     --wasm-execution=compiled --heap-pages=4096 \
+//!     --output=pallets/pallet-payment-registry/src/weights.rs
+//! ```
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for the payment registry pallet.
+pub trait WeightInfo {
+    fn create_payment(p: u32, ) -> Weight;
+    fn verify_payment() -> Weight;
+    fn complete_payment() -> Weight;
+    fn fail_payment() -> Weight;
+    fn dispute_payment() -> Weight;
+    fn resolve_dispute() -> Weight;
+    fn create_escrowed_payment(p: u32, ) -> Weight;
+    fn add_verifier() -> Weight;
+    fn remove_verifier() -> Weight;
+    fn submit_dispute_evidence() -> Weight;
+    fn add_lender() -> Weight;
+    fn remove_lender() -> Weight;
+    fn register_loan() -> Weight;
+    fn accept_loan(i: u32, ) -> Weight;
+    fn pay_installment(p: u32, ) -> Weight;
+    fn register_obligation() -> Weight;
+    fn accept_obligation() -> Weight;
+    fn cancel_obligation() -> Weight;
+    fn pay_obligation(p: u32, ) -> Weight;
+    fn add_processor() -> Weight;
+    fn remove_processor() -> Weight;
+    fn accept_due_date() -> Weight;
+}
+
+/// Weights for the payment registry pallet, using the runtime's database weights.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn create_payment(p: u32, ) -> Weight {
+        Weight::from_parts(36_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    fn verify_payment() -> Weight {
+        Weight::from_parts(79_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn complete_payment() -> Weight {
+        Weight::from_parts(72_000_000, 6_200)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+    fn fail_payment() -> Weight {
+        Weight::from_parts(63_000_000, 6_200)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn dispute_payment() -> Weight {
+        Weight::from_parts(58_000_000, 6_200)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(85_000_000, 6_200)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(13_u64))
+    }
+    fn create_escrowed_payment(p: u32, ) -> Weight {
+        Weight::from_parts(65_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    fn add_verifier() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_verifier() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn submit_dispute_evidence() -> Weight {
+        Weight::from_parts(21_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn add_lender() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_lender() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn register_loan() -> Weight {
+        Weight::from_parts(22_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn accept_loan(i: u32, ) -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(Weight::from_parts(5_100_000, 0).saturating_mul(i.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
+    }
+    fn pay_installment(p: u32, ) -> Weight {
+        Weight::from_parts(76_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn register_obligation() -> Weight {
+        Weight::from_parts(22_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn accept_obligation() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn cancel_obligation() -> Weight {
+        Weight::from_parts(17_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn pay_obligation(p: u32, ) -> Weight {
+        Weight::from_parts(76_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn add_processor() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn remove_processor() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }    fn accept_due_date() -> Weight {
+        Weight::from_parts(27_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+}
+
+/// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn create_payment(p: u32, ) -> Weight {
+        Weight::from_parts(36_000_000, 3_500)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn verify_payment() -> Weight {
+        Weight::from_parts(79_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn complete_payment() -> Weight {
+        Weight::from_parts(72_000_000, 6_200)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+    fn fail_payment() -> Weight {
+        Weight::from_parts(63_000_000, 6_200)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn dispute_payment() -> Weight {
+        Weight::from_parts(58_000_000, 6_200)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(85_000_000, 6_200)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+    }
+    fn create_escrowed_payment(p: u32, ) -> Weight {
+        Weight::from_parts(65_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+    fn add_verifier() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_verifier() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn submit_dispute_evidence() -> Weight {
+        Weight::from_parts(21_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn add_lender() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_lender() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn register_loan() -> Weight {
+        Weight::from_parts(22_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn accept_loan(i: u32, ) -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(Weight::from_parts(5_100_000, 0).saturating_mul(i.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(i.into())))
+    }
+    fn pay_installment(p: u32, ) -> Weight {
+        Weight::from_parts(76_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn register_obligation() -> Weight {
+        Weight::from_parts(22_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn accept_obligation() -> Weight {
+        Weight::from_parts(24_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn cancel_obligation() -> Weight {
+        Weight::from_parts(17_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn pay_obligation(p: u32, ) -> Weight {
+        Weight::from_parts(76_000_000, 6_200)
+            .saturating_add(Weight::from_parts(2_600_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn add_processor() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn remove_processor() -> Weight {
+        Weight::from_parts(16_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }    fn accept_due_date() -> Weight {
+        Weight::from_parts(27_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # CredChain Primitives
+//!
+//! Tipos e traits compartilhados entre os pallets do CredChain. Permitem que
+//! um pallet consuma dados de outro sem depender diretamente dele: o runtime
+//! conecta as implementações através do `Config` de cada pallet.
+
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use frame_support::{
+    storage::{IterableStorageDoubleMap, StorageValue},
+    weights::{RuntimeDbWeight, Weight},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{One, Saturating},
+    RuntimeDebug,
+};
+
+/// Resumo do histórico de pagamentos de uma conta como pagadora
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct PaymentSummary {
+    /// Pagamentos concluídos
+    pub completed: u32,
+    /// Pagamentos que falharam
+    pub failed: u32,
+    /// Pagamentos atualmente em disputa
+    pub disputed: u32,
+}
+
+impl PaymentSummary {
+    /// Total de pagamentos com desfecho relevante para o histórico
+    pub fn total(&self) -> u32 {
+        self.completed
+            .saturating_add(self.failed)
+            .saturating_add(self.disputed)
+    }
+}
+
+/// Fornece o histórico de pagamentos de uma conta
+pub trait PaymentHistoryProvider<AccountId> {
+    /// Resumo dos pagamentos em que a conta é pagadora
+    fn payment_summary(who: &AccountId) -> PaymentSummary;
+}
+
+impl<AccountId> PaymentHistoryProvider<AccountId> for () {
+    fn payment_summary(_who: &AccountId) -> PaymentSummary {
+        PaymentSummary::default()
+    }
+}
+
+/// Sequência de vencimentos recorrentes (aluguel, contas de consumo, telefonia)
+/// pagos em dia por uma conta
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct OnTimeStreak {
+    /// Vencimentos consecutivos pagos em dia até agora
+    pub current: u32,
+    /// Maior sequência já alcançada
+    pub longest: u32,
+}
+
+/// Fornece a sequência de pagamentos em dia de uma conta
+pub trait OnTimeStreakProvider<AccountId> {
+    /// Sequência atual e mais longa da conta como pagadora
+    fn on_time_streak(who: &AccountId) -> OnTimeStreak;
+}
+
+impl<AccountId> OnTimeStreakProvider<AccountId> for () {
+    fn on_time_streak(_who: &AccountId) -> OnTimeStreak {
+        OnTimeStreak::default()
+    }
+}
+
+/// Notificado quando um pagamento muda de estado
+pub trait OnPaymentStateChange<AccountId> {
+    /// Chamado após a mudança de estado de um pagamento do pagador
+    fn on_payment_state_change(payer: &AccountId);
+}
+
+impl<AccountId> OnPaymentStateChange<AccountId> for () {
+    fn on_payment_state_change(_payer: &AccountId) {}
+}
+
+/// Drena uma agenda indexada por bloco, do cursor até `now`, tratando no máximo
+/// `cap` entradas com `handle`, que retorna o peso de cada uma.
+///
+/// Cada bloco vazio visitado também consome uma unidade do limite, para que um
+/// cursor atrasado avance sem leituras ilimitadas. O restante fica para o próximo bloco.
+pub fn process_schedule<Schedule, Cursor, BlockNumber, K>(
+    now: BlockNumber,
+    cap: u32,
+    db_weight: RuntimeDbWeight,
+    mut handle: impl FnMut(K) -> Weight,
+) -> Weight
+where
+    Schedule: IterableStorageDoubleMap<BlockNumber, K, ()>,
+    Cursor: StorageValue<BlockNumber, Query = BlockNumber>,
+    BlockNumber: FullCodec + PartialOrd + Saturating + One,
+    K: FullCodec,
+{
+    let mut weight = db_weight.reads_writes(1, 1);
+    let mut remaining = cap;
+    let mut cursor = Cursor::get();
+
+    while cursor <= now && remaining > 0 {
+        let mut processed = 0u32;
+        for (key, ()) in Schedule::drain_prefix(&cursor).take(remaining as usize) {
+            processed += 1;
+            weight = weight.saturating_add(handle(key));
+        }
+
+        // O limite foi atingido antes de saber se a agenda do bloco ficou vazia
+        if processed == remaining {
+            break;
+        }
+
+        remaining = remaining.saturating_sub(processed.max(1));
+        weight = weight.saturating_add(db_weight.reads(1));
+        cursor = cursor.saturating_add(One::one());
+    }
+
+    Cursor::put(cursor);
+
+    weight
+}
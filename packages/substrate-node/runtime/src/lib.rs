@@ -1,63 +1,124 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+// `construct_runtime!` faz recursão profunda
+#![recursion_limit = "256"]
 
 //! CredChain Runtime
 //! 
 //! Runtime principal do CredChain que integra todos os pallets customizados
 //! para o sistema de credit scoring descentralizado.
 
-pub mod constants;
-pub mod currency;
+// WASM do runtime gerado pelo `build.rs`
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use frame_support::{
-    construct_runtime, parameter_types,
-    traits::{ConstU32, ConstU64, ConstU128, Everything},
-    weights::Weight,
+    construct_runtime, derive_impl,
+    genesis_builder_helper::{build_config, create_default_config},
+    parameter_types,
+    traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Everything},
+    weights::{
+        constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
+        IdentityFee, Weight,
+    },
 };
-use frame_system as system;
-use sp_core::H256;
+use pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
+use sp_api::impl_runtime_apis;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H256};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Perbill,
+    create_runtime_str, generic, impl_opaque_keys,
+    traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, OpaqueKeys, Verify},
+    transaction_validity::{TransactionSource, TransactionValidity},
+    ApplyExtrinsicResult, MultiSignature, Perbill, Permill,
 };
+#[cfg(feature = "std")]
+use sp_version::NativeVersion;
+use sp_version::RuntimeVersion;
 
-// Importa os pallets customizados
-use pallet_credit_score;
-use pallet_payment_registry;
-use pallet_identity_verification;
-use pallet_oracle_integration;
+use constants::{DAYS, MILLISECS_PER_BLOCK, MINUTES};
+use sp_std::prelude::*;
 
-/// Configuração do sistema
-pub type Block = frame_system::mocking::MockBlock<Runtime>;
-pub type AccountId = sp_core::crypto::AccountId32;
+/// Tipos primitivos do runtime
+pub type Signature = MultiSignature;
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
 pub type Balance = u128;
 pub type BlockNumber = u32;
+pub type Nonce = u32;
 pub type Hash = H256;
 
-/// Configuração do runtime
-#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
-impl system::Config for Runtime {
+/// Tipos opacos usados pelo nó, que não conhece as extrinsics do runtime
+pub mod opaque {
+    use super::*;
+
+    pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
+
+    pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+    pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+    pub type BlockId = generic::BlockId<Block>;
+
+    impl_opaque_keys! {
+        pub struct SessionKeys {
+            pub aura: Aura,
+            pub grandpa: Grandpa,
+        }
+    }
+}
+
+/// Versão do runtime
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("credchain"),
+    impl_name: create_runtime_str!("credchain"),
+    authoring_version: 1,
+    spec_version: 100,
+    impl_version: 1,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 1,
+    state_version: 1,
+};
+
+/// Versão nativa, usada pelo nó para comparar com o WASM on-chain
+#[cfg(feature = "std")]
+pub fn native_version() -> NativeVersion {
+    NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
+}
+
+/// Duração do slot do Aura, igual ao tempo de bloco
+pub const SLOT_DURATION: u64 = MILLISECS_PER_BLOCK;
+
+const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+
+parameter_types! {
+    pub const BlockHashCount: BlockNumber = 2400;
+    pub const Version: RuntimeVersion = VERSION;
+    /// Dois segundos de computação por bloco de seis segundos
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::with_sensible_defaults(
+            Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+            NORMAL_DISPATCH_RATIO,
+        );
+    pub BlockLength: frame_system::limits::BlockLength =
+        frame_system::limits::BlockLength::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
+    pub const SS58Prefix: u8 = 42;
+}
+
+/// Configuração do sistema
+#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
     type BaseCallFilter = Everything;
-    type BlockWeights = ();
-    type BlockLength = ();
-    type DbWeight = ();
-    type RuntimeOrigin = RuntimeOrigin;
-    type RuntimeCall = RuntimeCall;
-    type Nonce = u32;
+    type BlockWeights = BlockWeights;
+    type BlockLength = BlockLength;
+    type DbWeight = RocksDbWeight;
+    type Nonce = Nonce;
     type Hash = Hash;
-    type Hashing = BlakeTwo256;
     type AccountId = AccountId;
-    type Lookup = IdentityLookup<Self::AccountId>;
+    type Lookup = AccountIdLookup<AccountId, ()>;
     type Block = Block;
-    type RuntimeEvent = RuntimeEvent;
-    type BlockHashCount = ConstU64<250>;
-    type Version = ();
-    type PalletInfo = PalletInfo;
+    type BlockHashCount = BlockHashCount;
+    type Version = Version;
     type AccountData = pallet_balances::AccountData<Balance>;
-    type OnNewAccount = ();
-    type OnKilledAccount = ();
-    type SystemWeightInfo = ();
-    type SS58Prefix = ();
-    type OnSetCode = ();
+    type SS58Prefix = SS58Prefix;
     type MaxConsumers = ConstU32<16>;
 }
 
@@ -69,29 +130,29 @@ impl pallet_balances::Config for Runtime {
     type Balance = Balance;
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
-    type ExistentialDeposit = ConstU128<500>;
+    type ExistentialDeposit = ConstU128<{ constants::EXISTENTIAL_DEPOSIT }>;
     type AccountStore = System;
-    type WeightInfo = ();
+    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = ();
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
-    type RuntimeFreezeReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 /// Configuração do pallet Timestamp
 impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
-    type OnTimestampSet = ();
-    type MinimumPeriod = ConstU64<5>;
+    type OnTimestampSet = Aura;
+    type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
     type WeightInfo = ();
 }
 
 /// Configuração do pallet Aura
 impl pallet_aura::Config for Runtime {
-    type AuthorityId = sp_consensus_aura::sr25519::AuthorityId;
+    type AuthorityId = AuraId;
     type DisabledValidators = ();
     type MaxAuthorities = ConstU32<32>;
-    type AllowMultipleBlocksPerSlot = ();
+    type AllowMultipleBlocksPerSlot = ConstBool<false>;
 }
 
 /// Configuração do pallet Grandpa
@@ -99,81 +160,150 @@ impl pallet_grandpa::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type MaxAuthorities = ConstU32<32>;
-    type MaxNominators = ConstU32<100>;
-    type MaxSetIdSessionEntries = ConstU32<0>;
+    type MaxNominators = ConstU32<0>;
+    type MaxSetIdSessionEntries = ConstU64<0>;
     type KeyOwnerProof = sp_core::Void;
     type EquivocationReportSystem = ();
 }
 
 /// Configuração do pallet Randomness
-impl pallet_randomness_collective_flip::Config for Runtime {}
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+parameter_types! {
+    /// Duração de uma sessão de validadores
+    pub const SessionPeriod: BlockNumber = 6 * constants::HOURS;
+    pub const SessionOffset: BlockNumber = 0;
+}
 
 /// Configuração do pallet Session
 impl pallet_session::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
-    type ValidatorIdOf = pallet_session::historical::IdentityValidatorIdOf<Self>;
-    type ShouldEndSession = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>, ()>;
-    type NextSessionRotation = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>, ()>;
+    type ValidatorIdOf = sp_runtime::traits::ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
     type SessionManager = ();
-    type SessionHandler = ();
-    type Keys = pallet_session::historical::Keys<Self>;
+    type SessionHandler = <opaque::SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+    type Keys = opaque::SessionKeys;
     type WeightInfo = ();
-    type DisabledValidatorsThreshold = ();
 }
 
 /// Configuração do pallet Sudo
 impl pallet_sudo::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type WeightInfo = ();
+    type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub FeeMultiplier: Multiplier = Multiplier::from_u32(1);
 }
 
 /// Configuração do pallet Transaction Payment
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction = FungibleAdapter<Balances, ()>;
     type OperationalFeeMultiplier = ConstU8<5>;
-    type WeightToFee = ();
-    type LengthToFee = ();
-    type FeeMultiplierUpdate = ();
+    type WeightToFee = IdentityFee<Balance>;
+    type LengthToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
+}
+
+/// Número máximo de fatores por score, também usado pela API de runtime
+pub type MaxScoreFactors = ConstU32<{ constants::MAX_SCORE_FACTORS }>;
+
+parameter_types! {
+    /// Fração da distância ao score neutro perdida a cada mês sem recálculo
+    pub const ScoreDecayRate: Permill = Permill::from_percent(5);
 }
 
 /// Configuração do pallet Credit Score
 impl pallet_credit_score::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = ();
+    type WeightInfo = pallet_credit_score::weights::SubstrateWeight<Runtime>;
     type MinScore = ConstU32<0>;
     type MaxScore = ConstU32<1000>;
-    type MaxScoreFactors = ConstU32<10>;
-    type Randomness = pallet_randomness_collective_flip::RandomnessCollectiveFlip<Runtime>;
+    type MaxScoreFactors = MaxScoreFactors;
+    type Randomness = RandomnessCollectiveFlip;
+    type VerifierOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxVerifierMetadataLength = ConstU32<256>;
+    type MaxScoreVerifications = ConstU32<16>;
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type OracleAttesters = OracleIntegration;
+    type MaxBureauNameLength = ConstU32<64>;
+    type PaymentHistory = PaymentRegistry;
+    type PaymentHistoryWeight = ConstU32<35>;
+    type ScoringModel = pallet_credit_score::WeightedAverageModel;
+    type StalenessPeriod = ConstU32<{ 180 * DAYS }>;
+    type DecayPeriod = ConstU32<{ 30 * DAYS }>;
+    type DecayRate = ScoreDecayRate;
+    type NeutralScore = ConstU32<500>;
+    type MaxHistoryEntries = ConstU32<64>;
+    type MaxReasonCodes = ConstU32<4>;
+    type MaxConsentDuration = ConstU32<{ 90 * DAYS }>;
+    type MaxInquiries = ConstU32<50>;
+    type InquiryWindow = ConstU32<{ 365 * DAYS }>;
+    type InquiryDedupPeriod = ConstU32<{ 14 * DAYS }>;
+    type NewCreditWeight = ConstU32<10>;
+    type DisputeReviewPeriod = ConstU32<{ 30 * DAYS }>;
+    type FraudResponseOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxFreezeLiftDuration = ConstU32<{ 7 * DAYS }>;
+    type MaxScoreBands = ConstU32<10>;
+    type MaxStaleMarksPerBlock = ConstU32<50>;
+    type MaxDisputeExpiriesPerBlock = ConstU32<50>;
+    type MaxFreezeReinstatesPerBlock = ConstU32<50>;
+    type MaxInquiryExpiriesPerBlock = ConstU32<20>;
 }
 
 /// Configuração do pallet Payment Registry
 impl pallet_payment_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = ();
-    type MaxPaymentHistory = ConstU32<1000>;
+    type WeightInfo = pallet_payment_registry::weights::SubstrateWeight<Runtime>;
+    type MaxPaymentsPerUser = ConstU32<1000>;
     type MaxPaymentAmount = ConstU128<1_000_000_000_000>;
+    type MinPaymentAmount = ConstU128<1>;
+    type PaymentVerificationPeriod = ConstU32<DAYS>;
+    type OnPaymentStateChange = CreditScore;
+    type MaxVerificationsPerBlock = ConstU32<50>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type VerifierOrigin = frame_system::EnsureRoot<AccountId>;
+    type ArbiterOrigin = frame_system::EnsureRoot<AccountId>;
+    type ProcessorOrigin = frame_system::EnsureRoot<AccountId>;
+    type DisputeEvidencePeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxDefaultJudgmentsPerBlock = ConstU32<50>;
+    type LenderOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxInstallments = ConstU32<360>;
+    type MaxDueInstallmentsPerBlock = ConstU32<50>;
+    type BlocksPerDay = ConstU32<DAYS>;
+    type DefaultAfterDays = ConstU32<180>;
+    type MaxDelinquencyChecksPerBlock = ConstU32<50>;
+    type MinObligationPeriod = ConstU32<DAYS>;
+    type MaxOccurrencesPerBlock = ConstU32<50>;
+    type MaxStreakHistory = ConstU32<64>;
 }
 
 /// Configuração do pallet Identity Verification
 impl pallet_identity_verification::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = ();
-    type MaxVerificationAttempts = ConstU32<3>;
-    type VerificationTimeout = ConstU64<86400>; // 24 horas
+    type WeightInfo = pallet_identity_verification::weights::SubstrateWeight<Runtime>;
+    type MaxDocumentsPerUser = ConstU32<{ constants::MAX_DOCUMENTS_PER_USER }>;
+    type VerificationTimeout = ConstU32<{ constants::VERIFICATION_TIMEOUT }>;
+    type MaxExpirationsPerBlock = ConstU32<50>;
+    type RequiredVerificationLevel = ConstU8<2>;
 }
 
 /// Configuração do pallet Oracle Integration
 impl pallet_oracle_integration::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type WeightInfo = ();
-    type MaxOracleSources = ConstU32<10>;
-    type OracleTimeout = ConstU64<300>; // 5 minutos
+    type WeightInfo = pallet_oracle_integration::weights::SubstrateWeight<Runtime>;
+    type MaxOracles = ConstU32<100>;
+    type MaxDataSources = ConstU32<10>;
+    type UpdateInterval = ConstU32<{ 5 * MINUTES }>;
+    type AttesterOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
-/// Construção do runtime
+// Construção do runtime
 construct_runtime!(
     pub enum Runtime
     {
@@ -184,7 +314,7 @@ construct_runtime!(
         Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
         Sudo: pallet_sudo,
-        RandomnessCollectiveFlip: pallet_randomness_collective_flip,
+        RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
         Session: pallet_session,
         
         // CredChain Custom Pallets
@@ -195,6 +325,336 @@ construct_runtime!(
     }
 );
 
+/// Endereço usado nas extrinsics
+pub type Address = sp_runtime::MultiAddress<AccountId, ()>;
+/// Cabeçalho do bloco
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+/// Tipo do bloco
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+/// Extensões verificadas em toda extrinsic assinada
+pub type SignedExtra = (
+    frame_system::CheckNonZeroSender<Runtime>,
+    frame_system::CheckSpecVersion<Runtime>,
+    frame_system::CheckTxVersion<Runtime>,
+    frame_system::CheckGenesis<Runtime>,
+    frame_system::CheckEra<Runtime>,
+    frame_system::CheckNonce<Runtime>,
+    frame_system::CheckWeight<Runtime>,
+    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+);
+/// Extrinsic não verificada
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// Payload assinado pelas contas
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
+/// Migrações de storage executadas no upgrade do runtime.
+/// Cada migração confere a versão on-chain do pallet e é ignorada se já aplicada.
+pub type Migrations = (
+    pallet_credit_score::migrations::v1::MigrateToV1<Runtime>,
+    pallet_credit_score::migrations::v2::MigrateToV2<Runtime>,
+    pallet_credit_score::migrations::v3::MigrateToV3<Runtime>,
+    pallet_credit_score::migrations::v4::MigrateToV4<Runtime>,
+    pallet_payment_registry::migrations::v1::MigrateToV1<Runtime>,
+    pallet_payment_registry::migrations::v2::MigrateToV2<Runtime>,
+    pallet_payment_registry::migrations::v3::MigrateToV3<Runtime>,
+    pallet_payment_registry::migrations::v4::MigrateToV4<Runtime>,
+    pallet_payment_registry::migrations::v5::MigrateToV5<Runtime>,
+    pallet_payment_registry::migrations::v6::MigrateToV6<Runtime>,
+    pallet_payment_registry::migrations::v7::MigrateToV7<Runtime>,
+    pallet_identity_verification::migrations::v1::MigrateToV1<Runtime>,
+    pallet_oracle_integration::migrations::v1::MigrateToV1<Runtime>,
+);
+
+/// Executa blocos e upgrades, despachando chamadas aos pallets
+pub type Executive = frame_executive::Executive<
+    Runtime,
+    Block,
+    frame_system::ChainContext<Runtime>,
+    Runtime,
+    AllPalletsWithSystem,
+    Migrations,
+>;
+
+#[cfg(feature = "runtime-benchmarks")]
+#[macro_use]
+extern crate frame_benchmarking;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl frame_system_benchmarking::Config for Runtime {}
+
+/// Benchmarks disponíveis para o comando `benchmark pallet` do nó
+#[cfg(feature = "runtime-benchmarks")]
+mod benches {
+    define_benchmarks!(
+        [frame_system, SystemBench::<Runtime>]
+        [pallet_balances, Balances]
+        [pallet_timestamp, Timestamp]
+        [pallet_credit_score, CreditScore]
+        [pallet_payment_registry, PaymentRegistry]
+        [pallet_identity_verification, IdentityVerification]
+        [pallet_oracle_integration, OracleIntegration]
+    );
+}
+
+// APIs de runtime expostas ao nó
+impl_runtime_apis! {
+    impl sp_api::Core<Block> for Runtime {
+        fn version() -> RuntimeVersion {
+            VERSION
+        }
+
+        fn execute_block(block: Block) {
+            Executive::execute_block(block);
+        }
+
+        fn initialize_block(header: &<Block as BlockT>::Header) {
+            Executive::initialize_block(header)
+        }
+    }
+
+    impl sp_api::Metadata<Block> for Runtime {
+        fn metadata() -> OpaqueMetadata {
+            OpaqueMetadata::new(Runtime::metadata().into())
+        }
+
+        fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+            Runtime::metadata_at_version(version)
+        }
+
+        fn metadata_versions() -> sp_std::vec::Vec<u32> {
+            Runtime::metadata_versions()
+        }
+    }
+
+    impl sp_block_builder::BlockBuilder<Block> for Runtime {
+        fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+            Executive::apply_extrinsic(extrinsic)
+        }
+
+        fn finalize_block() -> <Block as BlockT>::Header {
+            Executive::finalize_block()
+        }
+
+        fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<<Block as BlockT>::Extrinsic> {
+            data.create_extrinsics()
+        }
+
+        fn check_inherents(
+            block: Block,
+            data: sp_inherents::InherentData,
+        ) -> sp_inherents::CheckInherentsResult {
+            data.check_extrinsics(&block)
+        }
+    }
+
+    impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+        fn validate_transaction(
+            source: TransactionSource,
+            tx: <Block as BlockT>::Extrinsic,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> TransactionValidity {
+            Executive::validate_transaction(source, tx, block_hash)
+        }
+    }
+
+    impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+        fn offchain_worker(header: &<Block as BlockT>::Header) {
+            Executive::offchain_worker(header)
+        }
+    }
+
+    impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+        fn slot_duration() -> sp_consensus_aura::SlotDuration {
+            sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+        }
+
+        fn authorities() -> Vec<AuraId> {
+            Aura::authorities().into_inner()
+        }
+    }
+
+    impl sp_session::SessionKeys<Block> for Runtime {
+        fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+            opaque::SessionKeys::generate(seed)
+        }
+
+        fn decode_session_keys(encoded: Vec<u8>) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+            opaque::SessionKeys::decode_into_raw_public_keys(&encoded)
+        }
+    }
+
+    impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
+        fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+            Grandpa::grandpa_authorities()
+        }
+
+        fn current_set_id() -> sp_consensus_grandpa::SetId {
+            Grandpa::current_set_id()
+        }
+
+        fn submit_report_equivocation_unsigned_extrinsic(
+            _equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+                <Block as BlockT>::Hash,
+                NumberFor<Block>,
+            >,
+            _key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+        ) -> Option<()> {
+            None
+        }
+
+        fn generate_key_ownership_proof(
+            _set_id: sp_consensus_grandpa::SetId,
+            _authority_id: GrandpaId,
+        ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+            // Sem equivocation reports, não há prova de posse de chave
+            None
+        }
+    }
+
+    impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
+        fn account_nonce(account: AccountId) -> Nonce {
+            System::account_nonce(account)
+        }
+    }
+
+    impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_info(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+            TransactionPayment::query_info(uxt, len)
+        }
+
+        fn query_fee_details(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment::FeeDetails<Balance> {
+            TransactionPayment::query_fee_details(uxt, len)
+        }
+
+        fn query_weight_to_fee(weight: Weight) -> Balance {
+            TransactionPayment::weight_to_fee(weight)
+        }
+
+        fn query_length_to_fee(length: u32) -> Balance {
+            TransactionPayment::length_to_fee(length)
+        }
+    }
+
+    impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+        fn create_default_config() -> Vec<u8> {
+            create_default_config::<RuntimeGenesisConfig>()
+        }
+
+        fn build_config(config: Vec<u8>) -> sp_genesis_builder::Result {
+            build_config::<RuntimeGenesisConfig>(config)
+        }
+    }
+
+    impl pallet_credit_score_runtime_api::CreditScoreApi<Block, AccountId, BlockNumber, Hash, MaxScoreFactors>
+        for Runtime
+    {
+        fn credit_score(who: AccountId) -> Option<pallet_credit_score::CreditScoreDataOf<Runtime>> {
+            CreditScore::credit_score(who)
+        }
+
+        fn effective_score(who: AccountId) -> Option<pallet_credit_score::EffectiveScore> {
+            CreditScore::effective_score(&who)
+        }
+
+        fn score_factors(
+            who: AccountId,
+        ) -> Vec<(pallet_credit_score::ScoreFactorType, pallet_credit_score::FactorRecord<AccountId, BlockNumber>)> {
+            CreditScore::factor_records(&who)
+        }
+
+        fn score_history(
+            who: AccountId,
+            start: Option<u64>,
+            limit: u32,
+        ) -> (Vec<pallet_credit_score::ScoreChangeOf<Runtime>>, Option<u64>) {
+            CreditScore::score_history_page(&who, start, limit)
+        }
+
+        fn explain_score(who: AccountId) -> Option<pallet_credit_score::ScoreExplanation> {
+            CreditScore::explain_score(&who)
+        }
+
+        fn score_reasons(who: AccountId) -> Vec<pallet_credit_score::ScoreReason> {
+            CreditScore::score_reasons(&who)
+        }
+
+        fn has_valid_consent(
+            owner: AccountId,
+            lender: AccountId,
+            purpose: pallet_credit_score::ConsentPurpose,
+        ) -> bool {
+            CreditScore::has_valid_consent(&owner, &lender, purpose)
+        }
+
+        fn is_frozen(who: AccountId) -> bool {
+            CreditScore::is_frozen(&who)
+        }
+
+        fn score_band(who: AccountId) -> Option<pallet_credit_score::ScoreBand> {
+            CreditScore::score_band(&who)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::{Benchmarking, BenchmarkList};
+            use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{BenchmarkBatch, Benchmarking};
+            use frame_support::traits::{TrackedStorageKey, WhitelistedStorageKeys};
+
+            use frame_system_benchmarking::Pallet as SystemBench;
+
+            // Chaves lidas e escritas em todo bloco não entram na medição
+            let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    impl frame_try_runtime::TryRuntime<Block> for Runtime {
+        fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
+            let weight = Executive::try_runtime_upgrade(checks).unwrap();
+            (weight, BlockWeights::get().max_block)
+        }
+
+        fn execute_block(
+            block: Block,
+            state_root_check: bool,
+            signature_check: bool,
+            select: frame_try_runtime::TryStateSelect,
+        ) -> Weight {
+            Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap()
+        }
+    }
+}
+
 /// Constantes do runtime
 pub mod constants {
     use super::*;
@@ -211,11 +671,19 @@ pub mod constants {
     /// Número máximo de fatores de score
     pub const MAX_SCORE_FACTORS: u32 = 10;
     
-    /// Número máximo de tentativas de verificação
-    pub const MAX_VERIFICATION_ATTEMPTS: u32 = 3;
+    /// Número máximo de documentos por usuário
+    pub const MAX_DOCUMENTS_PER_USER: u32 = 10;
     
-    /// Timeout para verificação (em segundos)
-    pub const VERIFICATION_TIMEOUT: u64 = 86400; // 24 horas
+    /// Timeout para verificação (em blocos)
+    pub const VERIFICATION_TIMEOUT: BlockNumber = DAYS;
+
+    /// Tempo de bloco alvo (em milissegundos)
+    pub const MILLISECS_PER_BLOCK: u64 = 6000;
+
+    /// Blocos por minuto, hora e dia
+    pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
+    pub const HOURS: BlockNumber = MINUTES * 60;
+    pub const DAYS: BlockNumber = HOURS * 24;
 }
 
 /// Configuração de moeda